@@ -13,7 +13,7 @@ fn main() {
     }
 
     println!("\n=== Components (Temperature) ===");
-    let mut components = Components::new_with_refreshed_list();
+    let components = Components::new_with_refreshed_list();
     println!("Number of components: {}", components.len());
 
     for (i, comp) in components.iter().enumerate() {
@@ -14,16 +14,18 @@ pub struct AiTechnician {
 }
 
 impl AiTechnician {
-    pub fn new(_lang: Language) -> Self {
+    /// `enabled: false` skips greetings, commentary and delays (--no-ai, JSON output)
+    /// `typewriter_delay_ms: 0` prints instantly without the thinking pauses (--typewriter-ms)
+    pub fn new(_lang: Language, enabled: bool, typewriter_delay_ms: u64) -> Self {
         Self {
-            enabled: true,  // Always on as per user decision
-            typewriter_delay_ms: 10,  // Faster typewriter for better UX
+            enabled,
+            typewriter_delay_ms,
         }
     }
 
     /// Typewriter effect - print text character by character
     pub fn type_print(&self, text: &str) {
-        if !self.enabled {
+        if !self.enabled || self.typewriter_delay_ms == 0 {
             println!("{}", text);
             return;
         }
@@ -38,7 +40,7 @@ impl AiTechnician {
 
     /// Artificial delay to simulate "reading" or "thinking"
     pub fn think(&self, duration_ms: u64) {
-        if self.enabled {
+        if self.enabled && self.typewriter_delay_ms > 0 {
             thread::sleep(Duration::from_millis(duration_ms));
         }
     }
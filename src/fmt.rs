@@ -1,10 +1,21 @@
 // Output formatting module
 
+pub mod json;
+pub mod csv;
+pub mod export;
+pub mod gzip;
+pub mod markdown;
+pub mod report;
+pub mod telemetry;
+
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
 use std::time::Instant;
 
 const SEPARATOR: &str = "============================================================";
 
-// ANSI color codes for terminal output
+// ANSI color codes for terminal output (print through `color()` so NO_COLOR is honored)
 pub const RESET: &str = "\x1b[0m";
 pub const CYAN: &str = "\x1b[36m";
 pub const YELLOW: &str = "\x1b[1;33m";
@@ -13,9 +24,218 @@ pub const ORANGE: &str = "\x1b[38;5;208m";
 pub const RED: &str = "\x1b[31m";
 pub const DARK_GRAY: &str = "\x1b[90m";
 
+/// Whether stdout is a terminal (checked once; piped output can't be redrawn in place)
+pub fn stdout_is_terminal() -> bool {
+    static TERMINAL: OnceLock<bool> = OnceLock::new();
+    *TERMINAL.get_or_init(|| std::io::stdout().is_terminal())
+}
+
+/// Colors are on for an interactive stdout unless NO_COLOR is set (https://no-color.org)
+pub fn color_enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        !no_color && stdout_is_terminal()
+    })
+}
+
+/// The given color code, or "" when colors are disabled
+pub fn color(code: &'static str) -> &'static str {
+    if color_enabled() { code } else { "" }
+}
+
+/// Emit live progress as JSON events on stderr instead of ANSI rendering (--progress-json)
+static PROGRESS_JSON: AtomicBool = AtomicBool::new(false);
+
+pub fn set_progress_json(enabled: bool) {
+    PROGRESS_JSON.store(enabled, Ordering::Relaxed);
+}
+
+pub fn progress_json_enabled() -> bool {
+    PROGRESS_JSON.load(Ordering::Relaxed)
+}
+
+/// Print one JSON document with all results instead of the text report (--format json)
+static JSON_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+pub fn set_json_output(enabled: bool) {
+    JSON_OUTPUT.store(enabled, Ordering::Relaxed);
+}
+
+pub fn json_output_enabled() -> bool {
+    JSON_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// Indent JSON documents instead of printing them on one line (--json-pretty)
+static JSON_PRETTY: AtomicBool = AtomicBool::new(false);
+
+pub fn set_json_pretty(enabled: bool) {
+    JSON_PRETTY.store(enabled, Ordering::Relaxed);
+}
+
+/// Serialize a JSON document, compact (one line, for log ingestion) or indented with --json-pretty
+pub fn to_json<T: serde::Serialize + ?Sized>(value: &T) -> String {
+    let json = if JSON_PRETTY.load(Ordering::Relaxed) {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    };
+    json.unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Print one Markdown document with all results instead of the text report (--format markdown)
+static MARKDOWN_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+pub fn set_markdown_output(enabled: bool) {
+    MARKDOWN_OUTPUT.store(enabled, Ordering::Relaxed);
+}
+
+pub fn markdown_output_enabled() -> bool {
+    MARKDOWN_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// Stdout is reserved for a single JSON or Markdown document
+pub fn document_output_enabled() -> bool {
+    json_output_enabled() || markdown_output_enabled()
+}
+
+/// Print only the verdict and issue list of a health check (--summary-only)
+static SUMMARY_ONLY: AtomicBool = AtomicBool::new(false);
+
+pub fn set_summary_only(enabled: bool) {
+    SUMMARY_ONLY.store(enabled, Ordering::Relaxed);
+}
+
+pub fn summary_only_enabled() -> bool {
+    SUMMARY_ONLY.load(Ordering::Relaxed)
+}
+
+/// Skip ANSI progress rendering on stdout (JSON or Markdown mode, or --summary-only)
+pub fn quiet_progress() -> bool {
+    progress_json_enabled() || document_output_enabled() || summary_only_enabled()
+}
+
+/// Log progress one line per update instead of redrawing in place (stdout is not a terminal)
+pub fn plain_progress() -> bool {
+    !quiet_progress() && !stdout_is_terminal()
+}
+
+/// One plain progress log line, e.g. "[10s] CPU 45°C 3.20GHz"
+pub fn progress_log_line(elapsed_s: u64, message: &str) -> String {
+    format!("[{}s] {}", elapsed_s, message)
+}
+
+/// Hide `outln!`/`out!` output on stdout (the --output capture still records it)
+static MUTED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_muted(muted: bool) {
+    MUTED.store(muted, Ordering::Relaxed);
+}
+
+/// Output recorded for the --output transcript (None when not recording)
+static CAPTURE: Mutex<Option<String>> = Mutex::new(None);
+
+/// Start recording everything printed through `outln!`/`out!`
+pub fn start_capture() {
+    if let Ok(mut capture) = CAPTURE.lock() {
+        *capture = Some(String::new());
+    }
+}
+
+/// Stop recording and return the captured text (ANSI codes stripped)
+pub fn take_capture() -> String {
+    CAPTURE.lock().ok().and_then(|mut c| c.take()).unwrap_or_default()
+}
+
+/// Backend of `outln!`/`out!`: records the text if capturing, prints it unless a document is emitted or muted
+pub fn write_out(args: std::fmt::Arguments, newline: bool) {
+    if let Ok(mut capture) = CAPTURE.lock() {
+        if let Some(buf) = capture.as_mut() {
+            buf.push_str(&strip_ansi(&args.to_string()));
+            if newline {
+                buf.push('\n');
+            }
+        }
+    }
+    if document_output_enabled() || MUTED.load(Ordering::Relaxed) {
+        return;
+    }
+    if newline {
+        println!("{}", args);
+    } else {
+        print!("{}", args);
+    }
+}
+
+/// `println!` that stays silent when stdout is reserved for the JSON/Markdown document
+#[macro_export]
+macro_rules! outln {
+    () => {
+        $crate::fmt::write_out(format_args!(""), true)
+    };
+    ($($arg:tt)*) => {
+        $crate::fmt::write_out(format_args!($($arg)*), true)
+    };
+}
+
+/// `print!` that stays silent when stdout is reserved for the JSON/Markdown document
+#[macro_export]
+macro_rules! out {
+    ($($arg:tt)*) => {
+        $crate::fmt::write_out(format_args!($($arg)*), false)
+    };
+}
+
+/// Remove ANSI escape sequences (colors, cursor movement) for plain-text output
+pub fn strip_ansi(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // CSI sequence: ESC [ params... final byte in @..~
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            continue;
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Build a compact JSON progress event
+/// e.g. {"component":"cpu","percent":42,"temp_c":61.2,"elapsed_s":42}
+pub fn progress_event_json(component: &str, percent: u8, temp_c: Option<f32>, elapsed_s: u64) -> String {
+    let mut event = serde_json::json!({
+        "component": component,
+        "percent": percent.min(100),
+        "elapsed_s": elapsed_s,
+    });
+    if let Some(temp) = temp_c {
+        // Round to one decimal so f32 noise doesn't leak into the output
+        event["temp_c"] = serde_json::json!((temp as f64 * 10.0).round() / 10.0);
+    }
+    event.to_string()
+}
+
+/// Write one progress event line to stderr (only in --progress-json mode)
+pub fn emit_progress_json(component: &str, percent: u8, temp_c: Option<f32>, elapsed_s: u64) {
+    if !progress_json_enabled() {
+        return;
+    }
+    let line = progress_event_json(component, percent, temp_c, elapsed_s);
+    let mut stderr = std::io::stderr().lock();
+    let _ = writeln!(stderr, "{}", line);
+    let _ = stderr.flush();
+}
+
 /// Get color for temperature value
 pub fn temp_color(temp: f32) -> &'static str {
-    if temp < 60.0 {
+    let code = if temp < 60.0 {
         GREEN
     } else if temp < 75.0 {
         YELLOW
@@ -23,7 +243,8 @@ pub fn temp_color(temp: f32) -> &'static str {
         ORANGE
     } else {
         RED
-    }
+    };
+    color(code)
 }
 
 /// Get temperature status text (requires Text for i18n)
@@ -58,7 +279,7 @@ pub fn temp_status(temp: f32) -> &'static str {
 
 /// Get color for CPU usage % (consistent with temperature colors)
 pub fn usage_color(usage: f32) -> &'static str {
-    if usage > 90.0 {
+    let code = if usage > 90.0 {
         RED
     }
     // Overload - same as "Nóng"
@@ -68,7 +289,8 @@ pub fn usage_color(usage: f32) -> &'static str {
     // Active - same as "Rất tốt"
     else {
         DARK_GRAY
-    } // Idle
+    }; // Idle
+    color(code)
 }
 
 /// Format large number with suffix (Billion, Trillion) - i18n version
@@ -109,17 +331,66 @@ pub fn format_number(n: u64) -> String {
     result
 }
 
+/// Format a duration in seconds as "3d 4h 12m" (leading zero units dropped)
+pub fn format_uptime(secs: u64) -> String {
+    let (days, hours, minutes) = (secs / 86_400, secs % 86_400 / 3_600, secs % 3_600 / 60);
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+/// Longest accepted test duration (7 days)
+pub const MAX_DURATION_SECS: u64 = 7 * 86_400;
+
+/// Parse a test duration: bare seconds ("90") or a number with an s/m/h suffix ("30s", "5m", "1h")
+pub fn parse_duration(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    let (number, unit_secs) = match value.char_indices().last() {
+        Some((i, 's')) => (&value[..i], 1),
+        Some((i, 'm')) => (&value[..i], 60),
+        Some((i, 'h')) => (&value[..i], 3_600),
+        _ => (value, 1),
+    };
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration '{}' (expected seconds, or a number with s/m/h such as 30s, 5m, 1h)", value))?;
+    let secs = number.saturating_mul(unit_secs);
+    if secs == 0 {
+        return Err("duration must be greater than zero".to_string());
+    }
+    if secs > MAX_DURATION_SECS {
+        return Err(format!("duration '{}' is too long (maximum is {}h)", value, MAX_DURATION_SECS / 3_600));
+    }
+    Ok(secs)
+}
+
+/// Remaining time of a timed test as "ETA mm:ss" (minutes keep counting past 59)
+pub fn format_eta(elapsed_secs: u64, total_secs: u64) -> String {
+    let remaining = total_secs.saturating_sub(elapsed_secs);
+    format!("ETA {:02}:{:02}", remaining / 60, remaining % 60)
+}
+
+/// Idle-to-peak temperature rise as "41°C → 83°C (+42°C)", None without both readings
+pub fn format_temp_rise(idle_c: Option<f32>, peak_c: Option<f32>) -> Option<String> {
+    let (idle, peak) = (idle_c?, peak_c?);
+    Some(format!("{:.0}°C → {:.0}°C ({:+.0}°C)", idle, peak, peak - idle))
+}
+
 /// Create progress bar string
 pub fn progress_bar(percent: u8, width: usize) -> String {
     let filled = (percent as usize * width / 100).min(width);
     let empty = width - filled;
     format!(
         "{}{}{}{}{}",
-        GREEN,
+        color(GREEN),
         "█".repeat(filled),
-        DARK_GRAY,
+        color(DARK_GRAY),
         "░".repeat(empty),
-        RESET
+        color(RESET)
     )
 }
 
@@ -147,3 +418,92 @@ pub fn print_footer_with_text(start_time: Instant, done_text: &str) {
     println!("{} {}", done_text, time_str);
     println!("{}", SEPARATOR);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_uptime() {
+        assert_eq!(format_uptime(59), "0m");
+        assert_eq!(format_uptime(3 * 3_600 + 5 * 60), "3h 5m");
+        assert_eq!(format_uptime(2 * 86_400 + 60), "2d 0h 1m");
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("60"), Ok(60));
+        assert_eq!(parse_duration("30s"), Ok(30));
+        assert_eq!(parse_duration("5m"), Ok(300));
+        assert_eq!(parse_duration("2h"), Ok(7_200));
+        assert!(parse_duration("0").is_err());
+        assert!(parse_duration("-5m").is_err());
+        assert!(parse_duration("1.5h").is_err());
+        assert!(parse_duration("m").is_err());
+        assert!(parse_duration("169h").is_err());
+        assert!(parse_duration("99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn test_format_eta() {
+        assert_eq!(format_eta(15, 60), "ETA 00:45");
+        assert_eq!(format_eta(0, 3_725), "ETA 62:05");
+        assert_eq!(format_eta(90, 60), "ETA 00:00");
+    }
+
+    #[test]
+    fn test_format_temp_rise() {
+        assert_eq!(format_temp_rise(Some(41.2), Some(83.4)).as_deref(), Some("41°C → 83°C (+42°C)"));
+        assert_eq!(format_temp_rise(None, Some(83.0)), None);
+        assert_eq!(format_temp_rise(Some(41.0), None), None);
+    }
+
+    #[test]
+    fn test_progress_log_line() {
+        assert_eq!(progress_log_line(10, "CPU 45°C 3.20GHz"), "[10s] CPU 45°C 3.20GHz");
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\x1b[32m61.0°C\x1b[0m ok"), "61.0°C ok");
+        assert_eq!(strip_ansi("\r\x1b[2K\x1b[38;5;208mwarm"), "\rwarm");
+        assert_eq!(strip_ansi("plain"), "plain");
+    }
+
+    #[test]
+    fn test_to_json_compact_and_pretty() {
+        let freq = crate::sensors::CpuFrequency {
+            current_mhz: 3000,
+            current_ghz: 3.0,
+            cores: 3,
+            per_core_mhz: [(2, 2900), (0, 3100), (1, 3000)].into(),
+        };
+        let compact = to_json(&freq);
+        assert!(!compact.contains('\n'));
+        // Fields in declaration order, per-core map by core index
+        assert!(compact.starts_with(r#"{"current_mhz":3000,"#));
+        assert!(compact.ends_with(r#""per_core_mhz":{"0":3100,"1":3000,"2":2900}}"#));
+
+        set_json_pretty(true);
+        let pretty = to_json(&freq);
+        set_json_pretty(false);
+        assert!(pretty.contains("\n  \"current_ghz\": 3.0"));
+    }
+
+    #[test]
+    fn test_progress_event_json() {
+        let line = progress_event_json("cpu", 42, Some(61.23), 42);
+        let v: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(v["component"], "cpu");
+        assert_eq!(v["percent"], 42);
+        assert_eq!(v["temp_c"], 61.2);
+        assert_eq!(v["elapsed_s"], 42);
+        assert!(!line.contains('\n'));
+
+        // No sensor: temp_c omitted, percent clamped
+        let line = progress_event_json("gpu", 150, None, 3);
+        let v: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert!(v.get("temp_c").is_none());
+        assert_eq!(v["percent"], 100);
+    }
+}
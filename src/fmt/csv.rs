@@ -0,0 +1,83 @@
+// SMART CSV export module
+// Appends one row per tested disk to a CSV file for tracking wear over time (--smart-csv)
+
+use std::io;
+use std::path::Path;
+
+use crate::stress::disk::smart::SmartData;
+
+pub const SMART_CSV_HEADER: &str = "timestamp,disk_name,health_percentage,ssd_life_left,power_on_hours,total_lbas_written,temperature_c,realloc_sectors,pending_sectors";
+
+/// One CSV row (no trailing newline); missing values are empty cells
+pub fn smart_csv_row(timestamp: &str, disk_name: &str, smart: &SmartData) -> String {
+    fn cell<T: ToString>(value: Option<T>) -> String {
+        value.map(|v| v.to_string()).unwrap_or_default()
+    }
+
+    [
+        escape(timestamp),
+        escape(disk_name),
+        cell(smart.health_percentage),
+        cell(smart.ssd_life_left),
+        cell(smart.power_on_hours),
+        cell(smart.total_lbas_written),
+        cell(smart.temperature_c),
+        cell(smart.realloc_sectors),
+        cell(smart.pending_sectors),
+    ]
+    .join(",")
+}
+
+/// Append rows to `path`, writing the header first when the file is new or empty
+pub fn append_smart_csv(path: &Path, rows: &[String]) -> io::Result<()> {
+    let mut out = String::new();
+    if std::fs::metadata(path).map_or(true, |meta| meta.len() == 0) {
+        out.push_str(SMART_CSV_HEADER);
+        out.push('\n');
+    }
+    for row in rows {
+        out.push_str(row);
+        out.push('\n');
+    }
+    super::gzip::append_file(path, out.as_bytes())
+}
+
+/// Quote a field that contains a comma, quote or newline
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smart_csv_row() {
+        let smart = SmartData {
+            ssd_life_left: Some(97),
+            power_on_hours: Some(5678),
+            temperature_c: Some(40.0),
+            realloc_sectors: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(
+            smart_csv_row("2026-10-15T08:00:00Z", "Samsung 970, 1TB", &smart),
+            "2026-10-15T08:00:00Z,\"Samsung 970, 1TB\",,97,5678,,40,0,"
+        );
+    }
+
+    #[test]
+    fn test_append_smart_csv_writes_header_once() {
+        let path = std::env::temp_dir().join(format!("pchecker_smart_{}.csv", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        append_smart_csv(&path, &["a".to_string()]).unwrap();
+        append_smart_csv(&path, &["b".to_string(), "c".to_string()]).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(contents, format!("{}\na\nb\nc\n", SMART_CSV_HEADER));
+    }
+}
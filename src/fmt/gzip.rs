@@ -0,0 +1,84 @@
+// Gzip compression for the file writers (--compress, or a file name ending in .gz)
+// Appends add a new gzip member; concatenated members are still one valid gzip file (zcat reads them all)
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+
+/// Compress every output file, whatever its name (--compress)
+static COMPRESS: AtomicBool = AtomicBool::new(false);
+
+pub fn set_compress(enabled: bool) {
+    COMPRESS.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether `path` is written gzip-compressed
+pub fn compressed(path: &Path) -> bool {
+    COMPRESS.load(Ordering::Relaxed) || path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("gz"))
+}
+
+/// Gzip `data` as one complete member
+fn gzip(data: &[u8]) -> io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data)?;
+    encoder.finish()
+}
+
+/// Replace `path` with `data` (compressed if enabled for it); returns the bytes now on disk
+pub fn write_file(path: &Path, data: &[u8]) -> io::Result<Vec<u8>> {
+    let bytes = if compressed(path) { gzip(data)? } else { data.to_vec() };
+    std::fs::write(path, &bytes)?;
+    Ok(bytes)
+}
+
+/// Append `data` to `path`, creating it if needed
+pub fn append_file(path: &Path, data: &[u8]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if compressed(path) {
+        file.write_all(&gzip(data)?)
+    } else {
+        file.write_all(data)
+    }
+}
+
+/// Streaming writer that replaces `path`; a compressed stream gets its trailer when the writer is dropped
+pub fn create_writer(path: &Path) -> io::Result<Box<dyn Write + Send>> {
+    let file = File::create(path)?;
+    Ok(if compressed(path) {
+        Box::new(GzEncoder::new(file, Compression::default()))
+    } else {
+        Box::new(file)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn gunzip(bytes: &[u8]) -> String {
+        let mut out = String::new();
+        flate2::read::MultiGzDecoder::new(bytes).read_to_string(&mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_gz_suffix_and_appended_members() {
+        assert!(compressed(Path::new("telemetry.jsonl.gz")));
+        assert!(!compressed(Path::new("telemetry.jsonl")));
+
+        let path = std::env::temp_dir().join(format!("pchecker_gzip_{}.txt.gz", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        for line in ["first\n", "second\n"] {
+            append_file(&path, line.as_bytes()).unwrap();
+        }
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(&bytes[..2], &[0x1f, 0x8b]);
+        assert_eq!(gunzip(&bytes), "first\nsecond\n");
+    }
+}
@@ -0,0 +1,125 @@
+// JSON health report module
+// Collects every component result of a health check into one document (--format json)
+
+use serde::Serialize;
+
+use crate::stress::expected::PerfCheck;
+use crate::stress::gpu::soak::GpuSoakResult;
+use crate::stress::{CpuTestResult, DiskTestResult, GpuTestResult, RamTestResult};
+
+/// Overall outcome of a health check run
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Good,
+    /// Tests passed but the required evidence (thermal, SMART, SSD life) is missing
+    NotFullyVerified,
+    SomeIssues,
+    NotRecommended,
+}
+
+impl Verdict {
+    pub fn from_run(all_healthy: bool, has_critical: bool, has_unmet_criteria: bool) -> Self {
+        if has_critical {
+            Verdict::NotRecommended
+        } else if !all_healthy {
+            Verdict::SomeIssues
+        } else if has_unmet_criteria {
+            Verdict::NotFullyVerified
+        } else {
+            Verdict::Good
+        }
+    }
+}
+
+/// Wall-clock time spent on one component
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentTiming {
+    pub component: String,
+    pub seconds: f64,
+}
+
+/// All results of one health check run
+#[derive(Serialize)]
+pub struct HealthReport {
+    pub version: &'static str,
+    pub cpu: Option<CpuTestResult>,
+    pub ram: Option<RamTestResult>,
+    pub disks: Vec<DiskTestResult>,
+    pub gpus: Vec<GpuTestResult>,
+    /// GPU results from --gpu-sustained (replaces `gpus`)
+    pub gpu_soak: Vec<GpuSoakResult>,
+    pub verdict: Verdict,
+    pub critical_issues: Vec<String>,
+    pub issues: Vec<String>,
+    pub unmet_criteria: Vec<String>,
+    /// Measured vs expected performance (--compare-to-model)
+    pub perf_checks: Vec<PerfCheck>,
+    pub timings: Vec<ComponentTiming>,
+    pub elapsed_s: f64,
+}
+
+impl HealthReport {
+    pub fn new() -> Self {
+        Self {
+            version: "0.3.0",
+            cpu: None,
+            ram: None,
+            disks: Vec::new(),
+            gpus: Vec::new(),
+            gpu_soak: Vec::new(),
+            verdict: Verdict::Good,
+            critical_issues: Vec::new(),
+            issues: Vec::new(),
+            unmet_criteria: Vec::new(),
+            perf_checks: Vec::new(),
+            timings: Vec::new(),
+            elapsed_s: 0.0,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        crate::fmt::to_json(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stress::{HealthStatus, Issue, IssueCode};
+
+    #[test]
+    fn test_verdict_from_run() {
+        assert_eq!(Verdict::from_run(true, false, false), Verdict::Good);
+        assert_eq!(Verdict::from_run(true, false, true), Verdict::NotFullyVerified);
+        assert_eq!(Verdict::from_run(false, false, true), Verdict::SomeIssues);
+        assert_eq!(Verdict::from_run(false, true, false), Verdict::NotRecommended);
+    }
+
+    #[test]
+    fn test_report_to_json() {
+        let mut report = HealthReport::new();
+        report.ram = Some(RamTestResult {
+            ram_total_gb: 16.0,
+            tested_gb: 8.0,
+            write_speed_gb_s: 12.5,
+            read_speed_gb_s: 14.0,
+            errors: 0,
+            passes: 1,
+            access: crate::stress::RamAccess::Sequential,
+            failed_patterns: vec![],
+            latency_ns: None,
+            max_request_gb: 0.0,
+            health: HealthStatus::IssuesDetected(vec![Issue::new(IssueCode::RamSlow, "slow")]),
+        });
+        report.verdict = Verdict::SomeIssues;
+
+        let value: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(value["verdict"], "some_issues");
+        assert_eq!(value["ram"]["tested_gb"], 8.0);
+        assert_eq!(value["ram"]["health"]["IssuesDetected"][0]["code"], "RamSlow");
+        assert_eq!(value["ram"]["health"]["IssuesDetected"][0]["message"], "slow");
+        assert!(value["cpu"].is_null());
+        assert_eq!(value["disks"].as_array().unwrap().len(), 0);
+    }
+}
@@ -0,0 +1,143 @@
+// Markdown report (--format markdown)
+// Headings and tables that paste cleanly into GitHub issues and forums (no box-drawing)
+
+use crate::hw::DeepInfo;
+use crate::lang::Text;
+
+use super::report::{escape_cell, ResultTable};
+
+/// Two-column table without a header row
+fn table(rows: &[(String, String)]) -> String {
+    let mut md = String::from("| | |\n|---|---|\n");
+    for (label, value) in rows {
+        md.push_str(&format!("| {} | {} |\n", escape_cell(label), escape_cell(value)));
+    }
+    md
+}
+
+/// System section: hardware detected before the tests, including deep info
+pub fn system_markdown(info: &DeepInfo, text: &Text) -> String {
+    let mut rows: Vec<(String, String)> = vec![
+        (text.system().to_string(), info.system_display()),
+        (text.uptime().to_string(), super::format_uptime(info.uptime_secs)),
+    ];
+    if let Some([one, five, fifteen]) = info.load_average {
+        rows.push((text.load_average().to_string(), format!("{:.2} {:.2} {:.2}", one, five, fifteen)));
+    }
+    rows.push((text.cpu().to_string(), format!("{} ({})", info.cpu.model, info.cpu.cores_display(text.cores_label()))));
+
+    if let Some(ref cache) = info.cache {
+        let levels: Vec<String> = [("L1", cache.l1_kb), ("L2", cache.l2_kb), ("L3", cache.l3_kb)]
+            .iter()
+            .filter_map(|(level, kb)| kb.map(|kb| format!("{} {} KB", level, kb)))
+            .collect();
+        if !levels.is_empty() {
+            rows.push(("Cache".to_string(), levels.join(", ")));
+        }
+    }
+    if let Some(ref isa) = info.instruction_sets {
+        rows.push(("Features".to_string(), isa.features.join(", ")));
+    }
+    if let Some(tdp) = info.tdp_w {
+        rows.push(("TDP".to_string(), format!("{} W", tdp)));
+    }
+
+    if info.gpus.is_empty() {
+        rows.push((text.gpu().to_string(), text.no_gpu().to_string()));
+    }
+    for (idx, gpu) in info.gpus.iter().enumerate() {
+        let label = if info.gpus.len() > 1 { format!("{} #{}", text.gpu(), idx) } else { text.gpu().to_string() };
+        rows.push((label, gpu.display_localized(text)));
+    }
+    if let Some(metal) = info.gpu_driver.as_ref().and_then(|d| d.metal.as_ref()) {
+        rows.push(("Metal".to_string(), metal.clone()));
+    }
+
+    rows.push((text.ram().to_string(), format!("{:.1} GB ({:.1} GB {})", info.ram.total_gb, info.ram.used_gb, text.ram_free())));
+    for slot in &info.dimm_slots {
+        let mut parts = vec![format!("{} GB {} ({})", slot.size_gb, slot.type_, slot.bank)];
+        parts.extend(slot.speed_mhz.map(|s| format!("{} MHz", s)));
+        parts.extend(slot.manufacturer.clone());
+        parts.extend(slot.part_number.clone());
+        rows.push((format!("Slot {}", slot.id), parts.join(", ")));
+    }
+
+    for (idx, disk) in info.disks.iter().enumerate() {
+        let label = if info.disks.len() > 1 { format!("{} #{}", text.disk(), idx) } else { text.disk().to_string() };
+        rows.push((label, disk.display()));
+    }
+    if let Some(ref health) = info.disk_health {
+        let mut parts = vec![health.status.clone()];
+        parts.extend(health.firmware.as_ref().map(|fw| format!("Firmware {}", fw)));
+        parts.extend(health.tbw.map(|tbw| format!("TBW {:.1} TB", tbw)));
+        parts.extend(health.hours.map(|h| format!("{} h", h)));
+        parts.extend(health.percentage_used.map(|pct| format!("Life Used {}%", pct)));
+        rows.push(("Health".to_string(), parts.join(", ")));
+    }
+
+    for (idx, nic) in info.network.iter().enumerate() {
+        let label = if info.network.len() > 1 { format!("{} #{}", text.network(), idx) } else { text.network().to_string() };
+        rows.push((label, nic.display()));
+    }
+    if let Some(ref battery) = info.battery {
+        rows.push((text.battery().to_string(), battery.display_localized(text)));
+    }
+
+    rows.push((text.measurable_label().to_string(), info.capabilities.badge_row()));
+
+    format!("## 💻 {}\n\n{}", text.system(), table(&rows))
+}
+
+/// Full report: system info, one section per test result, then the verdict and issues
+pub fn report_markdown(
+    title: &str,
+    system: &str,
+    results: &[ResultTable],
+    verdict: &str,
+    issues: &[String],
+    footer: &[String],
+) -> String {
+    let mut md = format!("# {}\n\n{}", title, system);
+
+    for result in results {
+        md.push('\n');
+        md.push_str(&result.to_markdown());
+    }
+
+    md.push_str(&format!("\n## {}\n", verdict));
+    if !issues.is_empty() {
+        md.push('\n');
+        for issue in issues {
+            md.push_str(&format!("- {}\n", issue));
+        }
+    }
+    for line in footer {
+        md.push_str(&format!("\n{}\n", line));
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stress::HealthStatus;
+
+    #[test]
+    fn test_report_markdown() {
+        let mut cpu = ResultTable::new("🧠", "CPU Health Check", &HealthStatus::Healthy);
+        cpu.row("cpu", "Apple M2");
+        let md = report_markdown(
+            "PCHECKER Health Check",
+            "## 💻 System\n\n| | |\n|---|---|\n| system | macOS |\n",
+            &[cpu],
+            "✅ Hardware good",
+            &["Disk: slow".to_string()],
+            &["Done in 61.20s".to_string()],
+        );
+        assert!(md.starts_with("# PCHECKER Health Check\n\n## 💻 System\n"));
+        assert!(md.contains("\n### 🧠 CPU Health Check ✅\n"));
+        assert!(md.contains("| cpu | Apple M2 |\n"));
+        assert!(md.contains("\n## ✅ Hardware good\n\n- Disk: slow\n"));
+        assert!(md.ends_with("\nDone in 61.20s\n"));
+    }
+}
@@ -0,0 +1,162 @@
+// Test result tables shared by the text box and Markdown renderers
+// Each component builds one ResultTable, the renderers only decide the layout
+
+use crate::stress::HealthStatus;
+
+const BOX_TOP: &str = "┌──────────────────────────────────────────────────────────┐";
+const BOX_SEPARATOR: &str = "├──────────────────────────────────────────────────────────┤";
+const BOX_BOTTOM: &str = "└──────────────────────────────────────────────────────────┘";
+
+/// One test result: a titled table of (label, value) rows split into sections
+#[derive(Debug, Clone)]
+pub struct ResultTable {
+    pub icon: &'static str,
+    pub title: String,
+    pub status_icon: &'static str,
+    pub healthy: bool,
+    pub issues: Vec<String>,
+    /// Rows grouped into sections (a separator line between sections)
+    /// A row with an empty value is a sub-heading ("Performance test:")
+    pub sections: Vec<Vec<(String, String)>>,
+    /// Advisories shown under the table
+    pub notes: Vec<String>,
+}
+
+impl ResultTable {
+    pub fn new(icon: &'static str, title: &str, health: &HealthStatus) -> Self {
+        let (status_icon, healthy, issues) = match health {
+            HealthStatus::Healthy => ("✅", true, vec![]),
+            HealthStatus::IssuesDetected(issues) => ("⚠️", false, issues.iter().map(|i| i.message.clone()).collect()),
+            HealthStatus::Failed(issue) => ("❌", false, vec![issue.message.clone()]),
+        };
+        Self {
+            icon,
+            title: title.to_string(),
+            status_icon,
+            healthy,
+            issues,
+            sections: vec![Vec::new()],
+            notes: Vec::new(),
+        }
+    }
+
+    /// Append a row to the current section
+    pub fn row(&mut self, label: &str, value: &str) {
+        if let Some(section) = self.sections.last_mut() {
+            section.push((label.to_string(), value.to_string()));
+        }
+    }
+
+    /// Start a new section (drawn after a separator line)
+    pub fn section(&mut self) {
+        self.sections.push(Vec::new());
+    }
+
+    pub fn note(&mut self, note: String) {
+        self.notes.push(note);
+    }
+
+    /// Box-drawing lines for the terminal report
+    pub fn box_lines(&self) -> Vec<String> {
+        // Header padding: 4 for emoji + spaces
+        let header_padding = 52usize.saturating_sub(self.title.chars().count() + 4);
+
+        let mut lines = vec![
+            BOX_TOP.to_string(),
+            format!("│ {} {} {:>width$} │", self.icon, self.title, self.status_icon, width = header_padding + 2),
+        ];
+        for section in self.sections.iter().filter(|s| !s.is_empty()) {
+            lines.push(BOX_SEPARATOR.to_string());
+            lines.extend(section.iter().map(|(label, value)| table_row(label, value)));
+        }
+        lines.push(BOX_BOTTOM.to_string());
+        lines.extend(self.notes.iter().map(|note| format!("ℹ️  {}", note)));
+        lines
+    }
+
+    /// GitHub-flavored Markdown: a heading, a two-column table, then issues and notes
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!("### {} {} {}\n\n", self.icon, self.title, self.status_icon);
+        md.push_str("| | |\n|---|---|\n");
+        for (label, value) in self.sections.iter().flatten() {
+            if value.is_empty() {
+                md.push_str(&format!("| **{}** | |\n", escape_cell(label)));
+            } else {
+                md.push_str(&format!("| {} | {} |\n", escape_cell(label), escape_cell(value)));
+            }
+        }
+        if !self.issues.is_empty() {
+            md.push('\n');
+            for issue in &self.issues {
+                md.push_str(&format!("- {} {}\n", self.status_icon, issue));
+            }
+        }
+        if !self.notes.is_empty() {
+            md.push('\n');
+            for note in &self.notes {
+                md.push_str(&format!("> ℹ️ {}\n", note));
+            }
+        }
+        md
+    }
+}
+
+/// Format a table row with proper alignment
+/// Box width is 56 chars internally (between "│ " and " │", matching the ┌─┐ border)
+/// Format: │ label: value │ where value is right-aligned
+pub fn table_row(label: &str, value: &str) -> String {
+    const BOX_WIDTH: usize = 56;  // Internal width between borders
+    let label_len = label.chars().count();
+    let value_len = value.chars().count();
+
+    // Calculate padding: BOX_WIDTH - label_len - ": " - value_len
+    let padding = BOX_WIDTH.saturating_sub(label_len + 2 + value_len);
+    format!("│ {}: {:>padding$} │", label, value, padding = value_len + padding)
+}
+
+/// Make text safe inside a Markdown table cell
+pub fn escape_cell(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stress::{Issue, IssueCode};
+
+    fn sample() -> ResultTable {
+        let mut table = ResultTable::new("💾", "RAM Health Check", &HealthStatus::IssuesDetected(vec![Issue::new(IssueCode::RamSlow, "slow")]));
+        table.row("ram", "16.0 GB");
+        table.section();
+        table.row("performance test", "");
+        table.row("pattern", "0x55 | 0xAA");
+        table.note("TRIM is off".to_string());
+        table
+    }
+
+    #[test]
+    fn test_box_lines() {
+        let lines = sample().box_lines();
+        let width = BOX_TOP.chars().count();
+        assert_eq!(lines[0], BOX_TOP);
+        assert_eq!(lines[2], BOX_SEPARATOR);
+        assert_eq!(lines.iter().filter(|l| *l == BOX_SEPARATOR).count(), 2);
+        assert_eq!(lines[lines.len() - 2], BOX_BOTTOM);
+        assert_eq!(lines[lines.len() - 1], "ℹ️  TRIM is off");
+        for line in &lines[2..lines.len() - 2] {
+            assert_eq!(line.chars().count(), width, "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let md = sample().to_markdown();
+        assert!(md.starts_with("### 💾 RAM Health Check ⚠️\n"));
+        assert!(md.contains("| ram | 16.0 GB |\n"));
+        assert!(md.contains("| **performance test** | |\n"));
+        assert!(md.contains("| pattern | 0x55 \\| 0xAA |\n"));
+        assert!(md.contains("- ⚠️ slow\n"));
+        assert!(md.contains("> ℹ️ TRIM is off\n"));
+        assert!(!md.contains('┌'));
+    }
+}
@@ -0,0 +1,112 @@
+// Per-second test telemetry (--telemetry)
+// One JSON object per line so the thermal ramp can be graphed after the run
+
+use std::collections::HashMap;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Telemetry destination: the file is only created by the first sample, so runs
+/// that never record one (--info, a declined prompt) don't leave an empty file
+struct Telemetry {
+    /// Not yet created file (None when --telemetry is not set or the file is open)
+    path: Option<PathBuf>,
+    writer: Option<BufWriter<Box<dyn Write + Send>>>,
+}
+
+static TELEMETRY: Mutex<Telemetry> = Mutex::new(Telemetry { path: None, writer: None });
+
+/// Set the telemetry file; it is created (or truncated) when the first sample is recorded
+/// Fails up front when the file's directory doesn't exist
+pub fn start(path: &Path) -> std::io::Result<()> {
+    let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    if !std::fs::metadata(dir)?.is_dir() {
+        return Err(std::io::Error::new(std::io::ErrorKind::NotFound, format!("{} is not a directory", dir.display())));
+    }
+    if let Ok(mut telemetry) = TELEMETRY.lock() {
+        telemetry.path = Some(path.to_path_buf());
+    }
+    Ok(())
+}
+
+/// Close the telemetry file (writes the gzip trailer when compressed); call before exiting
+pub fn finish() {
+    if let Ok(mut telemetry) = TELEMETRY.lock() {
+        telemetry.writer.take();
+    }
+}
+
+/// Build one telemetry line
+/// e.g. {"component":"cpu","elapsed_s":3,"temp_c":71.5,"freq_mhz":3400,"ops":1200,"per_core_usage":[98.0,97.5]}
+pub fn telemetry_line(
+    component: &str,
+    elapsed_s: u64,
+    temp_c: Option<f32>,
+    freq_mhz: u64,
+    ops: u64,
+    per_core_usage: &HashMap<usize, f32>,
+) -> String {
+    // Round to one decimal so f32 noise doesn't leak into the output
+    let round = |v: f32| (v as f64 * 10.0).round() / 10.0;
+    let mut cores: Vec<(&usize, &f32)> = per_core_usage.iter().collect();
+    cores.sort_by_key(|(core, _)| **core);
+
+    serde_json::json!({
+        "component": component,
+        "elapsed_s": elapsed_s,
+        "temp_c": temp_c.map(round),
+        "freq_mhz": freq_mhz,
+        "ops": ops,
+        "per_core_usage": cores.into_iter().map(|(_, usage)| round(*usage)).collect::<Vec<_>>(),
+    })
+    .to_string()
+}
+
+/// Append one sample line and flush it (no-op without --telemetry)
+pub fn record(
+    component: &str,
+    elapsed_s: u64,
+    temp_c: Option<f32>,
+    freq_mhz: u64,
+    ops: u64,
+    per_core_usage: &HashMap<usize, f32>,
+) {
+    let Ok(mut telemetry) = TELEMETRY.lock() else {
+        return;
+    };
+    if let Some(path) = telemetry.path.take() {
+        match super::gzip::create_writer(&path) {
+            Ok(file) => telemetry.writer = Some(BufWriter::new(file)),
+            Err(e) => eprintln!("⚠️  cannot create {}: {}", path.display(), e),
+        }
+    }
+    if let Some(writer) = telemetry.writer.as_mut() {
+        let line = telemetry_line(component, elapsed_s, temp_c, freq_mhz, ops, per_core_usage);
+        let _ = writeln!(writer, "{}", line);
+        let _ = writer.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_telemetry_line() {
+        let usage = HashMap::from([(1, 97.54), (0, 99.0)]);
+        let line = telemetry_line("cpu", 3, Some(71.46), 3400, 1200, &usage);
+        let v: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(v["component"], "cpu");
+        assert_eq!(v["elapsed_s"], 3);
+        assert_eq!(v["temp_c"], 71.5);
+        assert_eq!(v["freq_mhz"], 3400);
+        assert_eq!(v["ops"], 1200);
+        assert_eq!(v["per_core_usage"], serde_json::json!([99.0, 97.5]));
+        assert!(!line.contains('\n'));
+
+        // No sensor: temp_c is null
+        let line = telemetry_line("torture", 1, None, 0, 0, &HashMap::new());
+        let v: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert!(v["temp_c"].is_null());
+    }
+}
@@ -0,0 +1,274 @@
+// Battery detection (laptops)
+// macOS: system_profiler SPPowerDataType, Linux: /sys/class/power_supply/BAT*, Windows: WMIC Win32_Battery
+
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+use std::process::Command;
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+use crate::profile::TimedOutput;
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::lang::Text;
+use crate::stress::{HealthStatus, Issue, IssueCode};
+
+/// Wear above this (% of design capacity lost) is reported as an issue
+pub const MAX_WEAR_PCT: f32 = 30.0;
+
+/// Battery state (fields are None when the OS doesn't report them)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct BatteryInfo {
+    pub charge_pct: Option<f32>,
+    pub charging: Option<bool>,
+    pub cycle_count: Option<u32>,
+    /// Full-charge capacity as % of design capacity
+    pub health_pct: Option<f32>,
+}
+
+impl BatteryInfo {
+    /// Detect the built-in battery, None on machines without one
+    pub fn new() -> Option<Self> {
+        #[cfg(target_os = "macos")]
+        {
+            get_macos_battery()
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            read_linux_battery(Path::new("/sys/class/power_supply"))
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            get_windows_battery()
+        }
+
+        #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+        {
+            None
+        }
+    }
+
+    /// One-line summary, e.g. "76% (on battery) | health 65% | 412 cycles"
+    pub fn display_localized(&self, text: &Text) -> String {
+        let mut parts = Vec::new();
+        let state = self.charging.map(|c| if c { text.battery_charging() } else { text.battery_discharging() });
+        match (self.charge_pct, state) {
+            (Some(pct), Some(state)) => parts.push(format!("{:.0}% ({})", pct, state)),
+            (Some(pct), None) => parts.push(format!("{:.0}%", pct)),
+            (None, Some(state)) => parts.push(state.to_string()),
+            (None, None) => {}
+        }
+        if let Some(health) = self.health_pct {
+            parts.push(format!("{} {:.0}%", text.battery_health(), health));
+        }
+        if let Some(cycles) = self.cycle_count {
+            parts.push(format!("{} {}", cycles, text.battery_cycles()));
+        }
+        if parts.is_empty() {
+            text.not_available().to_string()
+        } else {
+            parts.join(" | ")
+        }
+    }
+
+    /// Capacity lost since new (%)
+    pub fn wear_pct(&self) -> Option<f32> {
+        self.health_pct.map(|h| (100.0 - h).max(0.0))
+    }
+
+    /// IssuesDetected when the battery has lost more than MAX_WEAR_PCT of its capacity
+    pub fn health(&self) -> HealthStatus {
+        match self.wear_pct() {
+            Some(wear) if wear > MAX_WEAR_PCT => HealthStatus::IssuesDetected(vec![Issue::new(
+                IssueCode::BatteryWear,
+                format!(
+                    "Battery wear {:.0}% (holds {:.0}% of design capacity) - expect much shorter runtime",
+                    wear,
+                    100.0 - wear
+                ),
+            )]),
+            _ => HealthStatus::Healthy,
+        }
+    }
+}
+
+/// Full vs design capacity as %, None if either is missing
+fn health_from_capacity(full: Option<f64>, design: Option<f64>) -> Option<f32> {
+    match (full, design) {
+        (Some(full), Some(design)) if full > 0.0 && design > 0.0 => Some((full / design * 100.0) as f32),
+        _ => None,
+    }
+}
+
+// =============================================================================
+// macOS implementation
+// =============================================================================
+
+#[cfg(target_os = "macos")]
+fn get_macos_battery() -> Option<BatteryInfo> {
+    let output = Command::new("system_profiler")
+        .arg("SPPowerDataType")
+        .timed_output()
+        .ok()?;
+    parse_sp_power(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse `system_profiler SPPowerDataType` ("Cycle Count: 245", "Maximum Capacity: 87%", ...)
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_sp_power(output: &str) -> Option<BatteryInfo> {
+    if !output.contains("Battery Information") {
+        return None;
+    }
+    let value = |key: &str| {
+        output
+            .lines()
+            .find_map(|l| l.trim().strip_prefix(key))
+            .and_then(|v| v.strip_prefix(':'))
+            .map(|v| v.trim().trim_end_matches('%').trim().to_string())
+    };
+    let number = |key: &str| value(key).and_then(|v| v.parse::<f64>().ok());
+
+    // Apple Silicon reports "Maximum Capacity", older Intel Macs only the mAh figures
+    let health_pct = number("Maximum Capacity")
+        .map(|pct| pct as f32)
+        .or_else(|| health_from_capacity(number("Full Charge Capacity (mAh)"), number("Design Capacity (mAh)")));
+
+    Some(BatteryInfo {
+        charge_pct: number("State of Charge (%)").map(|pct| pct as f32),
+        charging: value("Charging").map(|v| v == "Yes"),
+        cycle_count: number("Cycle Count").map(|c| c as u32),
+        health_pct,
+    })
+}
+
+// =============================================================================
+// Linux implementation
+// =============================================================================
+
+/// First supply of type "Battery" under a power_supply root (BAT0, BAT1, CMB0...)
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn read_linux_battery(root: &Path) -> Option<BatteryInfo> {
+    let mut supplies: Vec<_> = fs::read_dir(root).ok()?.flatten().map(|e| e.path()).collect();
+    supplies.sort();
+    let dir = supplies
+        .into_iter()
+        .find(|p| fs::read_to_string(p.join("type")).is_ok_and(|t| t.trim() == "Battery"))?;
+
+    let read = |name: &str| fs::read_to_string(dir.join(name)).ok().map(|v| v.trim().to_string());
+    let number = |name: &str| read(name).and_then(|v| v.parse::<f64>().ok());
+
+    // Drivers expose either energy_* (µWh) or charge_* (µAh)
+    let health_pct = health_from_capacity(number("energy_full"), number("energy_full_design"))
+        .or_else(|| health_from_capacity(number("charge_full"), number("charge_full_design")));
+
+    Some(BatteryInfo {
+        charge_pct: number("capacity").map(|pct| pct as f32),
+        charging: read("status").map(|s| s == "Charging"),
+        // Many drivers report 0 when they don't track cycles
+        cycle_count: number("cycle_count").map(|c| c as u32).filter(|&c| c > 0),
+        health_pct,
+    })
+}
+
+// =============================================================================
+// Windows implementation
+// =============================================================================
+
+#[cfg(target_os = "windows")]
+fn get_windows_battery() -> Option<BatteryInfo> {
+    let output = Command::new("WMIC")
+        .args([
+            "Path",
+            "Win32_Battery",
+            "Get",
+            "BatteryStatus,DesignCapacity,EstimatedChargeRemaining,FullChargeCapacity",
+            "/format:list",
+        ])
+        .timed_output()
+        .ok()?;
+    parse_win32_battery(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse `WMIC ... /format:list` key=value lines (empty output = no battery)
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_win32_battery(output: &str) -> Option<BatteryInfo> {
+    let number = |key: &str| {
+        output
+            .lines()
+            .find_map(|l| l.trim().strip_prefix(key)?.strip_prefix('='))
+            .and_then(|v| v.trim().parse::<f64>().ok())
+    };
+    let status = number("BatteryStatus")?;
+
+    Some(BatteryInfo {
+        charge_pct: number("EstimatedChargeRemaining").map(|pct| pct as f32),
+        // 2 = on AC, 6-9 = charging; 1 = discharging, 3 = fully charged
+        charging: Some(matches!(status as u32, 2 | 6..=9)),
+        cycle_count: None,
+        health_pct: health_from_capacity(number("FullChargeCapacity"), number("DesignCapacity")),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sp_power() {
+        let output = "Power:\n\n    Battery Information:\n\n      Charge Information:\n          \
+                      State of Charge (%): 76\n          Charging: No\n      Health Information:\n          \
+                      Cycle Count: 412\n          Condition: Normal\n          Maximum Capacity: 65%\n";
+        let battery = parse_sp_power(output).unwrap();
+        assert_eq!(battery.charge_pct, Some(76.0));
+        assert_eq!(battery.charging, Some(false));
+        assert_eq!(battery.cycle_count, Some(412));
+        assert_eq!(battery.wear_pct(), Some(35.0));
+        assert!(matches!(battery.health(), HealthStatus::IssuesDetected(_)));
+        let text = Text::new(crate::lang::Language::English);
+        assert_eq!(battery.display_localized(&text), "76% (on battery) | health 65% | 412 cycles");
+
+        // Mac mini: no battery section
+        assert!(parse_sp_power("Power:\n\n    System Power Settings:\n").is_none());
+    }
+
+    #[test]
+    fn test_read_linux_battery() {
+        let root = std::env::temp_dir().join(format!("pchecker_power_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        let ac = root.join("AC");
+        fs::create_dir_all(&ac).unwrap();
+        fs::write(ac.join("type"), "Mains\n").unwrap();
+        assert!(read_linux_battery(&root).is_none());
+
+        let bat = root.join("BAT0");
+        fs::create_dir_all(&bat).unwrap();
+        fs::write(bat.join("type"), "Battery\n").unwrap();
+        fs::write(bat.join("capacity"), "93\n").unwrap();
+        fs::write(bat.join("status"), "Charging\n").unwrap();
+        fs::write(bat.join("cycle_count"), "0\n").unwrap();
+        fs::write(bat.join("energy_full"), "45000000\n").unwrap();
+        fs::write(bat.join("energy_full_design"), "50000000\n").unwrap();
+
+        let battery = read_linux_battery(&root).unwrap();
+        assert_eq!(battery.charge_pct, Some(93.0));
+        assert_eq!(battery.charging, Some(true));
+        assert_eq!(battery.cycle_count, None);
+        assert_eq!(battery.health_pct, Some(90.0));
+        assert_eq!(battery.health(), HealthStatus::Healthy);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_parse_win32_battery() {
+        let output = "\r\n\r\nBatteryStatus=1\r\nDesignCapacity=57000\r\nEstimatedChargeRemaining=54\r\nFullChargeCapacity=51300\r\n";
+        let battery = parse_win32_battery(output).unwrap();
+        assert_eq!(battery.charge_pct, Some(54.0));
+        assert_eq!(battery.charging, Some(false));
+        assert_eq!(battery.health_pct, Some(90.0));
+        assert!(parse_win32_battery("No Instance(s) Available.\r\n").is_none());
+    }
+}
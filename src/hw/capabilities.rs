@@ -0,0 +1,106 @@
+// Capability probe
+// What the health check will be able to measure on this machine (shown before tests run)
+
+use serde::Serialize;
+
+#[cfg(target_os = "linux")]
+use std::process::Command;
+#[cfg(target_os = "linux")]
+use crate::profile::TimedOutput;
+
+/// Whether a measurement is available
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum Capability {
+    Available,
+    Unavailable,
+    /// Works when run with sudo / as administrator
+    NeedsPrivileges,
+}
+
+impl Capability {
+    fn from_bool(available: bool) -> Self {
+        if available { Capability::Available } else { Capability::Unavailable }
+    }
+
+    /// Compact badge: ✓, ✗ or ⚠(sudo)
+    pub fn badge(&self) -> &'static str {
+        match self {
+            Capability::Available => "✓",
+            Capability::Unavailable => "✗",
+            Capability::NeedsPrivileges => "⚠(sudo)",
+        }
+    }
+}
+
+/// Measurements the health check depends on
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Capabilities {
+    pub cpu_temp: Capability,
+    pub gpu_temp: Capability,
+    pub smart: Capability,
+    pub gpu_compute: Capability,
+}
+
+impl Capabilities {
+    /// Probe sensors and tools (no tests)
+    pub fn probe(first_mount: Option<&str>) -> Self {
+        Self {
+            cpu_temp: Capability::from_bool(crate::sensors::get_cpu_temp().is_some()),
+            gpu_temp: Capability::from_bool(crate::stress::gpu::get_gpu_temp().is_some()),
+            smart: probe_smart(first_mount),
+            gpu_compute: Capability::from_bool(cfg!(feature = "gpu-compute")),
+        }
+    }
+
+    /// One-line badge row: `CPU-temp ✓  GPU-temp ✗  SMART ⚠(sudo)  GPU-compute ✓`
+    pub fn badge_row(&self) -> String {
+        [
+            ("CPU-temp", self.cpu_temp),
+            ("GPU-temp", self.gpu_temp),
+            ("SMART", self.smart),
+            ("GPU-compute", self.gpu_compute),
+        ]
+        .iter()
+        .map(|(name, cap)| format!("{} {}", name, cap.badge()))
+        .collect::<Vec<_>>()
+        .join("  ")
+    }
+}
+
+/// Linux only has real SMART data through smartctl as root
+#[cfg(target_os = "linux")]
+fn probe_smart(_first_mount: Option<&str>) -> Capability {
+    let has_smartctl = Command::new("smartctl").arg("--version").timed_output().is_ok_and(|o| o.status.success());
+    if !has_smartctl {
+        return Capability::Unavailable;
+    }
+
+    if crate::hw::deep::common::running_as_root() { Capability::Available } else { Capability::NeedsPrivileges }
+}
+
+/// macOS diskutil / Windows WMI report status without privileges
+#[cfg(not(target_os = "linux"))]
+fn probe_smart(first_mount: Option<&str>) -> Capability {
+    use crate::stress::disk::smart::{get_smart_data, SmartStatus};
+
+    match first_mount {
+        Some(mount) => Capability::from_bool(get_smart_data(mount, false).status != SmartStatus::Unknown),
+        None => Capability::Unavailable,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_badge_row() {
+        let caps = Capabilities {
+            cpu_temp: Capability::Available,
+            gpu_temp: Capability::Unavailable,
+            smart: Capability::NeedsPrivileges,
+            gpu_compute: Capability::Available,
+        };
+        assert_eq!(caps.badge_row(), "CPU-temp ✓  GPU-temp ✗  SMART ⚠(sudo)  GPU-compute ✓");
+    }
+}
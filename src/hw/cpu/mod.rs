@@ -1,10 +1,15 @@
 // CPU detection module
 
+use serde::Serialize;
 use sysinfo::{System, CpuRefreshKind, RefreshKind};
 
+#[derive(Clone, Serialize)]
 pub struct CpuInfo {
     pub model: String,
     pub cores: usize,
+    /// Performance / efficiency logical CPUs on hybrid CPUs (None when all cores are the same type)
+    pub p_cores: Option<usize>,
+    pub e_cores: Option<usize>,
 }
 
 impl CpuInfo {
@@ -16,12 +21,156 @@ impl CpuInfo {
 
         let cpus = sys.cpus();
         let first_cpu = cpus.first();
+        let (p_cores, e_cores) = match detect_core_types() {
+            Some((p, e)) => (Some(p), Some(e)),
+            None => (None, None),
+        };
 
         Self {
             model: first_cpu
                 .map(|c| c.brand().to_string())
                 .unwrap_or_else(|| "Unknown".to_string()),
             cores: cpus.len(),
+            p_cores,
+            e_cores,
         }
     }
+
+    /// "8P + 4E" on hybrid CPUs
+    pub fn core_split(&self) -> Option<String> {
+        Some(format!("{}P + {}E", self.p_cores?, self.e_cores?))
+    }
+
+    /// "12 cores, 8P + 4E" (just "12 cores" when the cores are all one type)
+    pub fn cores_display(&self, cores_label: &str) -> String {
+        match self.core_split() {
+            Some(split) => format!("{} {}, {}", self.cores, cores_label, split),
+            None => format!("{} {}", self.cores, cores_label),
+        }
+    }
+}
+
+/// (P, E) logical CPU counts, None on non-hybrid CPUs or when the OS doesn't tell
+fn detect_core_types() -> Option<(usize, usize)> {
+    #[cfg(target_os = "macos")]
+    {
+        detect_macos_core_types()
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        detect_linux_core_types()
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    {
+        None
+    }
+}
+
+// =============================================================================
+// macOS implementation
+// =============================================================================
+
+/// Apple Silicon: perflevel0 = performance cluster, perflevel1 = efficiency cluster
+#[cfg(target_os = "macos")]
+fn detect_macos_core_types() -> Option<(usize, usize)> {
+    use crate::profile::TimedOutput;
+    use std::process::Command;
+
+    let sysctl = |key: &str| -> Option<usize> {
+        let output = Command::new("sysctl").args(["-n", key]).timed_output().ok()?;
+        String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+    };
+    if sysctl("hw.nperflevels")? < 2 {
+        return None;
+    }
+    Some((sysctl("hw.perflevel0.logicalcpu")?, sysctl("hw.perflevel1.logicalcpu")?))
+}
+
+// =============================================================================
+// Linux implementation
+// =============================================================================
+
+#[cfg(target_os = "linux")]
+fn detect_linux_core_types() -> Option<(usize, usize)> {
+    // Intel hybrid: the kernel registers separate PMUs for P-cores (cpu_core) and E-cores (cpu_atom)
+    let read = |path: &str| std::fs::read_to_string(path).ok();
+    if let (Some(p), Some(e)) = (read("/sys/devices/cpu_core/cpus"), read("/sys/devices/cpu_atom/cpus")) {
+        return Some((count_cpu_list(&p)?, count_cpu_list(&e)?));
+    }
+
+    // Otherwise (ARM big.LITTLE, AMD Zen c-cores): cluster the CPUs by maximum frequency
+    let max_freqs: Vec<u64> = std::fs::read_dir("/sys/devices/system/cpu")
+        .ok()?
+        .flatten()
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.strip_prefix("cpu").is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+        })
+        .filter_map(|entry| std::fs::read_to_string(entry.path().join("cpufreq/cpuinfo_max_freq")).ok())
+        .filter_map(|freq| freq.trim().parse().ok())
+        .collect();
+    split_by_max_freq(&max_freqs)
+}
+
+/// Number of CPUs in a sysfs CPU list ("0-7,16-23" = 16)
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn count_cpu_list(list: &str) -> Option<usize> {
+    let mut count = 0;
+    for part in list.trim().split(',').filter(|p| !p.is_empty()) {
+        count += match part.split_once('-') {
+            Some((start, end)) => end.parse::<usize>().ok()?.checked_sub(start.parse().ok()?)? + 1,
+            None => part.parse::<usize>().map(|_| 1).ok()?,
+        };
+    }
+    (count > 0).then_some(count)
+}
+
+/// Below this share of the fastest core's max clock a core counts as an efficiency core
+/// (favored P-cores differ by a few percent, E-cores by 20%+)
+const E_CORE_FREQ_RATIO: f64 = 0.85;
+
+/// (P, E) counts from per-CPU max frequencies, None when all CPUs are in one cluster
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn split_by_max_freq(max_freqs: &[u64]) -> Option<(usize, usize)> {
+    let fastest = *max_freqs.iter().max()? as f64;
+    let e_cores = max_freqs.iter().filter(|&&f| (f as f64) < fastest * E_CORE_FREQ_RATIO).count();
+    (e_cores > 0).then_some((max_freqs.len() - e_cores, e_cores))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_cpu_list() {
+        assert_eq!(count_cpu_list("0-15\n"), Some(16));
+        assert_eq!(count_cpu_list("0-7,16-23"), Some(16));
+        assert_eq!(count_cpu_list("3"), Some(1));
+        assert_eq!(count_cpu_list(""), None);
+        assert_eq!(count_cpu_list("7-3"), None);
+    }
+
+    #[test]
+    fn test_split_by_max_freq() {
+        // i7-12700H: 6 P-cores x2 threads at 4.7GHz (two favored at 4.8), 8 E-cores at 3.5GHz
+        let mut freqs = vec![4_800_000, 4_800_000];
+        freqs.extend([4_700_000; 10]);
+        freqs.extend([3_500_000; 8]);
+        assert_eq!(split_by_max_freq(&freqs), Some((12, 8)));
+
+        // Homogeneous CPU with boost variance
+        assert_eq!(split_by_max_freq(&[5_000_000, 4_900_000, 4_850_000]), None);
+        assert_eq!(split_by_max_freq(&[]), None);
+    }
+
+    #[test]
+    fn test_cores_display() {
+        let mut cpu = CpuInfo { model: "Intel Core i7-12700H".to_string(), cores: 20, p_cores: Some(12), e_cores: Some(8) };
+        assert_eq!(cpu.cores_display("cores"), "20 cores, 12P + 8E");
+        cpu.p_cores = None;
+        assert_eq!(cpu.cores_display("cores"), "20 cores");
+    }
 }
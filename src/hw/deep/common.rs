@@ -1,7 +1,16 @@
 // Common structs for deep hardware information
 // Shared across all platforms
 
+use std::ffi::OsStr;
+use std::path::PathBuf;
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+use std::process::Command;
+
 use serde::{Serialize, Deserialize};
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+use crate::profile::TimedOutput;
+use crate::lang::Text;
+#[allow(unused_imports)]  // Trait methods are only called from platform-specific arms
 use crate::hw::deep::{cpu::DeepCpuInfo, ram::DeepRamInfo, disk::DeepDiskInfo, gpu::DeepGpuInfo};
 
 /// CPU cache information
@@ -90,7 +99,13 @@ impl PlatformProbe {
             let probe = MacOsDeepProbe;
             probe.get_cache_info()
         }
-        #[cfg(not(target_os = "macos"))]
+        #[cfg(target_os = "linux")]
+        {
+            use crate::hw::deep::platform::linux::LinuxDeepProbe;
+            let probe = LinuxDeepProbe;
+            probe.get_cache_info()
+        }
+        #[cfg(not(any(target_os = "macos", target_os = "linux")))]
         { None }
     }
 
@@ -101,7 +116,13 @@ impl PlatformProbe {
             let probe = MacOsDeepProbe;
             probe.get_instruction_sets()
         }
-        #[cfg(not(target_os = "macos"))]
+        #[cfg(target_os = "linux")]
+        {
+            use crate::hw::deep::platform::linux::LinuxDeepProbe;
+            let probe = LinuxDeepProbe;
+            probe.get_instruction_sets()
+        }
+        #[cfg(not(any(target_os = "macos", target_os = "linux")))]
         { None }
     }
 
@@ -113,7 +134,7 @@ impl PlatformProbe {
             probe.get_tdp(model)
         }
         #[cfg(not(target_os = "macos"))]
-        { None }
+        { let _ = model; None }
     }
 
     pub fn get_dimm_slots(&self) -> Vec<DimmSlot> {
@@ -123,7 +144,13 @@ impl PlatformProbe {
             let probe = MacOsDeepProbe;
             probe.get_dimm_slots()
         }
-        #[cfg(not(target_os = "macos"))]
+        #[cfg(target_os = "linux")]
+        {
+            use crate::hw::deep::platform::linux::LinuxDeepProbe;
+            let probe = LinuxDeepProbe;
+            probe.get_dimm_slots()
+        }
+        #[cfg(not(any(target_os = "macos", target_os = "linux")))]
         { vec![] }
     }
 
@@ -221,3 +248,68 @@ impl PlatformProbe {
         { None }
     }
 }
+
+/// What to do when a detail came back empty because an external tool is missing or needs root
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[allow(dead_code)]  // Each platform only raises some of the hints
+pub enum ToolHint {
+    InstallSmartctl,
+    SudoSmart,
+    InstallDmidecode,
+    SudoDmidecode,
+    SudoPowermetrics,
+    InstallNvidiaSmi,
+}
+
+impl ToolHint {
+    pub fn message<'a>(&self, text: &'a Text) -> &'a str {
+        match self {
+            ToolHint::InstallSmartctl => text.hint_install_smartctl(),
+            ToolHint::SudoSmart => text.hint_sudo_smart(),
+            ToolHint::InstallDmidecode => text.hint_install_dmidecode(),
+            ToolHint::SudoDmidecode => text.hint_sudo_dmidecode(),
+            ToolHint::SudoPowermetrics => text.hint_sudo_powermetrics(),
+            ToolHint::InstallNvidiaSmi => text.hint_install_nvidia_smi(),
+        }
+    }
+}
+
+/// Whether an external tool is on PATH (like `which`)
+#[cfg_attr(target_os = "macos", allow(dead_code))]
+pub fn tool_available(name: &str) -> bool {
+    std::env::var_os("PATH").is_some_and(|path| find_in_path(name, &path).is_some())
+}
+
+/// First `name` (`name.exe` on Windows) in the directories of a PATH value
+fn find_in_path(name: &str, path: &OsStr) -> Option<PathBuf> {
+    let file_name = format!("{}{}", name, std::env::consts::EXE_SUFFIX);
+    std::env::split_paths(path).map(|dir| dir.join(&file_name)).find(|candidate| candidate.is_file())
+}
+
+/// Whether pchecker runs as root (sudo)
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub fn running_as_root() -> bool {
+    Command::new("id")
+        .arg("-u")
+        .timed_output()
+        .is_ok_and(|o| String::from_utf8_lossy(&o.stdout).trim() == "0")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_in_path() {
+        let dir = std::env::temp_dir().join(format!("pchecker_path_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file_name = format!("smartctl{}", std::env::consts::EXE_SUFFIX);
+        std::fs::write(dir.join(&file_name), "").unwrap();
+
+        let path = std::env::join_paths([PathBuf::from("/nonexistent"), dir.clone()]).unwrap();
+        assert_eq!(find_in_path("smartctl", &path), Some(dir.join(&file_name)));
+        assert_eq!(find_in_path("dmidecode", &path), None);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -4,6 +4,7 @@
 use crate::hw::deep::common::{CacheInfo, InstructionSets};
 
 /// Trait for deep CPU information
+#[allow(dead_code)]  // Only implemented by the macOS and Linux probes so far
 pub trait DeepCpuInfo {
     /// Get CPU cache information (L1, L2, L3)
     fn get_cache_info(&self) -> Option<CacheInfo>;
@@ -54,6 +55,15 @@ pub fn estimate_tdp_from_model(model: &str) -> Option<u32> {
     None
 }
 
+/// Expected prime-workload ops/sec per logical thread as (low, high), None for unknown models
+/// Spans the --compare-to-model baseline by the underperform margin in both directions
+pub fn expected_ops_range(model: &str) -> Option<(f64, f64)> {
+    use crate::stress::expected::{expected_cpu_ops, UNDERPERFORM_THRESHOLD_PCT};
+    let baseline = expected_cpu_ops(model, 1)?;
+    let margin = 1.0 - UNDERPERFORM_THRESHOLD_PCT / 100.0;
+    Some((baseline * (1.0 - margin), baseline * (1.0 + margin)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,4 +91,12 @@ mod tests {
     fn test_estimate_tdp_unknown() {
         assert_eq!(estimate_tdp_from_model("Unknown CPU Model XYZ"), None);
     }
+
+    #[test]
+    fn test_expected_ops_range() {
+        let (low, high) = expected_ops_range("AMD Ryzen 7 5800X 8-Core Processor").unwrap();
+        assert!((low - 208.0).abs() < 0.01);
+        assert!((high - 312.0).abs() < 0.01);
+        assert_eq!(expected_ops_range("Unknown CPU Model XYZ"), None);
+    }
 }
@@ -4,6 +4,7 @@
 use crate::hw::deep::common::DiskHealth;
 
 /// Trait for deep disk information
+#[allow(dead_code)]  // Only implemented by the macOS probe so far
 pub trait DeepDiskInfo {
     /// Get disk firmware version
     fn get_firmware(&self) -> Option<String>;
@@ -4,6 +4,7 @@
 use crate::hw::deep::common::{GpuDriver, PcieLink};
 
 /// Trait for deep GPU information
+#[allow(dead_code)]  // Only implemented by the macOS probe so far
 pub trait DeepGpuInfo {
     /// Get GPU driver version
     #[allow(dead_code)]  // Reserved for future GPU driver features
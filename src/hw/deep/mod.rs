@@ -13,11 +13,15 @@ pub mod platform;
 #[allow(unused_imports)]  // Types reserved for future deep info features
 pub use common::{
     CacheInfo, InstructionSets, DimmSlot, DiskHealth, GpuDriver, PcieLink,
-    get_platform_probe, PlatformProbe,
+    get_platform_probe, PlatformProbe, ToolHint,
 };
 
 // Re-export traits
+#[allow(unused_imports)]  // Only used by platform probes so far
 pub use cpu::{DeepCpuInfo, estimate_tdp_from_model};
+#[allow(unused_imports)]
 pub use ram::DeepRamInfo;
+#[allow(unused_imports)]
 pub use disk::DeepDiskInfo;
+#[allow(unused_imports)]
 pub use gpu::DeepGpuInfo;
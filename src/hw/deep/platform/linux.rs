@@ -0,0 +1,344 @@
+// Linux Deep Hardware Probe
+// Reads CPU details from sysfs and /proc/cpuinfo, memory modules from dmidecode/EDAC
+
+use std::path::Path;
+use std::process::Command;
+use crate::profile::TimedOutput;
+use crate::hw::deep::{
+    common::{CacheInfo, InstructionSets, DimmSlot},
+    {DeepCpuInfo, DeepRamInfo},
+    estimate_tdp_from_model,
+};
+
+/// Linux deep hardware probe
+pub struct LinuxDeepProbe;
+
+// ========== CPU Implementation ==========
+
+impl DeepCpuInfo for LinuxDeepProbe {
+    fn get_cache_info(&self) -> Option<CacheInfo> {
+        // One indexN directory per cache visible to cpu0
+        let entries = std::fs::read_dir("/sys/devices/system/cpu/cpu0/cache").ok()?;
+        let caches: Vec<SysfsCache> = entries
+            .flatten()
+            .filter(|e| e.file_name().to_string_lossy().starts_with("index"))
+            .filter_map(|e| {
+                let read = |name: &str| std::fs::read_to_string(e.path().join(name)).ok();
+                Some(SysfsCache {
+                    level: read("level")?.trim().parse().ok()?,
+                    cache_type: read("type")?.trim().to_string(),
+                    size_kb: parse_cache_size_kb(&read("size")?)?,
+                })
+            })
+            .collect();
+
+        if caches.is_empty() {
+            return None;
+        }
+        Some(cache_info_from_sysfs(&caches))
+    }
+
+    fn get_instruction_sets(&self) -> Option<InstructionSets> {
+        let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").ok()?;
+        let features = parse_cpuinfo_features(&cpuinfo);
+        if features.is_empty() {
+            return None;
+        }
+        Some(InstructionSets { features })
+    }
+
+    fn get_tdp(&self, model: &str) -> Option<u32> {
+        estimate_tdp_from_model(model)
+    }
+}
+
+// ========== RAM Implementation ==========
+
+impl DeepRamInfo for LinuxDeepProbe {
+    fn get_dimm_slots(&self) -> Vec<DimmSlot> {
+        // dmidecode needs root; EDAC sysfs is readable by anyone but has fewer details
+        let from_dmidecode = Command::new("dmidecode")
+            .args(["--type", "17"])
+            .timed_output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| parse_dmidecode_memory(&String::from_utf8_lossy(&output.stdout)))
+            .unwrap_or_default();
+
+        if from_dmidecode.is_empty() {
+            read_edac_dimms(Path::new("/sys/devices/system/edac/mc"))
+        } else {
+            from_dmidecode
+        }
+    }
+}
+
+/// Parse `dmidecode --type 17` output, skipping empty slots
+fn parse_dmidecode_memory(output: &str) -> Vec<DimmSlot> {
+    output
+        .split("Memory Device")
+        .skip(1)
+        .filter_map(|device| {
+            let field = |name: &str| {
+                device.lines().find_map(|line| {
+                    let (key, value) = line.split_once(':')?;
+                    let value = value.trim();
+                    let placeholder = value.is_empty()
+                        || ["Unknown", "Not Specified", "Not Provided"].contains(&value);
+                    (key.trim() == name && !placeholder).then(|| value.to_string())
+                })
+            };
+
+            let size_gb = parse_dmidecode_size_gb(&field("Size")?)?;
+            Some((size_gb, field))
+        })
+        .enumerate()
+        .map(|(id, (size_gb, field))| DimmSlot {
+            id,
+            bank: field("Locator").or_else(|| field("Bank Locator")).unwrap_or_else(|| "Unknown".to_string()),
+            size_gb,
+            type_: field("Type").unwrap_or_else(|| "Unknown".to_string()),
+            // "3200 MT/s" or "3200 MHz", prefer the configured speed
+            speed_mhz: field("Configured Memory Speed")
+                .or_else(|| field("Speed"))
+                .and_then(|s| s.split_whitespace().next()?.parse().ok()),
+            manufacturer: field("Manufacturer"),
+            part_number: field("Part Number"),
+        })
+        .collect()
+}
+
+/// Parse a dmidecode size ("16 GB", "8192 MB"); "No Module Installed" gives None
+fn parse_dmidecode_size_gb(s: &str) -> Option<f64> {
+    let mut parts = s.split_whitespace();
+    let num: f64 = parts.next()?.parse().ok()?;
+    match parts.next()? {
+        "TB" => Some(num * 1024.0),
+        "GB" => Some(num),
+        "MB" => Some(num / 1024.0),
+        _ => None,
+    }
+}
+
+/// Read DIMMs from EDAC sysfs (`mcN/dimmN/size` in MB, `dimm_mem_type`, `dimm_label`)
+fn read_edac_dimms(edac_root: &Path) -> Vec<DimmSlot> {
+    let mut dimm_dirs: Vec<_> = std::fs::read_dir(edac_root)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .flat_map(|mc| std::fs::read_dir(mc.path()).into_iter().flatten().flatten())
+        .filter(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            name.starts_with("dimm") || name.starts_with("rank")
+        })
+        .map(|e| e.path())
+        .collect();
+    dimm_dirs.sort();
+
+    dimm_dirs
+        .iter()
+        .filter_map(|dir| {
+            let read = |name: &str| std::fs::read_to_string(dir.join(name)).ok().map(|s| s.trim().to_string());
+            let size_mb: f64 = read("size")?.parse().ok()?;
+            (size_mb > 0.0).then(|| (size_mb / 1024.0, read("dimm_mem_type"), read("dimm_label")))
+        })
+        .enumerate()
+        .map(|(id, (size_gb, mem_type, label))| DimmSlot {
+            id,
+            bank: label.filter(|l| !l.is_empty()).unwrap_or_else(|| "Unknown".to_string()),
+            size_gb,
+            type_: mem_type.unwrap_or_else(|| "Unknown".to_string()),
+            speed_mhz: None,
+            manufacturer: None,
+            part_number: None,
+        })
+        .collect()
+}
+
+/// x86 `flags` to display names, in display order
+const X86_FEATURES: &[(&str, &str)] = &[
+    ("lm", "x86-64"),
+    ("sse4_2", "SSE4.2"),
+    ("avx", "AVX"),
+    ("avx2", "AVX2"),
+    ("avx512f", "AVX-512"),
+    ("aes", "AES"),
+    ("sha_ni", "SHA"),
+    ("vmx", "VT-x"),
+    ("svm", "AMD-V"),
+];
+
+/// ARM64 `Features` to display names, in display order
+const ARM_FEATURES: &[(&str, &str)] = &[
+    ("asimd", "NEON"),
+    ("fphp", "FP16"),
+    ("aes", "AES"),
+    ("sha2", "SHA"),
+    ("sha3", "SHA3"),
+    ("sve", "SVE"),
+];
+
+/// Map the first CPU's `flags` (x86) or `Features` (ARM64) line from /proc/cpuinfo
+fn parse_cpuinfo_features(cpuinfo: &str) -> Vec<String> {
+    let line = cpuinfo.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        matches!(key.trim(), "flags" | "Features").then_some((key.trim(), value))
+    });
+    let Some((key, value)) = line else { return vec![] };
+
+    let flags: Vec<&str> = value.split_whitespace().collect();
+    let (arch, table) = if key == "flags" { (None, X86_FEATURES) } else { (Some("ARM64"), ARM_FEATURES) };
+
+    arch.into_iter()
+        .chain(table.iter().filter(|(flag, _)| flags.contains(flag)).map(|(_, name)| *name))
+        .map(String::from)
+        .collect()
+}
+
+/// One `/sys/devices/system/cpu/cpu0/cache/indexN` entry
+struct SysfsCache {
+    level: u8,
+    cache_type: String,
+    size_kb: u32,
+}
+
+/// Parse a sysfs cache size ("48K", "2048K", "32M") into KB
+fn parse_cache_size_kb(s: &str) -> Option<u32> {
+    let s = s.trim();
+    if let Some(kb) = s.strip_suffix('K') {
+        kb.parse().ok()
+    } else if let Some(mb) = s.strip_suffix('M') {
+        mb.parse::<u32>().ok().map(|mb| mb * 1024)
+    } else {
+        s.parse::<u32>().ok().map(|bytes| bytes / 1024)
+    }
+}
+
+/// Pick one size per level; L1 reports the data cache (instruction cache if there is none)
+fn cache_info_from_sysfs(caches: &[SysfsCache]) -> CacheInfo {
+    let size_at = |level: u8| {
+        caches
+            .iter()
+            .filter(|c| c.level == level)
+            .max_by_key(|c| (c.cache_type != "Instruction", c.size_kb))
+            .map(|c| c.size_kb)
+    };
+
+    CacheInfo {
+        l1_kb: size_at(1),
+        l2_kb: size_at(2),
+        l3_kb: size_at(3),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cache_size_kb() {
+        assert_eq!(parse_cache_size_kb("48K\n"), Some(48));
+        assert_eq!(parse_cache_size_kb("32M"), Some(32 * 1024));
+        assert_eq!(parse_cache_size_kb("65536"), Some(64));
+        assert_eq!(parse_cache_size_kb(""), None);
+    }
+
+    #[test]
+    fn test_parse_cpuinfo_features() {
+        let x86 = "processor\t: 0\nmodel name\t: Intel Xeon\nflags\t\t: fpu lm sse4_2 avx avx2 aes vmx\n\nprocessor\t: 1\nflags\t\t: fpu\n";
+        assert_eq!(parse_cpuinfo_features(x86), ["x86-64", "SSE4.2", "AVX", "AVX2", "AES", "VT-x"]);
+
+        let arm = "processor\t: 0\nFeatures\t: fp asimd evtstrm aes pmull sha1 sha2 crc32\nCPU implementer\t: 0x41\n";
+        assert_eq!(parse_cpuinfo_features(arm), ["ARM64", "NEON", "AES", "SHA"]);
+
+        assert!(parse_cpuinfo_features("processor\t: 0\n").is_empty());
+    }
+
+    #[test]
+    fn test_parse_dmidecode_memory() {
+        let output = "\
+# dmidecode 3.3
+Handle 0x0040, DMI type 17, 92 bytes
+Memory Device
+\tTotal Width: 64 bits
+\tSize: 16 GB
+\tLocator: DIMM A
+\tBank Locator: BANK 0
+\tType: DDR4
+\tSpeed: 3200 MT/s
+\tManufacturer: Samsung
+\tPart Number: M471A2K43DB1-CWE
+\tConfigured Memory Speed: 2933 MT/s
+
+Handle 0x0041, DMI type 17, 92 bytes
+Memory Device
+\tSize: No Module Installed
+\tLocator: DIMM B
+\tType: Unknown
+\tManufacturer: Not Specified
+
+Handle 0x0042, DMI type 17, 92 bytes
+Memory Device
+\tSize: 8192 MB
+\tLocator: DIMM C
+\tType: DDR4
+\tSpeed: Unknown
+\tManufacturer: Unknown
+";
+        let slots = parse_dmidecode_memory(output);
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots[0].bank, "DIMM A");
+        assert_eq!(slots[0].size_gb, 16.0);
+        assert_eq!(slots[0].type_, "DDR4");
+        assert_eq!(slots[0].speed_mhz, Some(2933));
+        assert_eq!(slots[0].manufacturer.as_deref(), Some("Samsung"));
+        assert_eq!(slots[0].part_number.as_deref(), Some("M471A2K43DB1-CWE"));
+        assert_eq!(slots[1].id, 1);
+        assert_eq!(slots[1].size_gb, 8.0);
+        assert_eq!(slots[1].speed_mhz, None);
+        assert_eq!(slots[1].manufacturer, None);
+
+        // Non-root runs print only a permission error
+        assert!(parse_dmidecode_memory("/sys/firmware/dmi/tables/smbios_entry_point: Permission denied").is_empty());
+    }
+
+    #[test]
+    fn test_read_edac_dimms() {
+        let root = std::env::temp_dir().join(format!("pchecker_edac_{}", std::process::id()));
+        let dimm = root.join("mc0").join("dimm0");
+        std::fs::create_dir_all(&dimm).unwrap();
+        std::fs::write(dimm.join("size"), "8192\n").unwrap();
+        std::fs::write(dimm.join("dimm_mem_type"), "Unbuffered-DDR4\n").unwrap();
+        std::fs::write(dimm.join("dimm_label"), "CPU_SrcID#0_MC#0_Chan#0_DIMM#0\n").unwrap();
+        let empty = root.join("mc0").join("dimm1");
+        std::fs::create_dir_all(&empty).unwrap();
+        std::fs::write(empty.join("size"), "0\n").unwrap();
+
+        let slots = read_edac_dimms(&root);
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(slots.len(), 1);
+        assert_eq!(slots[0].size_gb, 8.0);
+        assert_eq!(slots[0].type_, "Unbuffered-DDR4");
+        assert_eq!(slots[0].bank, "CPU_SrcID#0_MC#0_Chan#0_DIMM#0");
+
+        assert!(read_edac_dimms(Path::new("/nonexistent/edac")).is_empty());
+    }
+
+    #[test]
+    fn test_cache_info_from_sysfs() {
+        let cache = |level, cache_type: &str, size_kb| SysfsCache { level, cache_type: cache_type.to_string(), size_kb };
+        let info = cache_info_from_sysfs(&[
+            cache(1, "Data", 48),
+            cache(1, "Instruction", 64),
+            cache(2, "Unified", 2048),
+            cache(3, "Unified", 107_520),
+        ]);
+        assert_eq!(info.l1_kb, Some(48));
+        assert_eq!(info.l2_kb, Some(2048));
+        assert_eq!(info.l3_kb, Some(107_520));
+
+        let info = cache_info_from_sysfs(&[cache(1, "Instruction", 32)]);
+        assert_eq!(info.l1_kb, Some(32));
+        assert_eq!(info.l3_kb, None);
+    }
+}
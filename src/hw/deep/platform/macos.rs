@@ -2,6 +2,7 @@
 // Uses system_profiler and sysctl to get detailed hardware info
 
 use std::process::Command;
+use crate::profile::TimedOutput;
 use crate::hw::deep::{
     common::{CacheInfo, InstructionSets, DimmSlot, DiskHealth, GpuDriver, PcieLink},
     {DeepCpuInfo, DeepRamInfo, DeepDiskInfo, DeepGpuInfo},
@@ -69,7 +70,7 @@ impl DeepRamInfo for MacOsDeepProbe {
     fn get_dimm_slots(&self) -> Vec<DimmSlot> {
         let output = Command::new("system_profiler")
             .args(["SPMemoryDataType", "-json"])
-            .output();
+            .timed_output();
 
         match output {
             Ok(result) => parse_macos_ram_json(&String::from_utf8_lossy(&result.stdout)),
@@ -191,7 +192,7 @@ impl DeepDiskInfo for MacOsDeepProbe {
         // Use diskutil to get firmware version
         let output = Command::new("diskutil")
             .args(["info", "-plist", "/"])
-            .output()
+            .timed_output()
             .ok()?;
 
         let _plist = String::from_utf8_lossy(&output.stdout);
@@ -233,7 +234,7 @@ impl DeepGpuInfo for MacOsDeepProbe {
         // Get Metal version from system_profiler
         let output = Command::new("system_profiler")
             .args(["SPDisplaysDataType", "-json"])
-            .output()
+            .timed_output()
             .ok()?;
 
         let json = String::from_utf8_lossy(&output.stdout);
@@ -269,7 +270,7 @@ fn get_sysctl(key: &str) -> Option<String> {
     let output = Command::new("sysctl")
         .arg("-n")
         .arg(key)
-        .output()
+        .timed_output()
         .ok()?;
 
     let value = String::from_utf8_lossy(&output.stdout);
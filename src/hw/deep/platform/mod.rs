@@ -1,8 +1,15 @@
 // Platform-specific deep hardware probes
 
+#[cfg(target_os = "macos")]
 pub mod macos;
 
+#[cfg(target_os = "linux")]
+pub mod linux;
+
 // Re-export platform probe (unused but reserved for future direct access)
 #[allow(unused_imports)]
 #[cfg(target_os = "macos")]
 pub use macos::MacOsDeepProbe;
+#[allow(unused_imports)]
+#[cfg(target_os = "linux")]
+pub use linux::LinuxDeepProbe;
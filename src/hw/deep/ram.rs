@@ -4,6 +4,7 @@
 use crate::hw::deep::common::DimmSlot;
 
 /// Trait for deep RAM information
+#[allow(dead_code)]  // Only implemented by the macOS and Linux probes so far
 pub trait DeepRamInfo {
     /// Get all DIMM slots information
     fn get_dimm_slots(&self) -> Vec<DimmSlot>;
@@ -2,14 +2,17 @@
 
 use sysinfo::Disks;
 use std::collections::HashSet;
+use serde::Serialize;
 
-#[derive(Clone)]
+#[derive(Clone, Serialize)]
 pub struct DiskInfo {
     pub name: String,
     pub total_gb: f64,
     pub used_gb: f64,
     pub available_gb: f64,
     pub mount_point: String,
+    /// Filesystem type as reported by the OS (apfs, ext4, NTFS, ...)
+    pub file_system: String,
 }
 
 impl DiskInfo {
@@ -37,6 +40,7 @@ impl DiskInfo {
                     used_gb: used_bytes as f64 / 1024.0 / 1024.0 / 1024.0,
                     available_gb: available_bytes as f64 / 1024.0 / 1024.0 / 1024.0,
                     mount_point: disk.mount_point().to_string_lossy().to_string(),
+                    file_system: disk.file_system().to_string_lossy().to_string(),
                 });
             }
         }
@@ -4,7 +4,7 @@
 mod common;
 mod platform;
 
-pub use common::GpuInfo;
+pub use common::{GpuInfo, GpuVendor};
 
 impl GpuInfo {
     /// Detect GPU using platform-specific commands
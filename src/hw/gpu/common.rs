@@ -1,9 +1,11 @@
 // Common GPU types and traits
 
+use serde::Serialize;
+
 use crate::lang::Text;
 
 /// GPU type classification
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum GpuType {
     Integrated,
     Discrete,
@@ -28,37 +30,82 @@ impl GpuType {
     }
 
     /// Detect GPU type from model name
+    /// Works on marketing names ("NVIDIA GeForce RTX 3060") and lspci names
+    /// ("Intel Corporation DG2 [Arc A770]", "[AMD/ATI] Cezanne [Radeon Vega Series]")
     pub fn from_model(model: &str) -> Self {
         let model_lower = model.to_lowercase();
+        let words: Vec<&str> = model_lower.split(|c: char| !c.is_ascii_alphanumeric()).collect();
+        let has = |s: &str| model_lower.contains(s);
 
-        // Integrated GPUs
-        if model_lower.contains("intel")
-            || model_lower.contains("integrated")
-            || model_lower.contains("uhd")
-            || model_lower.contains("iris")
-            || model_lower.contains("graphics")
-            || model_lower.contains("apple m")  // Apple Silicon = integrated
+        // Apple Silicon shares the SoC with the CPU
+        if has("apple m") {
+            return GpuType::Integrated;
+        }
+
+        // Discrete cards, checked before vendor names (Intel Arc is discrete, "Radeon RX Vega" too)
+        if words.contains(&"arc")
+            || has("nvidia")
+            || has("geforce")
+            || has("quadro")
+            || has("rtx")
+            || has("gtx")
+            || words.contains(&"rx")
+            || has("radeon pro")
+            || has("firepro")
         {
-            GpuType::Integrated
+            return GpuType::Discrete;
         }
-        // Discrete GPUs
-        else if model_lower.contains("nvidia")
-            || model_lower.contains("amd")
-            || model_lower.contains("radeon")
-            || model_lower.contains("geforce")
-            || model_lower.contains("rtx")
-            || model_lower.contains("gtx")
-            || model_lower.contains("rx ")
+
+        // Integrated: Intel iGPUs and AMD APUs ("AMD Radeon(TM) Graphics", "Radeon Vega 8")
+        if has("intel")
+            || has("uhd")
+            || has("iris")
+            || has("integrated")
+            || has("graphics")
+            || words.contains(&"vega")
         {
-            GpuType::Discrete
+            return GpuType::Integrated;
         }
-        else {
+
+        if has("amd") || has("radeon") {
+            GpuType::Discrete
+        } else {
             GpuType::Unknown
         }
     }
 }
 
-#[derive(Clone)]
+/// GPU maker, decides which vendor tool (nvidia-smi, amdgpu sysfs) describes the GPU under test
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GpuVendor {
+    Nvidia,
+    Amd,
+    Intel,
+    Apple,
+    Unknown,
+}
+
+impl GpuVendor {
+    /// Detect the vendor from a marketing or lspci model name
+    pub fn from_model(model: &str) -> Self {
+        let model_lower = model.to_lowercase();
+        let has = |s: &str| model_lower.contains(s);
+
+        if has("nvidia") || has("geforce") || has("quadro") || has("tesla") {
+            GpuVendor::Nvidia
+        } else if has("amd") || has("radeon") || has("ati ") || has("firepro") {
+            GpuVendor::Amd
+        } else if has("intel") {
+            GpuVendor::Intel
+        } else if has("apple") {
+            GpuVendor::Apple
+        } else {
+            GpuVendor::Unknown
+        }
+    }
+}
+
+#[derive(Clone, Serialize)]
 pub struct GpuInfo {
     pub model: String,
     pub vram_gb: Option<f64>,
@@ -85,3 +132,95 @@ impl GpuInfo {
         }
     }
 }
+
+/// Parse `Get-WmiObject Win32_VideoController | Select-Object Name, AdapterRAM | Format-List`
+/// One (name, VRAM GB) per adapter; blocks are separated by blank lines
+/// AdapterRAM is a 32-bit WMI field, so cards with 4GB+ report at most ~4GB
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+pub fn parse_video_controllers(content: &str) -> Vec<(String, Option<f64>)> {
+    let mut adapters = Vec::new();
+    let mut name: Option<String> = None;
+    let mut vram: Option<f64> = None;
+
+    for line in content.lines().chain(std::iter::once("")) {
+        let line = line.trim();
+        if line.is_empty() {
+            if let Some(name) = name.take().filter(|n| !n.is_empty()) {
+                adapters.push((name, vram));
+            }
+            vram = None;
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        match key.trim() {
+            "Name" => name = Some(value.trim().to_string()),
+            "AdapterRAM" => {
+                vram = value
+                    .trim()
+                    .parse::<u64>()
+                    .ok()
+                    .map(|bytes| bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+                    .filter(|gb| *gb > 0.0 && *gb < 256.0);  // Sanity check: 0-256 GB
+            }
+            _ => {}
+        }
+    }
+    adapters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gpu_type_from_model() {
+        let cases = [
+            ("Apple M2 Pro", GpuType::Integrated),
+            ("Intel(R) UHD Graphics 630", GpuType::Integrated),
+            ("Intel Corporation Alder Lake-P GT2 [Iris Xe Graphics]", GpuType::Integrated),
+            ("Intel(R) Arc(TM) A770 Graphics", GpuType::Discrete),
+            ("Intel Corporation DG2 [Arc A770]", GpuType::Discrete),
+            ("NVIDIA GeForce RTX 3060", GpuType::Discrete),
+            ("NVIDIA Corporation GA107M [GeForce RTX 3050 Mobile]", GpuType::Discrete),
+            ("AMD Radeon(TM) Graphics", GpuType::Integrated),
+            ("Advanced Micro Devices, Inc. [AMD/ATI] Cezanne [Radeon Vega Series / Radeon Vega Mobile Series]", GpuType::Integrated),
+            ("AMD Radeon RX 6800 XT", GpuType::Discrete),
+            ("Radeon RX Vega 64", GpuType::Discrete),
+            ("Advanced Micro Devices, Inc. [AMD/ATI] Navi 21 [Radeon RX 6800/6800 XT / 6900 XT]", GpuType::Discrete),
+            ("VMware SVGA II Adapter", GpuType::Unknown),
+        ];
+        for (model, expected) in cases {
+            assert_eq!(GpuType::from_model(model), expected, "{}", model);
+        }
+    }
+
+    #[test]
+    fn test_gpu_vendor_from_model() {
+        let cases = [
+            ("NVIDIA Corporation GA107M [GeForce RTX 3050 Mobile]", GpuVendor::Nvidia),
+            ("Advanced Micro Devices, Inc. [AMD/ATI] Navi 21 [Radeon RX 6800/6800 XT / 6900 XT]", GpuVendor::Amd),
+            ("Intel(R) UHD Graphics 630", GpuVendor::Intel),
+            ("Apple M2 Pro", GpuVendor::Apple),
+            ("VMware SVGA II Adapter", GpuVendor::Unknown),
+        ];
+        for (model, expected) in cases {
+            assert_eq!(GpuVendor::from_model(model), expected, "{}", model);
+        }
+    }
+
+    #[test]
+    fn test_parse_video_controllers() {
+        let content = "\r\n\r\nName       : Intel(R) UHD Graphics 630\r\nAdapterRAM : 1073741824\r\n\r\n\
+                       Name       : NVIDIA GeForce RTX 3060\r\nAdapterRAM : 4293918720\r\n\r\n\
+                       Name       : Microsoft Basic Display Adapter\r\nAdapterRAM :\r\n";
+        let adapters = parse_video_controllers(content);
+        assert_eq!(adapters.len(), 3);
+        assert_eq!(adapters[0], ("Intel(R) UHD Graphics 630".to_string(), Some(1.0)));
+        assert_eq!(adapters[1].0, "NVIDIA GeForce RTX 3060");
+        assert!(adapters[1].1.unwrap() > 3.9);
+        assert_eq!(adapters[2].1, None);
+        assert!(parse_video_controllers("").is_empty());
+    }
+}
@@ -1,43 +1,38 @@
 // Linux GPU detection
-// Uses lspci and /sys/class/drm for VRAM
+// Uses lspci for adapters, /sys/class/drm and nvidia-smi for per-adapter VRAM
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use std::process::Command;
+use crate::profile::TimedOutput;
 
 use crate::hw::gpu::common::{GpuInfo, GpuType};
 
 pub fn detect_gpus() -> Vec<GpuInfo> {
     let output = Command::new("lspci")
-        .args(&["-vnnn"])
-        .output();
+        .args(["-vnnn"])
+        .timed_output();
 
     match output {
         Ok(result) => {
             let content = String::from_utf8_lossy(&result.stdout);
+            let adapters = parse_lspci_gpus(&content);
+            if adapters.is_empty() {
+                return vec![GpuInfo {
+                    model: "Unknown".to_string(),
+                    vram_gb: None,
+                    gpu_type: GpuType::Unknown,
+                }];
+            }
 
-            // Find VGA line and parse GPU name
-            // Format: "VGA compatible controller [0300]: NVIDIA Corporation ... [10de:XXXX]"
-            let gpu_name = content
-                .lines()
-                .find(|l| l.contains("VGA compatible controller"))
-                .and_then(|l| l.split(':').nth(1))
-                .and_then(|s| s.split('[').next())
-                .map(|s| s.trim().to_string())
-                .unwrap_or_else(|| "Unknown".to_string());
-
-            // Detect GPU type from model name
-            let gpu_type = GpuType::from_model(&gpu_name);
-
-            // Try to get VRAM from /sys/class/drm/card*/device/mem_info_vram_total
-            // This works for AMD and some NVIDIA GPUs
-            let vram = try_get_vram_from_sysfs();
-
-            vec![GpuInfo {
-                model: gpu_name,
-                vram_gb: vram,
-                gpu_type,
-            }]
+            // VRAM per PCI slot: amdgpu (and some other drivers) expose it in sysfs,
+            // the proprietary NVIDIA driver only through nvidia-smi
+            let mut vram = sysfs_vram_by_slot();
+            for (slot, gb) in nvidia_smi_vram_by_slot() {
+                vram.entry(slot).or_insert(gb);
+            }
+            gpus_from_adapters(adapters, &vram)
         }
         Err(_) => vec![GpuInfo {
             model: "Detection failed (lspci not found)".to_string(),
@@ -47,43 +42,152 @@ pub fn detect_gpus() -> Vec<GpuInfo> {
     }
 }
 
-fn try_get_vram_from_sysfs() -> Option<f64> {
-    // Try to find VRAM from /sys/class/drm
-    // Paths: /sys/class/drm/card0/device/mem_info_vram_total (AMD)
-    //        /sys/class/drm/card0/device/memory/vram_total (NVIDIA)
-    let drm_path = Path::new("/sys/class/drm");
-
-    if let Ok(entries) = drm_path.read_dir() {
-        for entry in entries.flatten() {
-            let card_path = entry.path();
-
-            // Try AMD path first
-            let vram_path = card_path.join("device/mem_info_vram_total");
-            if vram_path.exists() {
-                if let Ok(content) = fs::read_to_string(&vram_path) {
-                    if let Ok(bytes) = content.trim().parse::<u64>() {
-                        let gb = bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-                        if gb > 0.0 && gb < 256.0 {
-                            return Some(gb);
-                        }
-                    }
-                }
-            }
+/// GPU adapters in `lspci -vnnn` output as (slot, name)
+/// e.g. "01:00.0 VGA compatible controller [0300]: NVIDIA Corporation GA106 [GeForce RTX 3060] [10de:2503] (rev a1)"
+/// gives ("01:00.0", "NVIDIA Corporation GA106 [GeForce RTX 3060]")
+pub fn parse_lspci_gpus(content: &str) -> Vec<(String, String)> {
+    const GPU_CLASSES: [&str; 3] = ["VGA compatible controller", "3D controller", "Display controller"];
+
+    content
+        .lines()
+        .filter(|l| !l.starts_with(char::is_whitespace))
+        .filter(|l| GPU_CLASSES.iter().any(|class| l.contains(class)))
+        .filter_map(|line| {
+            let (slot, rest) = line.split_once(' ')?;
+            let (_, name) = rest.split_once("]: ")?;
+            let name = name.split(" (rev ").next().unwrap_or(name);
+            let name = name.split(" (prog-if ").next().unwrap_or(name).trim_end();
+            // Drop the trailing "[vendor:device]" id
+            let name = match name.rsplit_once(" [") {
+                Some((model, id)) if id.len() == 10 && id.as_bytes()[4] == b':' => model,
+                _ => name,
+            };
+            Some((normalize_slot(slot), name.trim().to_string()))
+        })
+        .collect()
+}
+
+/// One GpuInfo per lspci adapter, each with its own type and the VRAM found for its slot
+fn gpus_from_adapters(adapters: Vec<(String, String)>, vram: &HashMap<String, f64>) -> Vec<GpuInfo> {
+    adapters
+        .into_iter()
+        .map(|(slot, model)| GpuInfo {
+            gpu_type: GpuType::from_model(&model),
+            vram_gb: vram.get(&slot).copied(),
+            model,
+        })
+        .collect()
+}
+
+/// "0000:01:00.0" / "00000000:01:00.0" / "01:00.0" -> "01:00.0"
+fn normalize_slot(slot: &str) -> String {
+    let slot = slot.trim().to_lowercase();
+    let parts: Vec<&str> = slot.split(':').collect();
+    match parts.len() {
+        n if n >= 2 => format!("{}:{}", parts[n - 2], parts[n - 1]),
+        _ => slot,
+    }
+}
+
+/// VRAM in GB from /sys/class/drm/cardN/device/mem_info_vram_total, keyed by PCI slot
+fn sysfs_vram_by_slot() -> HashMap<String, f64> {
+    let mut vram = HashMap::new();
+    let Ok(entries) = Path::new("/sys/class/drm").read_dir() else {
+        return vram;
+    };
+
+    for entry in entries.flatten() {
+        let device = entry.path().join("device");
+        // device is a symlink to the PCI device directory named after its address
+        let Some(slot) = fs::canonicalize(&device)
+            .ok()
+            .and_then(|p| p.file_name().map(|n| normalize_slot(&n.to_string_lossy())))
+        else {
+            continue;
+        };
 
-            // Try NVIDIA path
-            let vram_path_nvidia = card_path.join("device/memory/vram_total");
-            if vram_path_nvidia.exists() {
-                if let Ok(content) = fs::read_to_string(&vram_path_nvidia) {
-                    if let Ok(bytes) = content.trim().parse::<u64>() {
-                        let gb = bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-                        if gb > 0.0 && gb < 256.0 {
-                            return Some(gb);
-                        }
-                    }
-                }
+        for file in ["mem_info_vram_total", "memory/vram_total"] {
+            let gb = fs::read_to_string(device.join(file))
+                .ok()
+                .and_then(|c| c.trim().parse::<u64>().ok())
+                .map(|bytes| bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+                .filter(|gb| *gb > 0.0 && *gb < 256.0);
+            if let Some(gb) = gb {
+                vram.insert(slot.clone(), gb);
+                break;
             }
         }
     }
+    vram
+}
+
+/// VRAM of NVIDIA GPUs from nvidia-smi, keyed by PCI slot (empty without nvidia-smi)
+fn nvidia_smi_vram_by_slot() -> Vec<(String, f64)> {
+    Command::new("nvidia-smi")
+        .args(["--query-gpu=pci.bus_id,memory.total", "--format=csv,noheader,nounits"])
+        .timed_output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| parse_nvidia_smi_vram(&String::from_utf8_lossy(&o.stdout)))
+        .unwrap_or_default()
+}
+
+/// Parse "00000000:01:00.0, 12288" lines (memory.total in MiB)
+pub fn parse_nvidia_smi_vram(output: &str) -> Vec<(String, f64)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (bus_id, mib) = line.split_once(',')?;
+            let mib: f64 = mib.trim().parse().ok()?;
+            Some((normalize_slot(bus_id), mib / 1024.0))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    None
+    #[test]
+    fn test_parse_lspci_gpus() {
+        let content = "\
+00:02.0 VGA compatible controller [0300]: Intel Corporation Alder Lake-P GT2 [Iris Xe Graphics] [8086:46a6] (rev 0c) (prog-if 00 [VGA controller])
+\tSubsystem: Lenovo Device [17aa:3b1a]
+\tKernel driver in use: i915
+00:14.0 USB controller [0c03]: Intel Corporation Device [8086:51ed] (rev 01)
+01:00.0 3D controller [0302]: NVIDIA Corporation GA107M [GeForce RTX 3050 Mobile] [10de:25a2] (rev a1)
+\tKernel driver in use: nvidia
+00:0f.0 VGA compatible controller [0300]: VMware SVGA II Adapter [15ad:0405] (prog-if 00 [VGA controller])
+\tKernel driver in use: vmwgfx
+";
+        let gpus = parse_lspci_gpus(content);
+        assert_eq!(gpus.len(), 3);
+        assert_eq!(gpus[2].1, "VMware SVGA II Adapter");
+        assert_eq!(gpus[0], ("00:02.0".to_string(), "Intel Corporation Alder Lake-P GT2 [Iris Xe Graphics]".to_string()));
+        assert_eq!(gpus[1], ("01:00.0".to_string(), "NVIDIA Corporation GA107M [GeForce RTX 3050 Mobile]".to_string()));
+        assert!(parse_lspci_gpus("").is_empty());
+    }
+
+    #[test]
+    fn test_optimus_laptop_reports_both_gpus() {
+        // Intel iGPU plus an NVIDIA dGPU that lspci lists as "3D controller"
+        let content = "\
+00:02.0 VGA compatible controller [0300]: Intel Corporation TigerLake-LP GT2 [Iris Xe Graphics] [8086:9a49] (rev 01)
+01:00.0 3D controller [0302]: NVIDIA Corporation TU117M [GeForce GTX 1650 Mobile / Max-Q] [10de:1f9d] (rev a1)
+";
+        let vram = HashMap::from([("01:00.0".to_string(), 4.0)]);
+        let gpus = gpus_from_adapters(parse_lspci_gpus(content), &vram);
+        assert_eq!(gpus.len(), 2);
+        assert_eq!(gpus[0].gpu_type, GpuType::Integrated);
+        assert_eq!(gpus[0].vram_gb, None);
+        assert_eq!(gpus[1].gpu_type, GpuType::Discrete);
+        assert_eq!(gpus[1].vram_gb, Some(4.0));
+    }
+
+    #[test]
+    fn test_parse_nvidia_smi_vram() {
+        let vram = parse_nvidia_smi_vram("00000000:01:00.0, 12288\n00000000:2B:00.0, 8192\n");
+        assert_eq!(vram, vec![("01:00.0".to_string(), 12.0), ("2b:00.0".to_string(), 8.0)]);
+        assert_eq!(normalize_slot("0000:03:00.0"), "03:00.0");
+    }
 }
@@ -2,13 +2,14 @@
 // Uses system_profiler SPDisplaysDataType
 
 use std::process::Command;
+use crate::profile::TimedOutput;
 
 use crate::hw::gpu::common::{GpuInfo, GpuType};
 
 pub fn detect_gpus() -> Vec<GpuInfo> {
     let output = Command::new("system_profiler")
         .arg("SPDisplaysDataType")
-        .output();
+        .timed_output();
 
     match output {
         Ok(result) => {
@@ -2,8 +2,9 @@
 // Uses PowerShell WMI Win32_VideoController
 
 use std::process::Command;
+use crate::profile::TimedOutput;
 
-use crate::hw::gpu::common::{GpuInfo, GpuType};
+use crate::hw::gpu::common::{GpuInfo, GpuType, parse_video_controllers};
 
 pub fn detect_gpus() -> Vec<GpuInfo> {
     // Get GPU name and VRAM in one command
@@ -12,42 +13,30 @@ pub fn detect_gpus() -> Vec<GpuInfo> {
             "-Command",
             "Get-WmiObject Win32_VideoController | Select-Object Name, AdapterRAM | Format-List"
         ])
-        .output();
+        .timed_output();
 
     match output {
         Ok(result) => {
             let content = String::from_utf8_lossy(&result.stdout);
 
-            // Parse "Name : NVIDIA GeForce RTX 3060"
-            let model = content
-                .lines()
-                .find(|l| l.trim().starts_with("Name"))
-                .and_then(|l| l.split(':').nth(1))
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .unwrap_or_else(|| "Unknown GPU".to_string());
-
-            // Detect GPU type from model name
-            let gpu_type = GpuType::from_model(&model);
-
-            // Parse "AdapterRAM : 1073741824" (bytes, need to convert to GB)
-            let vram = content
-                .lines()
-                .find(|l| l.trim().starts_with("AdapterRAM"))
-                .and_then(|l| l.split(':').nth(1))
-                .and_then(|s| s.trim().parse::<u64>().ok())
-                .map(|bytes| bytes as f64 / (1024.0 * 1024.0 * 1024.0))
-                .filter(|gb| *gb > 0.0 && *gb < 256.0);  // Sanity check: 0-256 GB
-
-            if model == "Unknown GPU" {
-                vec![GpuInfo {
+            // One GpuInfo per adapter, each with its own AdapterRAM
+            let adapters = parse_video_controllers(&content);
+            if adapters.is_empty() {
+                return vec![GpuInfo {
                     model: "No GPU detected".to_string(),
                     vram_gb: None,
                     gpu_type: GpuType::Unknown,
-                }]
-            } else {
-                vec![GpuInfo { model, vram_gb: vram, gpu_type }]
+                }];
             }
+
+            adapters
+                .into_iter()
+                .map(|(model, vram_gb)| GpuInfo {
+                    gpu_type: GpuType::from_model(&model),
+                    model,
+                    vram_gb,
+                })
+                .collect()
         }
         Err(_) => vec![GpuInfo {
             model: "Detection failed".to_string(),
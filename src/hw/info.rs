@@ -0,0 +1,144 @@
+// Hardware inventory module
+// Collects basic + deep hardware info into one serializable tree (--info, --info --json)
+
+use serde::Serialize;
+
+use crate::hw::deep::{get_platform_probe, CacheInfo, DimmSlot, DiskHealth, GpuDriver, InstructionSets, ToolHint};
+use crate::hw::capabilities::{Capabilities, Capability};
+use crate::hw::{BatteryInfo, CpuInfo, DiskInfo, GpuInfo, NetworkInfo, RamInfo};
+
+/// Full hardware inventory, rendered by info mode and emitted by --json
+#[derive(Clone, Serialize)]
+pub struct DeepInfo {
+    pub platform: String,
+    pub os_version: Option<String>,
+    pub kernel_version: Option<String>,
+    pub uptime_secs: u64,
+    /// 1/5/15-minute load averages (Unix only)
+    pub load_average: Option<[f64; 3]>,
+    pub cpu: CpuInfo,
+    pub cache: Option<CacheInfo>,
+    pub instruction_sets: Option<InstructionSets>,
+    pub tdp_w: Option<u32>,
+    pub gpus: Vec<GpuInfo>,
+    pub gpu_driver: Option<GpuDriver>,
+    pub ram: RamInfo,
+    pub dimm_slots: Vec<DimmSlot>,
+    pub disks: Vec<DiskInfo>,
+    pub disk_health: Option<DiskHealth>,
+    /// None on machines without a battery
+    pub battery: Option<BatteryInfo>,
+    pub network: Vec<NetworkInfo>,
+    /// What the health check can measure here
+    pub capabilities: Capabilities,
+}
+
+impl DeepInfo {
+    /// Run all detectors and deep probes (no tests)
+    pub fn collect() -> Self {
+        let probe = get_platform_probe();
+        let cpu = CpuInfo::new();
+        let tdp_w = probe.get_tdp(&cpu.model);
+        let disks = DiskInfo::new();
+        let capabilities = Capabilities::probe(disks.first().map(|d| d.mount_point.as_str()));
+        let platform = crate::platform::detect();
+
+        Self {
+            platform: platform.to_string(),
+            os_version: platform.os_version(),
+            kernel_version: platform.kernel_version(),
+            uptime_secs: sysinfo::System::uptime(),
+            load_average: cfg!(unix).then(|| {
+                let load = sysinfo::System::load_average();
+                [load.one, load.five, load.fifteen]
+            }),
+            cache: probe.get_cache_info(),
+            instruction_sets: probe.get_instruction_sets(),
+            tdp_w,
+            cpu,
+            gpus: GpuInfo::new(),
+            gpu_driver: probe.get_gpu_driver(),
+            ram: RamInfo::with_pressure(),
+            dimm_slots: probe.get_dimm_slots(),
+            disks,
+            disk_health: probe.get_disk_health(),
+            battery: BatteryInfo::new(),
+            network: NetworkInfo::new(),
+            capabilities,
+        }
+    }
+
+    /// System line for the report, e.g. "Linux (Ubuntu 22.04.4 LTS, kernel 6.8.0-45-generic)"
+    pub fn system_display(&self) -> String {
+        crate::platform::describe(&self.platform, self.os_version.as_deref(), self.kernel_version.as_deref())
+    }
+
+    /// Hints for details that came back empty because a tool is missing or needs sudo
+    pub fn tool_hints(&self) -> Vec<ToolHint> {
+        let mut hints = Vec::new();
+
+        #[cfg(target_os = "linux")]
+        {
+            use crate::hw::deep::common::{running_as_root, tool_available};
+            if self.capabilities.smart != Capability::Available {
+                hints.push(if tool_available("smartctl") { ToolHint::SudoSmart } else { ToolHint::InstallSmartctl });
+            }
+            if self.dimm_slots.is_empty() {
+                if !tool_available("dmidecode") {
+                    hints.push(ToolHint::InstallDmidecode);
+                } else if !running_as_root() {
+                    hints.push(ToolHint::SudoDmidecode);
+                }
+            }
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            // Apple Silicon GPU temperature and power come from powermetrics, which needs root
+            if self.capabilities.gpu_temp != Capability::Available
+                && self.cpu.model.starts_with("Apple")
+                && !crate::hw::deep::common::running_as_root()
+            {
+                hints.push(ToolHint::SudoPowermetrics);
+            }
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            let has_nvidia = self.gpus.iter().any(|g| g.model.to_lowercase().contains("nvidia"));
+            if has_nvidia
+                && self.capabilities.gpu_temp != Capability::Available
+                && !crate::hw::deep::common::tool_available("nvidia-smi")
+            {
+                hints.push(ToolHint::InstallNvidiaSmi);
+            }
+        }
+
+        hints
+    }
+
+    pub fn to_json(&self) -> String {
+        crate::fmt::to_json(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json_has_deep_fields() {
+        // What `--info --json` prints
+        let json: serde_json::Value = serde_json::from_str(&DeepInfo::collect().to_json()).unwrap();
+        assert!(json["platform"].is_string());
+        assert!(json["cpu"]["model"].is_string());
+        assert!(json["ram"]["total_gb"].is_number());
+        assert!(json["disks"].is_array());
+        assert!(json["gpus"].is_array());
+        assert!(json["dimm_slots"].is_array());
+        // Deep probes are null where the platform can't tell, but always present
+        for key in ["cache", "instruction_sets", "gpu_driver", "disk_health", "tdp_w"] {
+            assert!(json.get(key).is_some(), "missing {}", key);
+        }
+    }
+}
@@ -5,8 +5,15 @@ pub mod ram;
 pub mod disk;
 pub mod gpu;
 pub mod deep;
+pub mod info;
+pub mod capabilities;
+pub mod battery;
+pub mod network;
 
 pub use cpu::CpuInfo;
-pub use ram::RamInfo;
+pub use ram::{RamInfo, MemoryPressure, PressureLevel};
 pub use disk::DiskInfo;
 pub use gpu::GpuInfo;
+pub use info::DeepInfo;
+pub use battery::BatteryInfo;
+pub use network::NetworkInfo;
@@ -0,0 +1,78 @@
+// Network interface inventory
+// Names and MAC addresses from sysinfo, link speed from /sys/class/net on Linux
+
+use serde::Serialize;
+use sysinfo::Networks;
+
+/// One network adapter (loopback and virtual interfaces without a MAC are skipped)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NetworkInfo {
+    pub name: String,
+    pub mac_address: String,
+    /// Negotiated link speed, None when unknown or the link is down
+    pub speed_mbps: Option<u32>,
+}
+
+impl NetworkInfo {
+    /// Detect network interfaces, sorted by name
+    pub fn new() -> Vec<Self> {
+        let networks = Networks::new_with_refreshed_list();
+        let mut interfaces: Vec<Self> = networks
+            .iter()
+            .filter_map(|(name, data)| {
+                let mac = data.mac_address();
+                if mac.is_unspecified() {
+                    return None;
+                }
+                Some(Self {
+                    name: name.clone(),
+                    mac_address: mac.to_string(),
+                    speed_mbps: link_speed_mbps(name),
+                })
+            })
+            .collect();
+        interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+        interfaces
+    }
+
+    /// e.g. "eth0 (aa:bb:cc:dd:ee:ff, 1000 Mb/s)"
+    pub fn display(&self) -> String {
+        match self.speed_mbps {
+            Some(speed) => format!("{} ({}, {} Mb/s)", self.name, self.mac_address, speed),
+            None => format!("{} ({})", self.name, self.mac_address),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn link_speed_mbps(name: &str) -> Option<u32> {
+    std::fs::read_to_string(format!("/sys/class/net/{}/speed", name))
+        .ok()
+        .and_then(|s| parse_link_speed(&s))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn link_speed_mbps(_name: &str) -> Option<u32> {
+    None
+}
+
+/// Parse /sys/class/net/<if>/speed (Mb/s; -1 or unreadable when the link is down)
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_link_speed(content: &str) -> Option<u32> {
+    content.trim().parse::<i64>().ok().filter(|&s| s > 0).map(|s| s as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_link_speed() {
+        assert_eq!(parse_link_speed("1000\n"), Some(1000));
+        assert_eq!(parse_link_speed("-1\n"), None);
+        assert_eq!(parse_link_speed(""), None);
+
+        let nic = NetworkInfo { name: "eth0".to_string(), mac_address: "aa:bb:cc:dd:ee:ff".to_string(), speed_mbps: Some(2500) };
+        assert_eq!(nic.display(), "eth0 (aa:bb:cc:dd:ee:ff, 2500 Mb/s)");
+    }
+}
@@ -1,14 +1,22 @@
 // RAM detection module
 
+pub mod pressure;
+
+use serde::Serialize;
 use sysinfo::System;
 
-#[derive(Clone)]
+pub use pressure::{MemoryPressure, PressureLevel};
+
+#[derive(Clone, Serialize)]
 pub struct RamInfo {
     pub total_gb: f64,
     pub used_gb: f64,
+    /// Only probed by `with_pressure` (all None otherwise)
+    pub pressure: MemoryPressure,
 }
 
 impl RamInfo {
+    /// Total/used memory only, cheap enough for every run and watch refresh
     pub fn new() -> Self {
         let mut sys = System::new_all();
         sys.refresh_memory();
@@ -19,6 +27,12 @@ impl RamInfo {
         Self {
             total_gb: total as f64 / 1024.0 / 1024.0 / 1024.0,
             used_gb: used as f64 / 1024.0 / 1024.0 / 1024.0,
+            pressure: MemoryPressure::default(),
         }
     }
+
+    /// Also probe memory pressure, which spawns external tools on macOS and Windows
+    pub fn with_pressure() -> Self {
+        Self { pressure: pressure::get_memory_pressure(), ..Self::new() }
+    }
 }
@@ -0,0 +1,174 @@
+// Memory pressure / compression detection
+// macOS: vm_stat + kern.memorystatus_vm_pressure_level, Windows: Get-Counter commit charge
+
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+use std::process::Command;
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+use crate::profile::TimedOutput;
+
+use serde::Serialize;
+
+/// OS-reported memory pressure level
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum PressureLevel {
+    Normal,
+    Warning,
+    Critical,
+}
+
+/// Memory pressure and compression state (fields are None when unavailable)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct MemoryPressure {
+    pub level: Option<PressureLevel>,
+    /// Memory held by the compressor (macOS compressor pages, Windows Memory Compression)
+    pub compressed_gb: Option<f64>,
+    /// Commit charge as % of commit limit (Windows)
+    pub commit_pct: Option<f64>,
+}
+
+impl MemoryPressure {
+    /// Pressure or compression high enough to constrain the RAM test
+    pub fn is_significant(&self, total_gb: f64) -> bool {
+        let compressed_heavy = self.compressed_gb.is_some_and(|gb| total_gb > 0.0 && gb / total_gb >= 0.10);
+        matches!(self.level, Some(PressureLevel::Warning | PressureLevel::Critical)) || compressed_heavy
+    }
+}
+
+/// Get current memory pressure / compression state
+pub fn get_memory_pressure() -> MemoryPressure {
+    #[cfg(target_os = "macos")]
+    {
+        get_macos_memory_pressure()
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        get_windows_memory_pressure()
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        MemoryPressure::default()
+    }
+}
+
+// =============================================================================
+// macOS implementation
+// =============================================================================
+
+#[cfg(target_os = "macos")]
+fn get_macos_memory_pressure() -> MemoryPressure {
+    let compressed_gb = Command::new("vm_stat")
+        .timed_output()
+        .ok()
+        .and_then(|o| parse_vm_stat_compressed(&String::from_utf8_lossy(&o.stdout)));
+
+    // Same level the kernel reports to `memory_pressure` (1 = normal, 2 = warn, 4 = critical)
+    let level = Command::new("sysctl")
+        .args(["-n", "kern.memorystatus_vm_pressure_level"])
+        .timed_output()
+        .ok()
+        .and_then(|o| parse_macos_pressure_level(&String::from_utf8_lossy(&o.stdout)));
+
+    MemoryPressure { level, compressed_gb, commit_pct: None }
+}
+
+/// Parse "Pages occupied by compressor: 123456." using the page size from the header
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_vm_stat_compressed(output: &str) -> Option<f64> {
+    let page_size: u64 = output
+        .lines()
+        .next()
+        .and_then(|l| l.split("page size of ").nth(1))
+        .and_then(|s| s.split_whitespace().next())
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(4096);
+
+    let pages: u64 = output
+        .lines()
+        .find_map(|l| l.strip_prefix("Pages occupied by compressor:"))
+        .and_then(|v| v.trim().trim_end_matches('.').parse().ok())?;
+
+    Some((pages * page_size) as f64 / 1024.0 / 1024.0 / 1024.0)
+}
+
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_macos_pressure_level(output: &str) -> Option<PressureLevel> {
+    match output.trim() {
+        "1" => Some(PressureLevel::Normal),
+        "2" => Some(PressureLevel::Warning),
+        "4" => Some(PressureLevel::Critical),
+        _ => None,
+    }
+}
+
+// =============================================================================
+// Windows implementation
+// =============================================================================
+
+#[cfg(target_os = "windows")]
+fn get_windows_memory_pressure() -> MemoryPressure {
+    // Line 1: commit charge %, line 2: Memory Compression working set (bytes, may be empty)
+    let output = Command::new("powershell")
+        .args([
+            "-Command",
+            "(Get-Counter '\\Memory\\% Committed Bytes In Use').CounterSamples[0].CookedValue; \
+             (Get-Process -Name 'Memory Compression' -ErrorAction SilentlyContinue).WorkingSet64",
+        ])
+        .timed_output();
+
+    match output {
+        Ok(o) => parse_windows_pressure(&String::from_utf8_lossy(&o.stdout)),
+        Err(_) => MemoryPressure::default(),
+    }
+}
+
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_windows_pressure(output: &str) -> MemoryPressure {
+    let mut lines = output.lines().map(str::trim).filter(|l| !l.is_empty());
+
+    let commit_pct = lines.next().and_then(|l| l.replace(',', ".").parse::<f64>().ok());
+    let compressed_gb = lines
+        .next()
+        .and_then(|l| l.parse::<u64>().ok())
+        .map(|bytes| bytes as f64 / 1024.0 / 1024.0 / 1024.0);
+
+    let level = commit_pct.map(|pct| {
+        if pct >= 90.0 {
+            PressureLevel::Critical
+        } else if pct >= 80.0 {
+            PressureLevel::Warning
+        } else {
+            PressureLevel::Normal
+        }
+    });
+
+    MemoryPressure { level, compressed_gb, commit_pct }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vm_stat_compressed() {
+        let output = "Mach Virtual Memory Statistics: (page size of 16384 bytes)\n\
+                      Pages free:                               12345.\n\
+                      Pages occupied by compressor:             65536.\n";
+        assert_eq!(parse_vm_stat_compressed(output), Some(1.0));
+        assert_eq!(parse_vm_stat_compressed("garbage"), None);
+        assert_eq!(parse_macos_pressure_level("2\n"), Some(PressureLevel::Warning));
+    }
+
+    #[test]
+    fn test_parse_windows_pressure() {
+        let pressure = parse_windows_pressure("85.5\r\n1073741824\r\n");
+        assert_eq!(pressure.level, Some(PressureLevel::Warning));
+        assert_eq!(pressure.compressed_gb, Some(1.0));
+        assert!(pressure.is_significant(16.0));
+
+        let pressure = parse_windows_pressure("42,1\n");
+        assert_eq!(pressure.level, Some(PressureLevel::Normal));
+        assert!(!pressure.is_significant(16.0));
+    }
+}
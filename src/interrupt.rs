@@ -0,0 +1,124 @@
+// Ctrl+C handling
+// While a stress test is running, Ctrl+C asks it to stop so its test files get cleaned up
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::lang::Text;
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+static ACTIVE_TESTS: AtomicUsize = AtomicUsize::new(0);
+/// Set by the signal handler when the process has to exit now; acted on by the exit thread
+#[cfg(unix)]
+static EXIT_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Exit status after Ctrl+C (128 + SIGINT)
+pub const EXIT_INTERRUPTED: i32 = 130;
+
+/// Install the Ctrl+C handler (call once at startup)
+pub fn install() {
+    #[cfg(unix)]
+    {
+        // The handler may only set flags, so the actual exit (closing the telemetry file) happens here
+        std::thread::spawn(|| loop {
+            std::thread::sleep(POLL_INTERVAL);
+            if EXIT_REQUESTED.load(Ordering::SeqCst) {
+                exit_now();
+            }
+        });
+        unsafe {
+            let handler: extern "C" fn(libc::c_int) = on_sigint;
+            libc::signal(libc::SIGINT, handler as libc::sighandler_t);
+        }
+    }
+
+    #[cfg(windows)]
+    unsafe {
+        SetConsoleCtrlHandler(Some(on_ctrl_event), 1);
+    }
+}
+
+/// Whether Ctrl+C was pressed during a test; test loops stop early when set
+pub fn interrupted() -> bool {
+    INTERRUPTED.load(Ordering::Relaxed)
+}
+
+/// Marks a stress test as running for as long as it is alive
+/// Ctrl+C outside a guarded test (e.g. at a prompt) exits right away
+pub struct TestGuard(());
+
+impl TestGuard {
+    pub fn new() -> Self {
+        ACTIVE_TESTS.fetch_add(1, Ordering::SeqCst);
+        TestGuard(())
+    }
+}
+
+impl Drop for TestGuard {
+    fn drop(&mut self) {
+        ACTIVE_TESTS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// How often waits and the exit thread check for Ctrl+C
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Sleep for `duration` as a guarded test, returning early on Ctrl+C (check `exit_if_interrupted` after)
+pub fn sleep_interruptible(duration: Duration) {
+    let _guard = TestGuard::new();
+    let end = Instant::now() + duration;
+    while !interrupted() {
+        let left = end.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            break;
+        }
+        std::thread::sleep(left.min(POLL_INTERVAL));
+    }
+}
+
+/// Call after a test returns: exit if it was stopped by Ctrl+C
+pub fn exit_if_interrupted(text: &Text) {
+    if interrupted() {
+        println!();
+        println!("⚠️  {}", text.interrupted());
+        crate::fmt::telemetry::finish();
+        std::process::exit(EXIT_INTERRUPTED);
+    }
+}
+
+/// First Ctrl+C during a test requests a stop, a second one (or one outside a test) exits
+fn should_exit_now() -> bool {
+    ACTIVE_TESTS.load(Ordering::SeqCst) == 0 || INTERRUPTED.swap(true, Ordering::SeqCst)
+}
+
+/// Exit without the interrupted message (Ctrl+C at a prompt, or a second one during a test)
+fn exit_now() -> ! {
+    crate::fmt::telemetry::finish();
+    std::process::exit(EXIT_INTERRUPTED);
+}
+
+#[cfg(unix)]
+extern "C" fn on_sigint(_signal: libc::c_int) {
+    // Only async-signal-safe work here: atomics, no locks or I/O
+    if should_exit_now() {
+        EXIT_REQUESTED.store(true, Ordering::SeqCst);
+    }
+}
+
+#[cfg(windows)]
+extern "system" {
+    fn SetConsoleCtrlHandler(handler: Option<unsafe extern "system" fn(u32) -> i32>, add: i32) -> i32;
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn on_ctrl_event(event: u32) -> i32 {
+    const CTRL_C_EVENT: u32 = 0;
+    if event != CTRL_C_EVENT {
+        return 0;
+    }
+    if should_exit_now() {
+        // Handler runs on its own thread, so a normal exit is safe
+        exit_now();
+    }
+    1
+}
@@ -1,9 +1,37 @@
 // Language module - Multi-language support for pchecker
+// Vietnamese and English are complete, other languages overlay a key table and fall back to English
+
+mod es;
+mod fr;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Language {
     Vietnamese,
     English,
+    French,
+    Spanish,
+}
+
+impl Language {
+    /// All supported languages
+    pub const ALL: [Language; 4] = [Language::Vietnamese, Language::English, Language::French, Language::Spanish];
+
+    /// Key table for languages that fall back to English (None for complete languages)
+    fn translation_table(self) -> Option<&'static [(&'static str, &'static str)]> {
+        match self {
+            Language::French => Some(fr::TEXTS),
+            Language::Spanish => Some(es::TEXTS),
+            Language::Vietnamese | Language::English => None,
+        }
+    }
+
+    /// Translation of a `TEXT_KEYS` key, None if this language has no entry for it
+    pub fn translation(self, key: &str) -> Option<&'static str> {
+        self.translation_table()?
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| *value)
+    }
 }
 
 // Text translations
@@ -20,231 +48,308 @@ impl Text {
     pub fn header(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Công cụ kiểm tra phần cứng",
-            Language::English => "Hardware Info Tool",
+            lang => lang.translation("header").unwrap_or("Hardware Info Tool"),
         }
     }
 
     pub fn system(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "HỆ ĐIỀU HÀNH",
-            Language::English => "SYSTEM",
+            lang => lang.translation("system").unwrap_or("SYSTEM"),
         }
     }
 
     pub fn cpu(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "cpu",
-            Language::English => "cpu",
+            lang => lang.translation("cpu").unwrap_or("cpu"),
         }
     }
 
     pub fn gpu(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "gpu",
-            Language::English => "gpu",
+            lang => lang.translation("gpu").unwrap_or("gpu"),
         }
     }
 
     pub fn ram(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "ram",
-            Language::English => "ram",
+            lang => lang.translation("ram").unwrap_or("ram"),
         }
     }
 
     pub fn cores_label(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "nhân",
-            Language::English => "cores",
+            lang => lang.translation("cores_label").unwrap_or("cores"),
         }
     }
 
     pub fn disk(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Ổ CỨNG",
-            Language::English => "DISK",
+            lang => lang.translation("disk").unwrap_or("DISK"),
         }
     }
 
     pub fn ram_free(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "trống",
-            Language::English => "free",
+            lang => lang.translation("ram_free").unwrap_or("free"),
         }
     }
 
     pub fn done_in(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Hoàn thành trong",
-            Language::English => "Done in",
+            lang => lang.translation("done_in").unwrap_or("Done in"),
         }
     }
 
     pub fn no_gpu(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Không phát hiện GPU rời",
-            Language::English => "No dedicated GPU detected",
+            lang => lang.translation("no_gpu").unwrap_or("No dedicated GPU detected"),
         }
     }
 
     pub fn health_check(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "KIỂM TRA SỨC KHỎE PHẦN CỨNG",
-            Language::English => "HARDWARE HEALTH CHECK",
+            lang => lang.translation("health_check").unwrap_or("HARDWARE HEALTH CHECK"),
         }
     }
 
     pub fn testing_cpu(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đang kiểm tra CPU",
-            Language::English => "Testing CPU",
+            lang => lang.translation("testing_cpu").unwrap_or("Testing CPU"),
         }
     }
 
     pub fn testing_ram(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đang kiểm tra RAM",
-            Language::English => "Testing RAM",
+            lang => lang.translation("testing_ram").unwrap_or("Testing RAM"),
         }
     }
 
     pub fn critical_issues(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "VẤN ĐỀ NGHIÊM TRỌNG:",
-            Language::English => "CRITICAL ISSUES:",
+            lang => lang.translation("critical_issues").unwrap_or("CRITICAL ISSUES:"),
         }
     }
 
     pub fn issues_detected(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "PHÁT HIỆN VẤN ĐỀ:",
-            Language::English => "ISSUES DETECTED:",
+            lang => lang.translation("issues_detected").unwrap_or("ISSUES DETECTED:"),
         }
     }
 
     pub fn summary(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "TÓM TẮT:",
-            Language::English => "SUMMARY:",
+            lang => lang.translation("summary").unwrap_or("SUMMARY:"),
         }
     }
 
     pub fn hardware_good(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Phần cứng ở trạng thái tốt",
-            Language::English => "Hardware is in good condition",
+            lang => lang.translation("hardware_good").unwrap_or("Hardware is in good condition"),
         }
     }
 
     pub fn hardware_some_issues(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Phần cứng có một số vấn đề",
-            Language::English => "Hardware has some issues",
+            lang => lang.translation("hardware_some_issues").unwrap_or("Hardware has some issues"),
         }
     }
 
     pub fn hardware_not_recommended(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Không khuyến nghị sử dụng",
-            Language::English => "Not recommended for use",
+            lang => lang.translation("hardware_not_recommended").unwrap_or("Not recommended for use"),
         }
     }
 
     pub fn cpu_health_check(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Kiểm tra CPU",
-            Language::English => "CPU Health Check",
+            lang => lang.translation("cpu_health_check").unwrap_or("CPU Health Check"),
         }
     }
 
     pub fn operations(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "phép tính",
-            Language::English => "operations",
+            lang => lang.translation("operations").unwrap_or("operations"),
         }
     }
 
     pub fn ops_per_sec(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "phép/giây",
-            Language::English => "ops/sec",
+            lang => lang.translation("ops_per_sec").unwrap_or("ops/sec"),
+        }
+    }
+
+    pub fn expected_ops_range(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "dự kiến",
+            lang => lang.translation("expected_ops_range").unwrap_or("expected"),
+        }
+    }
+
+    pub fn perf_within_range(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "trong khoảng",
+            lang => lang.translation("perf_within_range").unwrap_or("within range"),
+        }
+    }
+
+    pub fn perf_above_range(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "cao hơn dự kiến",
+            lang => lang.translation("perf_above_range").unwrap_or("above range"),
+        }
+    }
+
+    pub fn perf_below_range(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "thấp hơn dự kiến",
+            lang => lang.translation("perf_below_range").unwrap_or("below range"),
         }
     }
 
     pub fn avg_op_time(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "tb thời gian",
-            Language::English => "avg time",
+            lang => lang.translation("avg_op_time").unwrap_or("avg time"),
         }
     }
 
     pub fn variance(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "dao động",
-            Language::English => "variance",
+            lang => lang.translation("variance").unwrap_or("variance"),
         }
     }
 
     pub fn ram_health_check(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Kiểm tra RAM",
-            Language::English => "RAM Health Check",
+            lang => lang.translation("ram_health_check").unwrap_or("RAM Health Check"),
         }
     }
 
     pub fn tested_gb(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "đã test",
-            Language::English => "tested",
+            lang => lang.translation("tested_gb").unwrap_or("tested"),
+        }
+    }
+
+    pub fn ram_partial_coverage(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Chỉ test {TESTED} / {TOTAL} GB ({PCT}%) - phần RAM còn lại chưa được kiểm tra, dùng --ram-gb để test nhiều hơn",
+            lang => lang.translation("ram_partial_coverage").unwrap_or("Only {TESTED} of {TOTAL} GB tested ({PCT}%) - the rest of the RAM was not verified, use --ram-gb to test more"),
+        }
+    }
+
+    pub fn ram_partial_coverage_in_use(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Chỉ test {TESTED} / {TOTAL} GB ({PCT}%) - phần RAM còn lại đang được chương trình khác sử dụng, đóng bớt chương trình để test nhiều hơn",
+            lang => lang.translation("ram_partial_coverage_in_use").unwrap_or("Only {TESTED} of {TOTAL} GB tested ({PCT}%) - the rest of the RAM is in use by other programs, close them to test more"),
+        }
+    }
+
+    pub fn ram_access(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "kiểu truy cập",
+            lang => lang.translation("ram_access").unwrap_or("access pattern"),
         }
     }
 
     pub fn write_speed(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "tốc độ ghi",
-            Language::English => "write speed",
+            lang => lang.translation("write_speed").unwrap_or("write speed"),
+        }
+    }
+
+    pub fn write_speed_peak(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "ghi đỉnh (cache)",
+            lang => lang.translation("write_speed_peak").unwrap_or("peak write (cache)"),
+        }
+    }
+
+    pub fn write_speed_sustained(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "ghi liên tục",
+            lang => lang.translation("write_speed_sustained").unwrap_or("sustained write"),
+        }
+    }
+
+    pub fn disk_cache_cliff(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Tốc độ ghi giảm {PCT}% khi cache ghi đầy - chép file lớn sẽ chậm hơn nhiều so với tốc độ quảng cáo",
+            lang => lang.translation("disk_cache_cliff").unwrap_or("Write speed drops {PCT}% once the write cache fills - large copies will run well below the advertised speed"),
         }
     }
 
     pub fn read_speed(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "tốc độ đọc",
-            Language::English => "read speed",
+            lang => lang.translation("read_speed").unwrap_or("read speed"),
         }
     }
 
     pub fn errors_detected(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "lỗi phát hiện",
-            Language::English => "errors detected",
+            lang => lang.translation("errors_detected").unwrap_or("errors detected"),
         }
     }
 
     pub fn testing_disk(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đang kiểm tra ổ cứng",
-            Language::English => "Testing Disk",
+            lang => lang.translation("testing_disk").unwrap_or("Testing Disk"),
         }
     }
 
     pub fn disk_health_check(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Kiểm tra ổ cứng",
-            Language::English => "Disk Health Check",
+            lang => lang.translation("disk_health_check").unwrap_or("Disk Health Check"),
         }
     }
 
     pub fn seek_time(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "thời gian seek",
-            Language::English => "seek time",
+            lang => lang.translation("seek_time").unwrap_or("seek time"),
         }
     }
 
     pub fn bad_sectors(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "bad sector",
-            Language::English => "bad sectors",
+            lang => lang.translation("bad_sectors").unwrap_or("bad sectors"),
+        }
+    }
+
+    pub fn read_retries(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "sector đọc lại OK",
+            lang => lang.translation("read_retries").unwrap_or("read retries"),
         }
     }
 
@@ -252,77 +357,105 @@ impl Text {
     pub fn device(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "thiết bị",
-            Language::English => "device",
+            lang => lang.translation("device").unwrap_or("device"),
         }
     }
 
     pub fn usage(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "đã dùng",
-            Language::English => "usage",
+            lang => lang.translation("usage").unwrap_or("usage"),
         }
     }
 
     pub fn available(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "còn trống",
-            Language::English => "available",
+            lang => lang.translation("available").unwrap_or("available"),
         }
     }
 
     pub fn performance_test(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "KIỂM TRA HIỆU NĂNG",
-            Language::English => "PERFORMANCE TEST",
+            lang => lang.translation("performance_test").unwrap_or("PERFORMANCE TEST"),
         }
     }
 
     pub fn smart_health(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "SỨC KHỎE SMART",
-            Language::English => "SMART HEALTH",
+            lang => lang.translation("smart_health").unwrap_or("SMART HEALTH"),
         }
     }
 
     pub fn smart_status(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "trạng thái SMART",
-            Language::English => "SMART status",
+            lang => lang.translation("smart_status").unwrap_or("SMART status"),
         }
     }
 
     pub fn temperature(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "nhiệt độ",
-            Language::English => "temperature",
+            lang => lang.translation("temperature").unwrap_or("temperature"),
+        }
+    }
+
+    pub fn fans(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "quạt",
+            lang => lang.translation("fans").unwrap_or("fans"),
+        }
+    }
+
+    pub fn cpu_working_set(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "vùng dữ liệu",
+            lang => lang.translation("cpu_working_set").unwrap_or("working set"),
+        }
+    }
+
+    pub fn fan_not_spinning(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Quạt không quay khi tải nặng ({FAN} ở 0 RPM, {TEMP}°C) - có thể quạt bị hỏng",
+            lang => lang.translation("fan_not_spinning").unwrap_or("Fan not spinning under load ({FAN} at 0 RPM, {TEMP}°C) - possible fan failure"),
+        }
+    }
+
+    pub fn temp_idle_to_peak(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "nghỉ → đỉnh",
+            lang => lang.translation("temp_idle_to_peak").unwrap_or("idle → peak"),
         }
     }
 
     pub fn frequency(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "xung nhịp",
-            Language::English => "frequency",
+            lang => lang.translation("frequency").unwrap_or("frequency"),
         }
     }
 
     pub fn power_on_hours(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "giờ hoạt động",
-            Language::English => "power on hours",
+            lang => lang.translation("power_on_hours").unwrap_or("power on hours"),
         }
     }
 
     pub fn power_cycles(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "chu kỳ bật",
-            Language::English => "power cycles",
+            lang => lang.translation("power_cycles").unwrap_or("power cycles"),
         }
     }
 
     pub fn model(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "mẫu",
-            Language::English => "model",
+            lang => lang.translation("model").unwrap_or("model"),
         }
     }
 
@@ -330,14 +463,14 @@ impl Text {
     pub fn gpu_health_check(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Kiểm tra GPU",
-            Language::English => "GPU Health Check",
+            lang => lang.translation("gpu_health_check").unwrap_or("GPU Health Check"),
         }
     }
 
     pub fn testing_gpu(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đang kiểm tra GPU",
-            Language::English => "Testing GPU",
+            lang => lang.translation("testing_gpu").unwrap_or("Testing GPU"),
         }
     }
 
@@ -345,63 +478,63 @@ impl Text {
     pub fn disk_label(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "đĩa",
-            Language::English => "disk",
+            lang => lang.translation("disk_label").unwrap_or("disk"),
         }
     }
 
     pub fn size(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "kích thước",
-            Language::English => "size",
+            lang => lang.translation("size").unwrap_or("size"),
         }
     }
 
     pub fn fs(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "fs",
-            Language::English => "fs",
+            lang => lang.translation("fs").unwrap_or("fs"),
         }
     }
 
     pub fn type_label(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "kiểu",
-            Language::English => "type",
+            lang => lang.translation("type_label").unwrap_or("type"),
         }
     }
 
     pub fn ssd(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "SSD",
-            Language::English => "SSD",
+            lang => lang.translation("ssd").unwrap_or("SSD"),
         }
     }
 
     pub fn hdd(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "HDD",
-            Language::English => "HDD",
+            lang => lang.translation("hdd").unwrap_or("HDD"),
         }
     }
 
     pub fn unified_memory(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Unified (chia sẻ)",
-            Language::English => "Unified (with CPU)",
+            lang => lang.translation("unified_memory").unwrap_or("Unified (with CPU)"),
         }
     }
 
     pub fn soc_see_cpu(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "SoC (xem CPU)",
-            Language::English => "SoC (see CPU)",
+            lang => lang.translation("soc_see_cpu").unwrap_or("SoC (see CPU)"),
         }
     }
 
     pub fn not_available(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "N/A",
-            Language::English => "N/A",
+            lang => lang.translation("not_available").unwrap_or("N/A"),
         }
     }
 
@@ -409,7 +542,7 @@ impl Text {
     pub fn sensors(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Cảm biến",
-            Language::English => "Sensors",
+            lang => lang.translation("sensors").unwrap_or("Sensors"),
         }
     }
 
@@ -417,63 +550,63 @@ impl Text {
     pub fn health(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "sức khỏe",
-            Language::English => "health",
+            lang => lang.translation("health").unwrap_or("health"),
         }
     }
 
     pub fn ssd_life(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "tuổi thọ SSD",
-            Language::English => "SSD life",
+            lang => lang.translation("ssd_life").unwrap_or("SSD life"),
         }
     }
 
     pub fn serial(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "số serial",
-            Language::English => "serial",
+            lang => lang.translation("serial").unwrap_or("serial"),
         }
     }
 
     pub fn firmware(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "firmware",
-            Language::English => "firmware",
+            lang => lang.translation("firmware").unwrap_or("firmware"),
         }
     }
 
     pub fn realloc_sectors(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "realloc sectors",
-            Language::English => "realloc sectors",
+            lang => lang.translation("realloc_sectors").unwrap_or("realloc sectors"),
         }
     }
 
     pub fn pending_sectors(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "pending sectors",
-            Language::English => "pending sectors",
+            lang => lang.translation("pending_sectors").unwrap_or("pending sectors"),
         }
     }
 
     pub fn realloc_events(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "realloc events",
-            Language::English => "realloc events",
+            lang => lang.translation("realloc_events").unwrap_or("realloc events"),
         }
     }
 
     pub fn total_written(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "tổng đã ghi",
-            Language::English => "total written",
+            lang => lang.translation("total_written").unwrap_or("total written"),
         }
     }
 
     pub fn total_read(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "tổng đã đọc",
-            Language::English => "total read",
+            lang => lang.translation("total_read").unwrap_or("total read"),
         }
     }
 
@@ -481,49 +614,49 @@ impl Text {
     pub fn gpu_freq(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "tần số",
-            Language::English => "GPU freq",
+            lang => lang.translation("gpu_freq").unwrap_or("GPU freq"),
         }
     }
 
     pub fn gpu_power(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "công suất",
-            Language::English => "GPU power",
+            lang => lang.translation("gpu_power").unwrap_or("GPU power"),
         }
     }
 
     pub fn gpu_usage(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "sử dụng",
-            Language::English => "GPU usage",
+            lang => lang.translation("gpu_usage").unwrap_or("GPU usage"),
         }
     }
 
     pub fn gpu_cores(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "nhân GPU",
-            Language::English => "GPU cores",
+            lang => lang.translation("gpu_cores").unwrap_or("GPU cores"),
         }
     }
 
     pub fn metal(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Metal",
-            Language::English => "Metal",
+            lang => lang.translation("metal").unwrap_or("Metal"),
         }
     }
 
     pub fn thermal_state(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "trạng thái nhiệt",
-            Language::English => "Thermal state",
+            lang => lang.translation("thermal_state").unwrap_or("Thermal state"),
         }
     }
 
     pub fn smc_temp(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "nhiệt độ SMC",
-            Language::English => "SMC temp",
+            lang => lang.translation("smc_temp").unwrap_or("SMC temp"),
         }
     }
 
@@ -531,21 +664,21 @@ impl Text {
     pub fn gpu_type_integrated(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Tích hợp",
-            Language::English => "Integrated",
+            lang => lang.translation("gpu_type_integrated").unwrap_or("Integrated"),
         }
     }
 
     pub fn gpu_type_discrete(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Rời",
-            Language::English => "Discrete",
+            lang => lang.translation("gpu_type_discrete").unwrap_or("Discrete"),
         }
     }
 
     pub fn gpu_type_unknown(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Không rõ",
-            Language::English => "Unknown",
+            lang => lang.translation("gpu_type_unknown").unwrap_or("Unknown"),
         }
     }
 
@@ -563,7 +696,7 @@ impl Text {
     pub fn torture_test(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "KIỂM TRA TỔNG THỂ",
-            Language::English => "TORTURE TEST (System Stress)",
+            lang => lang.translation("torture_test").unwrap_or("TORTURE TEST (System Stress)"),
         }
     }
 
@@ -572,30 +705,30 @@ impl Text {
             Language::Vietnamese => {
                 "Đây là bài test cường độ cao. Hệ thống sẽ bị đẩy tới giới hạn."
             }
-            Language::English => {
+            lang => lang.translation("torture_warning").unwrap_or(
                 "This is a high-intensity test. Your system will be pushed to its limits."
-            }
+            ),
         }
     }
 
     pub fn torture_warning_psu(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Máy có thể tắt đột ngột nếu nguồn (PSU) yếu",
-            Language::English => "System may shut down if power supply (PSU) is weak",
+            lang => lang.translation("torture_warning_psu").unwrap_or("System may shut down if power supply (PSU) is weak"),
         }
     }
 
     pub fn torture_warning_thermal(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Nhiệt độ sẽ tăng cao, quát sẽ chạy rất mạnh",
-            Language::English => "Temperatures will rise, fans will run at maximum speed",
+            lang => lang.translation("torture_warning_thermal").unwrap_or("Temperatures will rise, fans will run at maximum speed"),
         }
     }
 
     pub fn torture_warning_fans(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Quát kêu to là BÌNH THƯỜNG trong bài test này",
-            Language::English => "Loud fans are NORMAL during this test",
+            lang => lang.translation("torture_warning_fans").unwrap_or("Loud fans are NORMAL during this test"),
         }
     }
 
@@ -603,70 +736,77 @@ impl Text {
     pub fn duration(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Thời lượng",
-            Language::English => "Duration",
+            lang => lang.translation("duration").unwrap_or("Duration"),
         }
     }
 
     pub fn seconds(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "giây",
-            Language::English => "seconds",
+            lang => lang.translation("seconds").unwrap_or("seconds"),
         }
     }
 
     pub fn torture_cancel_info(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Nhấn Ctrl+C để hủy",
-            Language::English => "Press Ctrl+C to cancel",
+            lang => lang.translation("torture_cancel_info").unwrap_or("Press Ctrl+C to cancel"),
         }
     }
 
     pub fn torture_confirm(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Tiếp tục?",
-            Language::English => "Continue?",
+            lang => lang.translation("torture_confirm").unwrap_or("Continue?"),
         }
     }
 
     pub fn torture_cancelled(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đã hủy bài test",
-            Language::English => "Test cancelled",
+            lang => lang.translation("torture_cancelled").unwrap_or("Test cancelled"),
         }
     }
 
     pub fn torture_starting(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đang bắt đầu",
-            Language::English => "Starting",
+            lang => lang.translation("torture_starting").unwrap_or("Starting"),
         }
     }
 
     pub fn torture_summary(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "TÓM TẮT BÀI TEST TỔNG",
-            Language::English => "TORTURE TEST SUMMARY",
+            lang => lang.translation("torture_summary").unwrap_or("TORTURE TEST SUMMARY"),
         }
     }
 
     pub fn torture_duration(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Thời gian chạy",
-            Language::English => "Duration",
+            lang => lang.translation("torture_duration").unwrap_or("Duration"),
         }
     }
 
     pub fn torture_passed(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "HỆ THỐNG ĐÃ VƯỢT QUA BÀI TEST TỔNG! Máy ổn định.",
-            Language::English => "SYSTEM SURVIVED THE TORTURE TEST! Hardware is stable.",
+            lang => lang.translation("torture_passed").unwrap_or("SYSTEM SURVIVED THE TORTURE TEST! Hardware is stable."),
         }
     }
 
     pub fn torture_failed(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "HỆ THỐNG CÓ VẤN ĐỀ. Xem chi tiết bên trên.",
-            Language::English => "SYSTEM HAS ISSUES. See details above.",
+            lang => lang.translation("torture_failed").unwrap_or("SYSTEM HAS ISSUES. See details above."),
+        }
+    }
+
+    pub fn torture_emergency_stop(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "DỪNG KHẨN CẤP: nhiệt độ vượt {TEMP}°C quá lâu - đã dừng tất cả bài test",
+            lang => lang.translation("torture_emergency_stop").unwrap_or("EMERGENCY STOP: temperature stayed above {TEMP}°C - all tests stopped"),
         }
     }
 
@@ -674,7 +814,7 @@ impl Text {
     pub fn torture_dashboard_warning(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "QUÁT SẼ KÊU RẤT TO - ĐÂY LÀ BÌNH THƯỜNG",
-            Language::English => "LOUD FANS ARE NORMAL DURING THIS TEST",
+            lang => lang.translation("torture_dashboard_warning").unwrap_or("LOUD FANS ARE NORMAL DURING THIS TEST"),
         }
     }
 
@@ -682,120 +822,190 @@ impl Text {
     pub fn torture_cpu(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "CPU",
-            Language::English => "CPU",
+            lang => lang.translation("torture_cpu").unwrap_or("CPU"),
         }
     }
 
     pub fn torture_gpu(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "GPU",
-            Language::English => "GPU",
+            lang => lang.translation("torture_gpu").unwrap_or("GPU"),
         }
     }
 
     pub fn torture_ram(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "RAM",
-            Language::English => "RAM",
+            lang => lang.translation("torture_ram").unwrap_or("RAM"),
         }
     }
 
     pub fn torture_disk(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Ổ cứng",
-            Language::English => "Disk",
+            lang => lang.translation("torture_disk").unwrap_or("Disk"),
         }
     }
 
     pub fn torture_load(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "tải",
-            Language::English => "load",
+            lang => lang.translation("torture_load").unwrap_or("load"),
         }
     }
 
     pub fn torture_errors(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "lỗi",
-            Language::English => "errors",
+            lang => lang.translation("torture_errors").unwrap_or("errors"),
         }
     }
 
     pub fn torture_write(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "ghi",
-            Language::English => "write",
+            lang => lang.translation("torture_write").unwrap_or("write"),
         }
     }
 
     pub fn torture_read(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "đọc",
-            Language::English => "read",
+            lang => lang.translation("torture_read").unwrap_or("read"),
         }
     }
 
     pub fn torture_mb_s(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "MB/s",
-            Language::English => "MB/s",
+            lang => lang.translation("torture_mb_s").unwrap_or("MB/s"),
         }
     }
 
     pub fn torture_na(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "N/A",
-            Language::English => "N/A",
+            lang => lang.translation("torture_na").unwrap_or("N/A"),
         }
     }
 
     // Level selection prompt
+    pub fn menu_title(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Bạn muốn kiểm tra gì?",
+            lang => lang.translation("menu_title").unwrap_or("What do you want to check?"),
+        }
+    }
+
+    pub fn menu_info(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Chỉ xem thông tin phần cứng",
+            lang => lang.translation("menu_info").unwrap_or("Hardware info only"),
+        }
+    }
+
+    pub fn menu_cpu(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Kiểm tra CPU",
+            lang => lang.translation("menu_cpu").unwrap_or("CPU test"),
+        }
+    }
+
+    pub fn menu_ram(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Kiểm tra RAM",
+            lang => lang.translation("menu_ram").unwrap_or("RAM test"),
+        }
+    }
+
+    pub fn menu_disk(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Kiểm tra ổ cứng",
+            lang => lang.translation("menu_disk").unwrap_or("Disk test"),
+        }
+    }
+
+    pub fn menu_gpu(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Kiểm tra GPU",
+            lang => lang.translation("menu_gpu").unwrap_or("GPU test"),
+        }
+    }
+
+    pub fn menu_full_auto(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Kiểm tra toàn bộ tự động (tất cả bài test + test tổng)",
+            lang => lang.translation("menu_full_auto").unwrap_or("Full auto check (every test, then the torture test)"),
+        }
+    }
+
+    pub fn menu_torture(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Test tổng (tất cả linh kiện cùng lúc)",
+            lang => lang.translation("menu_torture").unwrap_or("Torture test (all components at once)"),
+        }
+    }
+
+    pub fn menu_quit(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Thoát",
+            lang => lang.translation("menu_quit").unwrap_or("Quit"),
+        }
+    }
+
+    pub fn menu_invalid_choice(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Lựa chọn không hợp lệ. Vui lòng chọn từ 0 đến 7.",
+            lang => lang.translation("menu_invalid_choice").unwrap_or("Invalid choice. Please select 0-7."),
+        }
+    }
+
     pub fn select_test_level(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Chọn mức độ kiểm tra",
-            Language::English => "Select test level",
+            lang => lang.translation("select_test_level").unwrap_or("Select test level"),
         }
     }
 
     pub fn level_quick(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Nhanh",
-            Language::English => "Quick",
+            lang => lang.translation("level_quick").unwrap_or("Quick"),
         }
     }
 
     pub fn level_normal(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Thường",
-            Language::English => "Normal",
+            lang => lang.translation("level_normal").unwrap_or("Normal"),
         }
     }
 
     pub fn level_deep(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Chuyên sâu",
-            Language::English => "Deep",
+            lang => lang.translation("level_deep").unwrap_or("Deep"),
         }
     }
 
     pub fn your_choice(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Lựa chọn của bạn",
-            Language::English => "Your choice",
+            lang => lang.translation("your_choice").unwrap_or("Your choice"),
         }
     }
 
     pub fn invalid_choice(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Lựa chọn không hợp lệ. Vui lòng chọn 1, 2 hoặc 3.",
-            Language::English => "Invalid choice. Please select 1, 2, or 3.",
+            lang => lang.translation("invalid_choice").unwrap_or("Invalid choice. Please select 1, 2, or 3."),
         }
     }
 
     pub fn torture_final(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "BÀI TEST TỔNG (TORTURE TEST)",
-            Language::English => "FINAL TEST (TORTURE TEST)",
+            lang => lang.translation("torture_final").unwrap_or("FINAL TEST (TORTURE TEST)"),
         }
     }
 
@@ -803,28 +1013,28 @@ impl Text {
     pub fn ai_greet(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Xin chào! Để tôi khám sức khỏe cho chiếc máy này nhé.",
-            Language::English => "Hello! Let me check the health of this machine.",
+            lang => lang.translation("ai_greet").unwrap_or("Hello! Let me check the health of this machine."),
         }
     }
 
     pub fn ai_detecting(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đang ngó qua cấu hình phần cứng một chút...",
-            Language::English => "Taking a quick look at the hardware configuration...",
+            lang => lang.translation("ai_detecting").unwrap_or("Taking a quick look at the hardware configuration..."),
         }
     }
 
     pub fn ai_specs_good(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Chà, máy ngon đấy! Cấu hình này dư sức làm việc nặng.",
-            Language::English => "Wow, nice machine! This config can handle heavy workloads.",
+            lang => lang.translation("ai_specs_good").unwrap_or("Wow, nice machine! This config can handle heavy workloads."),
         }
     }
 
     pub fn ai_specs_ok(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Cấu hình ổn định, đủ dùng cho công việc hàng ngày.",
-            Language::English => "Decent configuration, good enough for daily tasks.",
+            lang => lang.translation("ai_specs_ok").unwrap_or("Decent configuration, good enough for daily tasks."),
         }
     }
 
@@ -832,21 +1042,21 @@ impl Text {
     pub fn ai_pass(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Hoàn thành tốt. Không phát hiện vấn đề.",
-            Language::English => "Test passed. No issues detected.",
+            lang => lang.translation("ai_pass").unwrap_or("Test passed. No issues detected."),
         }
     }
 
     pub fn ai_warning(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Hoàn thành nhưng có cảnh báo. Nên kiểm tra lại.",
-            Language::English => "Test completed with warnings. Recommend review.",
+            lang => lang.translation("ai_warning").unwrap_or("Test completed with warnings. Recommend review."),
         }
     }
 
     pub fn ai_fail(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Thất bại. Cần kiểm tra ngay.",
-            Language::English => "Test failed. Immediate attention needed.",
+            lang => lang.translation("ai_fail").unwrap_or("Test failed. Immediate attention needed."),
         }
     }
 
@@ -855,7 +1065,7 @@ impl Text {
     pub fn temp_status_excellent(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Rất tốt",
-            Language::English => "Very good",
+            lang => lang.translation("temp_status_excellent").unwrap_or("Very good"),
         }
     }
 
@@ -863,7 +1073,7 @@ impl Text {
     pub fn temp_status_stable(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Ổn định",
-            Language::English => "Stable",
+            lang => lang.translation("temp_status_stable").unwrap_or("Stable"),
         }
     }
 
@@ -871,7 +1081,7 @@ impl Text {
     pub fn temp_status_warm(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Ấm",
-            Language::English => "Warm",
+            lang => lang.translation("temp_status_warm").unwrap_or("Warm"),
         }
     }
 
@@ -879,7 +1089,7 @@ impl Text {
     pub fn temp_status_hot(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Nóng",
-            Language::English => "Hot",
+            lang => lang.translation("temp_status_hot").unwrap_or("Hot"),
         }
     }
 
@@ -888,7 +1098,7 @@ impl Text {
     pub fn billion_suffix(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Tỷ",
-            Language::English => "Billion",
+            lang => lang.translation("billion_suffix").unwrap_or("Billion"),
         }
     }
 
@@ -896,7 +1106,7 @@ impl Text {
     pub fn million_suffix(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Triệu",
-            Language::English => "Million",
+            lang => lang.translation("million_suffix").unwrap_or("Million"),
         }
     }
 
@@ -905,7 +1115,7 @@ impl Text {
     pub fn language_select_prompt(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Chọn ngôn ngữ / Select language:",
-            Language::English => "Select language",
+            lang => lang.translation("language_select_prompt").unwrap_or("Select language"),
         }
     }
 
@@ -913,7 +1123,7 @@ impl Text {
     pub fn language_option_vi(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Tiếng Việt",
-            Language::English => "Vietnamese",
+            lang => lang.translation("language_option_vi").unwrap_or("Vietnamese"),
         }
     }
 
@@ -921,23 +1131,38 @@ impl Text {
     pub fn language_option_en(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "English",
-            Language::English => "English",
+            lang => lang.translation("language_option_en").unwrap_or("English"),
+        }
+    }
+
+    /// Language names in the menu are shown in their own language
+    pub fn language_option_fr(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Français",
+            lang => lang.translation("language_option_fr").unwrap_or("Français"),
+        }
+    }
+
+    pub fn language_option_es(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Español",
+            lang => lang.translation("language_option_es").unwrap_or("Español"),
         }
     }
 
     #[allow(dead_code)] // Reserved for future i18n features
     pub fn language_choice_prompt(&self) -> &str {
         match self.lang {
-            Language::Vietnamese => "Lựa chọn của bạn [1-2]: ",
-            Language::English => "Your choice [1-2]: ",
+            Language::Vietnamese => "Lựa chọn của bạn [1-4]: ",
+            lang => lang.translation("language_choice_prompt").unwrap_or("Your choice [1-4]: "),
         }
     }
 
     #[allow(dead_code)] // Reserved for future i18n features
     pub fn language_invalid_choice(&self) -> &str {
         match self.lang {
-            Language::Vietnamese => "⚠️  Lựa chọn không hợp lệ. Vui lòng chọn 1 hoặc 2.",
-            Language::English => "⚠️  Invalid choice. Please select 1 or 2.",
+            Language::Vietnamese => "⚠️  Lựa chọn không hợp lệ. Vui lòng chọn từ 1 đến 4.",
+            lang => lang.translation("language_invalid_choice").unwrap_or("⚠️  Invalid choice. Please select 1-4."),
         }
     }
 
@@ -946,7 +1171,7 @@ impl Text {
     pub fn progress_allocating(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đang cấp phát...",
-            Language::English => "Allocating...",
+            lang => lang.translation("progress_allocating").unwrap_or("Allocating..."),
         }
     }
 
@@ -954,7 +1179,7 @@ impl Text {
     pub fn progress_writing(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đang ghi...",
-            Language::English => "Writing...",
+            lang => lang.translation("progress_writing").unwrap_or("Writing..."),
         }
     }
 
@@ -962,7 +1187,7 @@ impl Text {
     pub fn progress_reading(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đang đọc...",
-            Language::English => "Reading...",
+            lang => lang.translation("progress_reading").unwrap_or("Reading..."),
         }
     }
 
@@ -970,7 +1195,7 @@ impl Text {
     pub fn progress_verifying(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đang xác minh...",
-            Language::English => "Verifying...",
+            lang => lang.translation("progress_verifying").unwrap_or("Verifying..."),
         }
     }
 
@@ -978,7 +1203,7 @@ impl Text {
     pub fn progress_complete(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Hoàn thành",
-            Language::English => "Complete",
+            lang => lang.translation("progress_complete").unwrap_or("Complete"),
         }
     }
 
@@ -987,7 +1212,7 @@ impl Text {
     pub fn status_ok(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "OK",
-            Language::English => "OK",
+            lang => lang.translation("status_ok").unwrap_or("OK"),
         }
     }
 
@@ -995,7 +1220,7 @@ impl Text {
     pub fn status_ok_no_temp(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "OK (không có dữ liệu nhiệt độ)",
-            Language::English => "OK (no temp data)",
+            lang => lang.translation("status_ok_no_temp").unwrap_or("OK (no temp data)"),
         }
     }
 
@@ -1004,7 +1229,7 @@ impl Text {
     pub fn gpu_compute_unavailable(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "GPU compute không khả dụng",
-            Language::English => "GPU compute unavailable",
+            lang => lang.translation("gpu_compute_unavailable").unwrap_or("GPU compute unavailable"),
         }
     }
 
@@ -1012,7 +1237,7 @@ impl Text {
     pub fn gpu_fallback_thermal(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Dự phòng: theo dõi nhiệt độ...",
-            Language::English => "Falling back to thermal monitoring...",
+            lang => lang.translation("gpu_fallback_thermal").unwrap_or("Falling back to thermal monitoring..."),
         }
     }
 
@@ -1020,7 +1245,7 @@ impl Text {
     pub fn gpu_soc_needs_sudo(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "SoC (cần sudo)",
-            Language::English => "SoC (needs sudo)",
+            lang => lang.translation("gpu_soc_needs_sudo").unwrap_or("SoC (needs sudo)"),
         }
     }
 
@@ -1028,7 +1253,7 @@ impl Text {
     pub fn gpu_no_sensor(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "N/A (không có cảm biến)",
-            Language::English => "N/A (no sensor)",
+            lang => lang.translation("gpu_no_sensor").unwrap_or("N/A (no sensor)"),
         }
     }
 
@@ -1037,7 +1262,7 @@ impl Text {
     pub fn cpu_crashed(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "CPU bị treo trong bài test - PHẦN CỨNG LỖI",
-            Language::English => "CPU crashed during test - FAULTY HARDWARE",
+            lang => lang.translation("cpu_crashed").unwrap_or("CPU crashed during test - FAULTY HARDWARE"),
         }
     }
 
@@ -1045,7 +1270,7 @@ impl Text {
     pub fn cpu_overheating(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "CPU quá nhiệt ({:.1}°C) - thất bại hệ thống tản nhiệt",
-            Language::English => "CPU overheating ({:.1}°C) - cooling system failure",
+            lang => lang.translation("cpu_overheating").unwrap_or("CPU overheating ({:.1}°C) - cooling system failure"),
         }
     }
 
@@ -1053,7 +1278,7 @@ impl Text {
     pub fn cpu_running_hot(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "CPU đang chạy nóng ({:.1}°C) - kiểm tra tản nhiệt",
-            Language::English => "CPU running hot ({:.1}°C) - check cooling",
+            lang => lang.translation("cpu_running_hot").unwrap_or("CPU running hot ({:.1}°C) - check cooling"),
         }
     }
 
@@ -1061,7 +1286,7 @@ impl Text {
     pub fn cpu_throttled(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "CPU bị giới hạn {:.1}% - có thể do nhiệt hoặc công suất",
-            Language::English => "CPU throttled by {:.1}% - possible thermal or power limit",
+            lang => lang.translation("cpu_throttled").unwrap_or("CPU throttled by {:.1}% - possible thermal or power limit"),
         }
     }
 
@@ -1071,9 +1296,9 @@ impl Text {
             Language::Vietnamese => {
                 "Phát hiện độ không ổn định cực cao (variance: {:.1}%) - có thể lỗi CPU"
             }
-            Language::English => {
+            lang => lang.translation("cpu_extreme_instability").unwrap_or(
                 "Extreme instability detected (variance: {:.1}%) - possible CPU fault"
-            }
+            ),
         }
     }
 
@@ -1082,7 +1307,7 @@ impl Text {
     pub fn ram_errors_detected(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Phát hiện lỗi bộ nhớ ({} lỗi) - RAM HỎNG",
-            Language::English => "Memory errors detected ({} errors) - BAD RAM",
+            lang => lang.translation("ram_errors_detected").unwrap_or("Memory errors detected ({} errors) - BAD RAM"),
         }
     }
 
@@ -1090,7 +1315,7 @@ impl Text {
     pub fn ram_allocation_failed(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Không thể cấp phát bộ nhớ",
-            Language::English => "Memory allocation failed",
+            lang => lang.translation("ram_allocation_failed").unwrap_or("Memory allocation failed"),
         }
     }
 
@@ -1098,9 +1323,9 @@ impl Text {
     pub fn ram_low_write_speed(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Tốc độ ghi quá chậm ({:.1} GB/s) - RAM hỏng hoặc sai khe",
-            Language::English => {
+            lang => lang.translation("ram_low_write_speed").unwrap_or(
                 "Extremely low write speed ({:.1} GB/s) - faulty RAM or wrong slot"
-            }
+            ),
         }
     }
 
@@ -1108,7 +1333,7 @@ impl Text {
     pub fn ram_low_read_speed(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Tốc độ đọc quá chậm ({:.1} GB/s) - RAM hỏng hoặc sai khe",
-            Language::English => "Extremely low read speed ({:.1} GB/s) - faulty RAM or wrong slot",
+            lang => lang.translation("ram_low_read_speed").unwrap_or("Extremely low read speed ({:.1} GB/s) - faulty RAM or wrong slot"),
         }
     }
 
@@ -1117,7 +1342,7 @@ impl Text {
     pub fn disk_write_permission_error(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Không thể ghi đĩa - kiểm tra quyền hoặc dung lượng đĩa",
-            Language::English => "Cannot write to disk - check permissions or disk space",
+            lang => lang.translation("disk_write_permission_error").unwrap_or("Cannot write to disk - check permissions or disk space"),
         }
     }
 
@@ -1125,7 +1350,7 @@ impl Text {
     pub fn disk_read_failure(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Bài test đọc thất bại - có thể lỗi đĩa",
-            Language::English => "Read test failed - possible disk failure",
+            lang => lang.translation("disk_read_failure").unwrap_or("Read test failed - possible disk failure"),
         }
     }
 
@@ -1133,7 +1358,7 @@ impl Text {
     pub fn disk_bad_sectors(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Phát hiện bad sector ({} sectors) - đĩa sắp hỏng",
-            Language::English => "Bad sectors detected ({} sectors) - disk failure imminent",
+            lang => lang.translation("disk_bad_sectors").unwrap_or("Bad sectors detected ({} sectors) - disk failure imminent"),
         }
     }
 
@@ -1141,7 +1366,7 @@ impl Text {
     pub fn disk_slow_read(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Tốc độ đọc quá chậm ({:.1} MB/s) - đĩa sắp hỏng",
-            Language::English => "Extremely slow read speed ({:.1} MB/s) - dying disk",
+            lang => lang.translation("disk_slow_read").unwrap_or("Extremely slow read speed ({:.1} MB/s) - dying disk"),
         }
     }
 
@@ -1149,7 +1374,7 @@ impl Text {
     pub fn disk_slow_write(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Tốc độ ghi quá chậm ({:.1} MB/s) - đĩa sắp hỏng",
-            Language::English => "Extremely slow write speed ({:.1} MB/s) - dying disk",
+            lang => lang.translation("disk_slow_write").unwrap_or("Extremely slow write speed ({:.1} MB/s) - dying disk"),
         }
     }
 
@@ -1157,7 +1382,7 @@ impl Text {
     pub fn disk_slow_seek(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Thời gian seek chậm ({:.1}ms) - có thể vấn đề cơ học",
-            Language::English => "Slow seek time ({:.1}ms) - possible mechanical issue",
+            lang => lang.translation("disk_slow_seek").unwrap_or("Slow seek time ({:.1}ms) - possible mechanical issue"),
         }
     }
 
@@ -1165,7 +1390,7 @@ impl Text {
     pub fn disk_ssd_slow(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Tốc độ SSD đọc dưới trung bình ({:.1} MB/s)",
-            Language::English => "SSD read speed below average ({:.1} MB/s)",
+            lang => lang.translation("disk_ssd_slow").unwrap_or("SSD read speed below average ({:.1} MB/s)"),
         }
     }
 
@@ -1173,7 +1398,7 @@ impl Text {
     pub fn disk_hdd_slow(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Tốc độ HDD đọc dưới trung bình ({:.1} MB/s)",
-            Language::English => "HDD read speed below average ({:.1} MB/s)",
+            lang => lang.translation("disk_hdd_slow").unwrap_or("HDD read speed below average ({:.1} MB/s)"),
         }
     }
 
@@ -1182,7 +1407,7 @@ impl Text {
     pub fn ai_cpu_temp_hot(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Nhiệt độ CPU {:.0}°C - đang chạy khá nóng",
-            Language::English => "CPU temperature at {:.0}°C - running hot",
+            lang => lang.translation("ai_cpu_temp_hot").unwrap_or("CPU temperature at {:.0}°C - running hot"),
         }
     }
 
@@ -1190,7 +1415,7 @@ impl Text {
     pub fn ai_cpu_temp_warming(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Nhiệt độ CPU {:.0}°C - đang ấm dần lên",
-            Language::English => "CPU temperature at {:.0}°C - warming up nicely",
+            lang => lang.translation("ai_cpu_temp_warming").unwrap_or("CPU temperature at {:.0}°C - warming up nicely"),
         }
     }
 
@@ -1198,7 +1423,7 @@ impl Text {
     pub fn ai_ram_speed_excellent(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Tốc độ ghi RAM: {:.1} GB/s - xuất sắc",
-            Language::English => "RAM write speed: {:.1} GB/s - excellent",
+            lang => lang.translation("ai_ram_speed_excellent").unwrap_or("RAM write speed: {:.1} GB/s - excellent"),
         }
     }
 
@@ -1206,7 +1431,7 @@ impl Text {
     pub fn ai_ram_speed_good(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Tốc độ ghi RAM: {:.1} GB/s - tốt",
-            Language::English => "RAM write speed: {:.1} GB/s - good",
+            lang => lang.translation("ai_ram_speed_good").unwrap_or("RAM write speed: {:.1} GB/s - good"),
         }
     }
 
@@ -1214,7 +1439,7 @@ impl Text {
     pub fn ai_ssd_speed_excellent(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "{DISK} SSD đọc: {SPEED:.1} MB/s - xuất sắc",
-            Language::English => "{DISK} SSD read speed: {SPEED:.1} MB/s - excellent",
+            lang => lang.translation("ai_ssd_speed_excellent").unwrap_or("{DISK} SSD read speed: {SPEED:.1} MB/s - excellent"),
         }
     }
 
@@ -1222,7 +1447,7 @@ impl Text {
     pub fn ai_ssd_speed_good(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "{DISK} SSD đọc: {SPEED:.1} MB/s - tốt",
-            Language::English => "{DISK} SSD read speed: {SPEED:.1} MB/s - good",
+            lang => lang.translation("ai_ssd_speed_good").unwrap_or("{DISK} SSD read speed: {SPEED:.1} MB/s - good"),
         }
     }
 
@@ -1230,7 +1455,7 @@ impl Text {
     pub fn ai_ssd_speed_below_avg(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "{DISK} SSD đọc: {SPEED:.1} MB/s - dưới trung bình",
-            Language::English => "{DISK} SSD read speed: {SPEED:.1} MB/s - below average",
+            lang => lang.translation("ai_ssd_speed_below_avg").unwrap_or("{DISK} SSD read speed: {SPEED:.1} MB/s - below average"),
         }
     }
 
@@ -1238,7 +1463,7 @@ impl Text {
     pub fn ai_hdd_speed_excellent(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "{DISK} HDD đọc: {SPEED:.1} MB/s - xuất sắc",
-            Language::English => "{DISK} HDD read speed: {SPEED:.1} MB/s - excellent",
+            lang => lang.translation("ai_hdd_speed_excellent").unwrap_or("{DISK} HDD read speed: {SPEED:.1} MB/s - excellent"),
         }
     }
 
@@ -1246,7 +1471,7 @@ impl Text {
     pub fn ai_hdd_speed_good(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "{DISK} HDD đọc: {SPEED:.1} MB/s - tốt",
-            Language::English => "{DISK} HDD read speed: {SPEED:.1} MB/s - good",
+            lang => lang.translation("ai_hdd_speed_good").unwrap_or("{DISK} HDD read speed: {SPEED:.1} MB/s - good"),
         }
     }
 
@@ -1255,7 +1480,7 @@ impl Text {
     pub fn smart_verified(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đã xác minh",
-            Language::English => "Verified",
+            lang => lang.translation("smart_verified").unwrap_or("Verified"),
         }
     }
 
@@ -1263,7 +1488,7 @@ impl Text {
     pub fn smart_failing(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đang thất bại",
-            Language::English => "Failing",
+            lang => lang.translation("smart_failing").unwrap_or("Failing"),
         }
     }
 
@@ -1271,7 +1496,7 @@ impl Text {
     pub fn smart_unknown(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Không rõ",
-            Language::English => "Unknown",
+            lang => lang.translation("smart_unknown").unwrap_or("Unknown"),
         }
     }
 
@@ -1279,7 +1504,7 @@ impl Text {
     pub fn thermal_nominal(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Bình thường",
-            Language::English => "Nominal",
+            lang => lang.translation("thermal_nominal").unwrap_or("Nominal"),
         }
     }
 
@@ -1287,7 +1512,7 @@ impl Text {
     pub fn thermal_moderate(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Vừa phải",
-            Language::English => "Moderate",
+            lang => lang.translation("thermal_moderate").unwrap_or("Moderate"),
         }
     }
 
@@ -1295,7 +1520,7 @@ impl Text {
     pub fn thermal_heavy(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Nặng",
-            Language::English => "Heavy",
+            lang => lang.translation("thermal_heavy").unwrap_or("Heavy"),
         }
     }
 
@@ -1303,7 +1528,7 @@ impl Text {
     pub fn thermal_trapping(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đang bẫy nhiệt",
-            Language::English => "Trapping",
+            lang => lang.translation("thermal_trapping").unwrap_or("Trapping"),
         }
     }
 
@@ -1311,7 +1536,7 @@ impl Text {
     pub fn thermal_sleeping(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đang ngủ",
-            Language::English => "Sleeping",
+            lang => lang.translation("thermal_sleeping").unwrap_or("Sleeping"),
         }
     }
 
@@ -1320,7 +1545,7 @@ impl Text {
     pub fn issue_cpu(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "CPU: {}",
-            Language::English => "CPU: {}",
+            lang => lang.translation("issue_cpu").unwrap_or("CPU: {}"),
         }
     }
 
@@ -1328,7 +1553,7 @@ impl Text {
     pub fn issue_ram(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "RAM: {}",
-            Language::English => "RAM: {}",
+            lang => lang.translation("issue_ram").unwrap_or("RAM: {}"),
         }
     }
 
@@ -1336,7 +1561,7 @@ impl Text {
     pub fn issue_disk(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đĩa: {}",
-            Language::English => "Disk: {}",
+            lang => lang.translation("issue_disk").unwrap_or("Disk: {}"),
         }
     }
 
@@ -1344,7 +1569,7 @@ impl Text {
     pub fn issue_disk_indexed(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "Đĩa #{} ({}): {}",
-            Language::English => "Disk #{} ({}): {}",
+            lang => lang.translation("issue_disk_indexed").unwrap_or("Disk #{} ({}): {}"),
         }
     }
 
@@ -1352,7 +1577,595 @@ impl Text {
     pub fn issue_gpu(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "GPU: {}",
-            Language::English => "GPU: {}",
+            lang => lang.translation("issue_gpu").unwrap_or("GPU: {}"),
+        }
+    }
+
+    pub fn disk_no_writable_location(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Không có vị trí ghi được để kiểm tra ổ đĩa",
+            lang => lang.translation("disk_no_writable_location").unwrap_or("No writable location for disk test"),
+        }
+    }
+
+    pub fn disk_insufficient_space(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Không đủ dung lượng trống cho bài kiểm tra ổ đĩa: cần {SIZE} MB, còn trống {FREE} GB",
+            lang => lang.translation("disk_insufficient_space").unwrap_or("Not enough free space for disk test: {SIZE} MB requested, {FREE} GB available"),
+        }
+    }
+
+    pub fn interrupted(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Đã dừng bởi Ctrl+C - đã dọn dẹp các tệp kiểm tra",
+            lang => lang.translation("interrupted").unwrap_or("Interrupted by Ctrl+C - test files cleaned up"),
+        }
+    }
+
+    pub fn cpu_workload(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "tải kiểm tra",
+            lang => lang.translation("cpu_workload").unwrap_or("workload"),
+        }
+    }
+
+    pub fn gpu_throughput(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "hiệu năng tính toán",
+            lang => lang.translation("gpu_throughput").unwrap_or("compute throughput"),
+        }
+    }
+
+    pub fn gpu_compute_load(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "tải tính toán",
+            lang => lang.translation("gpu_compute_load").unwrap_or("compute load"),
+        }
+    }
+
+    pub fn gpu_frame(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "khung",
+            lang => lang.translation("gpu_frame").unwrap_or("frame"),
+        }
+    }
+
+    pub fn gpu_frames(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "khung",
+            lang => lang.translation("gpu_frames").unwrap_or("frames"),
+        }
+    }
+
+    pub fn gpu_thermal_only(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "chỉ theo dõi nhiệt",
+            lang => lang.translation("gpu_thermal_only").unwrap_or("thermal monitoring only"),
+        }
+    }
+
+    pub fn gpu_vram_used(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "VRAM đã dùng",
+            lang => lang.translation("gpu_vram_used").unwrap_or("VRAM used"),
+        }
+    }
+
+    pub fn watch_iteration(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Theo dõi lần #{N} - {TIME}",
+            lang => lang.translation("watch_iteration").unwrap_or("Watch #{N} - {TIME}"),
+        }
+    }
+
+    pub fn watch_next_run(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Lần kiểm tra tiếp theo sau {MIN} phút (Ctrl+C để dừng)",
+            lang => lang.translation("watch_next_run").unwrap_or("Next check in {MIN} min (Ctrl+C to stop)"),
+        }
+    }
+
+    pub fn random_read_iops(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "đọc ngẫu nhiên 4K",
+            lang => lang.translation("random_read_iops").unwrap_or("random 4K read"),
+        }
+    }
+
+    pub fn turbo_boost(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "turbo boost",
+            lang => lang.translation("turbo_boost").unwrap_or("turbo boost"),
+        }
+    }
+
+    pub fn boost_enabled(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Bật",
+            lang => lang.translation("boost_enabled").unwrap_or("Enabled"),
+        }
+    }
+
+    pub fn boost_disabled(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Tắt",
+            lang => lang.translation("boost_disabled").unwrap_or("Disabled"),
+        }
+    }
+
+    pub fn boost_disabled_note(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Turbo boost đang tắt - hiệu năng tối đa bị giới hạn ở xung nhịp cơ bản",
+            lang => lang.translation("boost_disabled_note").unwrap_or("Turbo boost is disabled - peak performance is capped at base clock"),
+        }
+    }
+
+    pub fn time_per_component(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Thời gian từng phần:",
+            lang => lang.translation("time_per_component").unwrap_or("Time per component:"),
+        }
+    }
+
+    pub fn trim(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "TRIM",
+            lang => lang.translation("trim").unwrap_or("TRIM"),
+        }
+    }
+
+    pub fn trim_on(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Đang bật",
+            lang => lang.translation("trim_on").unwrap_or("Enabled"),
+        }
+    }
+
+    pub fn trim_off(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Đang tắt",
+            lang => lang.translation("trim_off").unwrap_or("Disabled"),
+        }
+    }
+
+    pub fn trim_unsupported(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Không hỗ trợ",
+            lang => lang.translation("trim_unsupported").unwrap_or("Not supported"),
+        }
+    }
+
+    pub fn trim_disabled_advisory(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "{DISK} hỗ trợ TRIM nhưng TRIM đang tắt - hiệu năng SSD sẽ giảm dần theo thời gian",
+            lang => lang.translation("trim_disabled_advisory").unwrap_or("{DISK} supports TRIM but it is disabled - SSD performance will degrade over time"),
+        }
+    }
+
+    // ========== Verdict Criteria ==========
+    pub fn hardware_not_fully_verified(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Không thể xác minh đầy đủ phần cứng",
+            lang => lang.translation("hardware_not_fully_verified").unwrap_or("Could not fully verify hardware"),
+        }
+    }
+
+    pub fn unmet_criteria(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Tiêu chí chưa đạt:",
+            lang => lang.translation("unmet_criteria").unwrap_or("Unmet criteria:"),
+        }
+    }
+
+    pub fn criteria_no_thermal(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "{COMPONENT}: không có dữ liệu nhiệt độ",
+            lang => lang.translation("criteria_no_thermal").unwrap_or("{COMPONENT}: no thermal data"),
+        }
+    }
+
+    pub fn criteria_smart_unverified(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "{DISK}: trạng thái SMART chưa được xác minh",
+            lang => lang.translation("criteria_smart_unverified").unwrap_or("{DISK}: SMART status not verified"),
+        }
+    }
+
+    pub fn criteria_ssd_life_low(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "{DISK}: tuổi thọ SSD {PCT}% thấp hơn mức yêu cầu {MIN}%",
+            lang => lang.translation("criteria_ssd_life_low").unwrap_or("{DISK}: SSD life {PCT}% is below the required {MIN}%"),
+        }
+    }
+
+    pub fn criteria_ssd_life_unknown(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "{DISK}: không rõ tuổi thọ SSD",
+            lang => lang.translation("criteria_ssd_life_unknown").unwrap_or("{DISK}: SSD life unknown"),
+        }
+    }
+
+    // ========== Expected Performance ==========
+    pub fn perf_compare_title(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "HIỆU NĂNG SO VỚI KỲ VỌNG",
+            lang => lang.translation("perf_compare_title").unwrap_or("EXPECTED PERFORMANCE"),
+        }
+    }
+
+    pub fn perf_as_expected(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "{COMPONENT}: đúng kỳ vọng ({PCT}% so với mức chuẩn của {MODEL})",
+            lang => lang.translation("perf_as_expected").unwrap_or("{COMPONENT}: performing as expected ({PCT}% of expected for {MODEL})"),
+        }
+    }
+
+    pub fn perf_underperforming(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "{COMPONENT} thấp hơn {PCT}% so với kỳ vọng cho {MODEL}",
+            lang => lang.translation("perf_underperforming").unwrap_or("{COMPONENT} scored {PCT}% below expected for {MODEL}"),
+        }
+    }
+
+    pub fn perf_no_baseline(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "{COMPONENT}: chưa có dữ liệu chuẩn cho {MODEL}",
+            lang => lang.translation("perf_no_baseline").unwrap_or("{COMPONENT}: no baseline for {MODEL}"),
+        }
+    }
+
+    pub fn perf_hint_cpu(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "kiểm tra tản nhiệt/chế độ nguồn",
+            lang => lang.translation("perf_hint_cpu").unwrap_or("check cooling/power settings"),
+        }
+    }
+
+    pub fn perf_hint_ram(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "kiểm tra chạy kênh đôi và XMP/EXPO",
+            lang => lang.translation("perf_hint_ram").unwrap_or("check dual-channel and XMP/EXPO settings"),
+        }
+    }
+
+    pub fn perf_hint_disk(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "kiểm tra cổng kết nối và dung lượng trống của ổ",
+            lang => lang.translation("perf_hint_disk").unwrap_or("check the drive interface and free space"),
+        }
+    }
+
+    pub fn perf_debug_build(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Bản build debug - kết quả sẽ thấp hơn mức chuẩn",
+            lang => lang.translation("perf_debug_build").unwrap_or("Debug build - results will be lower than the baselines"),
+        }
+    }
+
+    // ========== GPU Sustained Test ==========
+    pub fn gpu_soak_health_check(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "KIỂM TRA GPU LIÊN TỤC",
+            lang => lang.translation("gpu_soak_health_check").unwrap_or("GPU SUSTAINED TEST"),
+        }
+    }
+
+    pub fn testing_gpu_soak(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Đang chạy GPU đến khi nhiệt độ ổn định",
+            lang => lang.translation("testing_gpu_soak").unwrap_or("Running GPU until temperature stabilizes"),
+        }
+    }
+
+    pub fn steady_state_temp(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Nhiệt độ ổn định",
+            lang => lang.translation("steady_state_temp").unwrap_or("Steady-state temp"),
+        }
+    }
+
+    pub fn time_to_stabilize(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Thời gian ổn định",
+            lang => lang.translation("time_to_stabilize").unwrap_or("Time to stabilize"),
+        }
+    }
+
+    pub fn gpu_not_stabilized(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Chưa ổn định",
+            lang => lang.translation("gpu_not_stabilized").unwrap_or("Not stabilized"),
+        }
+    }
+
+    pub fn gpu_load(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Tải GPU",
+            lang => lang.translation("gpu_load").unwrap_or("GPU load"),
+        }
+    }
+
+    pub fn gpu_monitoring_only(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Chỉ theo dõi nhiệt",
+            lang => lang.translation("gpu_monitoring_only").unwrap_or("Monitoring only"),
+        }
+    }
+
+    // ========== Memory Pressure ==========
+    pub fn memory_pressure(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Áp lực bộ nhớ",
+            lang => lang.translation("memory_pressure").unwrap_or("Memory pressure"),
+        }
+    }
+
+    pub fn pressure_normal(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Bình thường",
+            lang => lang.translation("pressure_normal").unwrap_or("Normal"),
+        }
+    }
+
+    pub fn pressure_warning(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Cao",
+            lang => lang.translation("pressure_warning").unwrap_or("Elevated"),
+        }
+    }
+
+    pub fn pressure_critical(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Nghiêm trọng",
+            lang => lang.translation("pressure_critical").unwrap_or("Critical"),
+        }
+    }
+
+    pub fn commit_charge(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Bộ nhớ cam kết",
+            lang => lang.translation("commit_charge").unwrap_or("Commit charge"),
+        }
+    }
+
+    pub fn memory_compressed(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Đã nén",
+            lang => lang.translation("memory_compressed").unwrap_or("Compressed"),
+        }
+    }
+
+    pub fn memory_pressure_warning(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Hệ thống đang nén/thiếu bộ nhớ - dung lượng kiểm tra RAM sẽ bị giới hạn và kết quả có thể bị ảnh hưởng",
+            lang => lang.translation("memory_pressure_warning").unwrap_or("Memory compression/pressure is active - RAM test size will be limited and results may be affected"),
+        }
+    }
+
+    // ========== Idle Core Temperature ==========
+    pub fn idle_core_spread(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Chênh lệch nhiệt độ các nhân khi nghỉ",
+            lang => lang.translation("idle_core_spread").unwrap_or("Idle core temperature spread"),
+        }
+    }
+
+    pub fn idle_spread_advisory(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "{CORE} nóng hơn nhân mát nhất {SPREAD}°C khi nghỉ - kiểm tra tiếp xúc tản nhiệt và keo tản nhiệt",
+            lang => lang.translation("idle_spread_advisory").unwrap_or("{CORE} is {SPREAD}°C hotter than the coolest core at idle - check cooler mounting and thermal paste"),
+        }
+    }
+
+    // ========== Retry Failed ==========
+    pub fn retry_failed_prompt(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Chạy lại các thành phần lỗi ({LIST})? [y/N]: ",
+            lang => lang.translation("retry_failed_prompt").unwrap_or("Re-run failed components ({LIST})? [y/N]: "),
+        }
+    }
+
+    pub fn retrying_failed(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Đang chạy lại các thành phần lỗi: {LIST}",
+            lang => lang.translation("retrying_failed").unwrap_or("Re-running failed components: {LIST}"),
+        }
+    }
+
+    // ========== Throttle Point ==========
+    pub fn throttle_temp(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Ngưỡng giảm xung",
+            lang => lang.translation("throttle_temp").unwrap_or("Throttle point"),
+        }
+    }
+
+    pub fn throttle_observed(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "quan sát khi test",
+            lang => lang.translation("throttle_observed").unwrap_or("observed"),
+        }
+    }
+
+    // ========== Capabilities ==========
+    pub fn measurable_label(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Có thể đo",
+            lang => lang.translation("measurable_label").unwrap_or("Measurable"),
+        }
+    }
+
+    // ========== System uptime/load ==========
+    pub fn uptime(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "ĐÃ CHẠY",
+            lang => lang.translation("uptime").unwrap_or("UPTIME"),
+        }
+    }
+
+    pub fn load_average(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "TẢI TB",
+            lang => lang.translation("load_average").unwrap_or("LOAD AVG"),
+        }
+    }
+
+    // ========== Battery ==========
+    pub fn battery(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "PIN",
+            lang => lang.translation("battery").unwrap_or("BATTERY"),
+        }
+    }
+
+    pub fn battery_charging(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "đang sạc",
+            lang => lang.translation("battery_charging").unwrap_or("charging"),
+        }
+    }
+
+    pub fn battery_discharging(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "đang xả",
+            lang => lang.translation("battery_discharging").unwrap_or("on battery"),
+        }
+    }
+
+    pub fn battery_health(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "độ chai còn",
+            lang => lang.translation("battery_health").unwrap_or("health"),
+        }
+    }
+
+    pub fn battery_cycles(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "chu kỳ sạc",
+            lang => lang.translation("battery_cycles").unwrap_or("cycles"),
+        }
+    }
+
+    // ========== Network ==========
+    pub fn network(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "MẠNG",
+            lang => lang.translation("network").unwrap_or("NETWORK"),
+        }
+    }
+
+    pub fn no_network(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Không tìm thấy card mạng",
+            lang => lang.translation("no_network").unwrap_or("No network adapter found"),
+        }
+    }
+
+    // ========== Tool hints ==========
+    pub fn hint_install_smartctl(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Cài smartmontools (smartctl) để xem dữ liệu SMART chi tiết",
+            lang => lang.translation("hint_install_smartctl").unwrap_or("Install smartmontools (smartctl) for detailed SMART data"),
+        }
+    }
+
+    pub fn hint_sudo_smart(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Chạy với sudo để đọc dữ liệu SMART",
+            lang => lang.translation("hint_sudo_smart").unwrap_or("Run with sudo to read SMART data"),
+        }
+    }
+
+    pub fn hint_install_dmidecode(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Cài dmidecode để xem thông tin từng khe RAM",
+            lang => lang.translation("hint_install_dmidecode").unwrap_or("Install dmidecode for per-slot RAM details"),
+        }
+    }
+
+    pub fn hint_sudo_dmidecode(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Chạy với sudo để xem thông tin từng khe RAM (dmidecode)",
+            lang => lang.translation("hint_sudo_dmidecode").unwrap_or("Run with sudo for per-slot RAM details (dmidecode)"),
+        }
+    }
+
+    pub fn hint_sudo_powermetrics(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Chạy với sudo để xem nhiệt độ và công suất GPU (powermetrics)",
+            lang => lang.translation("hint_sudo_powermetrics").unwrap_or("Run with sudo for GPU temperature and power metrics (powermetrics)"),
+        }
+    }
+
+    pub fn hint_install_nvidia_smi(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Cài công cụ driver NVIDIA (nvidia-smi) để đọc nhiệt độ GPU",
+            lang => lang.translation("hint_install_nvidia_smi").unwrap_or("Install the NVIDIA driver tools (nvidia-smi) for GPU temperature"),
+        }
+    }
+
+    pub fn sudo_smart_prompt(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Nhập mật khẩu để đọc dữ liệu SMART chi tiết?",
+            lang => lang.translation("sudo_smart_prompt").unwrap_or("Enter your password to read detailed SMART data?"),
+        }
+    }
+
+    pub fn sudo_smart_skipped(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Không có quyền sudo - dữ liệu SMART chỉ lấy từ nguồn không cần quyền",
+            lang => lang.translation("sudo_smart_skipped").unwrap_or("No sudo access - SMART data will only come from unprivileged sources"),
+        }
+    }
+
+    // ========== Transcript ==========
+    pub fn report_exported(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Đã xuất báo cáo",
+            lang => lang.translation("report_exported").unwrap_or("Report exported to"),
+        }
+    }
+
+    pub fn output_write_failed(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Không thể ghi kết quả vào",
+            lang => lang.translation("output_write_failed").unwrap_or("Could not write results to"),
+        }
+    }
+
+    // ========== RAM Progress ==========
+    pub fn ram_allocating(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Đang kiểm tra RAM... Đang cấp phát {SIZE} GB...",
+            lang => lang.translation("ram_allocating").unwrap_or("Checking RAM... Allocating {SIZE} GB..."),
+        }
+    }
+
+    pub fn ram_writing(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Đang kiểm tra RAM... Đang ghi dữ liệu...",
+            lang => lang.translation("ram_writing").unwrap_or("Checking RAM... Writing data..."),
+        }
+    }
+
+    pub fn ram_verifying(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Đang kiểm tra RAM... Đang xác minh dữ liệu...",
+            lang => lang.translation("ram_verifying").unwrap_or("Checking RAM... Verifying data..."),
+        }
+    }
+
+    pub fn ram_measuring_latency(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "Đang kiểm tra RAM... Đang đo độ trễ...",
+            lang => lang.translation("ram_measuring_latency").unwrap_or("Checking RAM... Measuring latency..."),
+        }
+    }
+
+    pub fn ram_latency(&self) -> &str {
+        match self.lang {
+            Language::Vietnamese => "độ trễ",
+            lang => lang.translation("ram_latency").unwrap_or("latency"),
         }
     }
 
@@ -1361,7 +2174,425 @@ impl Text {
     pub fn deep_info(&self) -> &str {
         match self.lang {
             Language::Vietnamese => "THÔNG TIN CHI TIẾT PHẦN CỨNG",
-            Language::English => "DEEP HARDWARE INFO",
+            lang => lang.translation("deep_info").unwrap_or("DEEP HARDWARE INFO"),
         }
     }
 }
+
+/// Accessor for a single translation key
+pub type TextKey = fn(&Text) -> &str;
+
+/// Keyed table of every translation, used to check completeness for all languages
+pub const TEXT_KEYS: &[(&str, TextKey)] = &[
+    ("header", Text::header),
+    ("system", Text::system),
+    ("cpu", Text::cpu),
+    ("gpu", Text::gpu),
+    ("ram", Text::ram),
+    ("cores_label", Text::cores_label),
+    ("disk", Text::disk),
+    ("ram_free", Text::ram_free),
+    ("done_in", Text::done_in),
+    ("no_gpu", Text::no_gpu),
+    ("health_check", Text::health_check),
+    ("testing_cpu", Text::testing_cpu),
+    ("testing_ram", Text::testing_ram),
+    ("critical_issues", Text::critical_issues),
+    ("issues_detected", Text::issues_detected),
+    ("summary", Text::summary),
+    ("hardware_good", Text::hardware_good),
+    ("hardware_some_issues", Text::hardware_some_issues),
+    ("hardware_not_recommended", Text::hardware_not_recommended),
+    ("cpu_health_check", Text::cpu_health_check),
+    ("operations", Text::operations),
+    ("ops_per_sec", Text::ops_per_sec),
+    ("avg_op_time", Text::avg_op_time),
+    ("variance", Text::variance),
+    ("ram_health_check", Text::ram_health_check),
+    ("tested_gb", Text::tested_gb),
+    ("write_speed", Text::write_speed),
+    ("read_speed", Text::read_speed),
+    ("errors_detected", Text::errors_detected),
+    ("testing_disk", Text::testing_disk),
+    ("disk_health_check", Text::disk_health_check),
+    ("seek_time", Text::seek_time),
+    ("bad_sectors", Text::bad_sectors),
+    ("device", Text::device),
+    ("usage", Text::usage),
+    ("available", Text::available),
+    ("performance_test", Text::performance_test),
+    ("smart_health", Text::smart_health),
+    ("smart_status", Text::smart_status),
+    ("temperature", Text::temperature),
+    ("frequency", Text::frequency),
+    ("power_on_hours", Text::power_on_hours),
+    ("power_cycles", Text::power_cycles),
+    ("model", Text::model),
+    ("gpu_health_check", Text::gpu_health_check),
+    ("testing_gpu", Text::testing_gpu),
+    ("disk_label", Text::disk_label),
+    ("size", Text::size),
+    ("fs", Text::fs),
+    ("type_label", Text::type_label),
+    ("ssd", Text::ssd),
+    ("hdd", Text::hdd),
+    ("unified_memory", Text::unified_memory),
+    ("soc_see_cpu", Text::soc_see_cpu),
+    ("not_available", Text::not_available),
+    ("sensors", Text::sensors),
+    ("health", Text::health),
+    ("ssd_life", Text::ssd_life),
+    ("serial", Text::serial),
+    ("firmware", Text::firmware),
+    ("realloc_sectors", Text::realloc_sectors),
+    ("pending_sectors", Text::pending_sectors),
+    ("realloc_events", Text::realloc_events),
+    ("total_written", Text::total_written),
+    ("total_read", Text::total_read),
+    ("gpu_freq", Text::gpu_freq),
+    ("gpu_power", Text::gpu_power),
+    ("gpu_usage", Text::gpu_usage),
+    ("gpu_cores", Text::gpu_cores),
+    ("metal", Text::metal),
+    ("thermal_state", Text::thermal_state),
+    ("smc_temp", Text::smc_temp),
+    ("gpu_type_integrated", Text::gpu_type_integrated),
+    ("gpu_type_discrete", Text::gpu_type_discrete),
+    ("gpu_type_unknown", Text::gpu_type_unknown),
+    ("torture_test", Text::torture_test),
+    ("torture_warning", Text::torture_warning),
+    ("torture_warning_psu", Text::torture_warning_psu),
+    ("torture_warning_thermal", Text::torture_warning_thermal),
+    ("torture_warning_fans", Text::torture_warning_fans),
+    ("duration", Text::duration),
+    ("seconds", Text::seconds),
+    ("torture_cancel_info", Text::torture_cancel_info),
+    ("torture_confirm", Text::torture_confirm),
+    ("torture_cancelled", Text::torture_cancelled),
+    ("torture_starting", Text::torture_starting),
+    ("torture_summary", Text::torture_summary),
+    ("torture_duration", Text::torture_duration),
+    ("torture_passed", Text::torture_passed),
+    ("torture_failed", Text::torture_failed),
+    ("torture_dashboard_warning", Text::torture_dashboard_warning),
+    ("torture_cpu", Text::torture_cpu),
+    ("torture_gpu", Text::torture_gpu),
+    ("torture_ram", Text::torture_ram),
+    ("torture_disk", Text::torture_disk),
+    ("torture_load", Text::torture_load),
+    ("torture_errors", Text::torture_errors),
+    ("torture_write", Text::torture_write),
+    ("torture_read", Text::torture_read),
+    ("torture_mb_s", Text::torture_mb_s),
+    ("torture_na", Text::torture_na),
+    ("select_test_level", Text::select_test_level),
+    ("level_quick", Text::level_quick),
+    ("level_normal", Text::level_normal),
+    ("level_deep", Text::level_deep),
+    ("your_choice", Text::your_choice),
+    ("invalid_choice", Text::invalid_choice),
+    ("torture_final", Text::torture_final),
+    ("ai_greet", Text::ai_greet),
+    ("ai_detecting", Text::ai_detecting),
+    ("ai_specs_good", Text::ai_specs_good),
+    ("ai_specs_ok", Text::ai_specs_ok),
+    ("ai_pass", Text::ai_pass),
+    ("ai_warning", Text::ai_warning),
+    ("ai_fail", Text::ai_fail),
+    ("temp_status_excellent", Text::temp_status_excellent),
+    ("temp_status_stable", Text::temp_status_stable),
+    ("temp_status_warm", Text::temp_status_warm),
+    ("temp_status_hot", Text::temp_status_hot),
+    ("billion_suffix", Text::billion_suffix),
+    ("million_suffix", Text::million_suffix),
+    ("language_select_prompt", Text::language_select_prompt),
+    ("language_option_vi", Text::language_option_vi),
+    ("language_option_en", Text::language_option_en),
+    ("language_option_fr", Text::language_option_fr),
+    ("language_option_es", Text::language_option_es),
+    ("language_choice_prompt", Text::language_choice_prompt),
+    ("language_invalid_choice", Text::language_invalid_choice),
+    ("progress_allocating", Text::progress_allocating),
+    ("progress_writing", Text::progress_writing),
+    ("progress_reading", Text::progress_reading),
+    ("progress_verifying", Text::progress_verifying),
+    ("progress_complete", Text::progress_complete),
+    ("status_ok", Text::status_ok),
+    ("status_ok_no_temp", Text::status_ok_no_temp),
+    ("gpu_compute_unavailable", Text::gpu_compute_unavailable),
+    ("gpu_fallback_thermal", Text::gpu_fallback_thermal),
+    ("gpu_soc_needs_sudo", Text::gpu_soc_needs_sudo),
+    ("gpu_no_sensor", Text::gpu_no_sensor),
+    ("cpu_crashed", Text::cpu_crashed),
+    ("cpu_overheating", Text::cpu_overheating),
+    ("cpu_running_hot", Text::cpu_running_hot),
+    ("cpu_throttled", Text::cpu_throttled),
+    ("cpu_extreme_instability", Text::cpu_extreme_instability),
+    ("ram_errors_detected", Text::ram_errors_detected),
+    ("ram_allocation_failed", Text::ram_allocation_failed),
+    ("ram_low_write_speed", Text::ram_low_write_speed),
+    ("ram_low_read_speed", Text::ram_low_read_speed),
+    ("disk_write_permission_error", Text::disk_write_permission_error),
+    ("disk_read_failure", Text::disk_read_failure),
+    ("disk_bad_sectors", Text::disk_bad_sectors),
+    ("disk_slow_read", Text::disk_slow_read),
+    ("disk_slow_write", Text::disk_slow_write),
+    ("disk_slow_seek", Text::disk_slow_seek),
+    ("disk_ssd_slow", Text::disk_ssd_slow),
+    ("disk_hdd_slow", Text::disk_hdd_slow),
+    ("ai_cpu_temp_hot", Text::ai_cpu_temp_hot),
+    ("ai_cpu_temp_warming", Text::ai_cpu_temp_warming),
+    ("ai_ram_speed_excellent", Text::ai_ram_speed_excellent),
+    ("ai_ram_speed_good", Text::ai_ram_speed_good),
+    ("ai_ssd_speed_excellent", Text::ai_ssd_speed_excellent),
+    ("ai_ssd_speed_good", Text::ai_ssd_speed_good),
+    ("ai_ssd_speed_below_avg", Text::ai_ssd_speed_below_avg),
+    ("ai_hdd_speed_excellent", Text::ai_hdd_speed_excellent),
+    ("ai_hdd_speed_good", Text::ai_hdd_speed_good),
+    ("smart_verified", Text::smart_verified),
+    ("smart_failing", Text::smart_failing),
+    ("smart_unknown", Text::smart_unknown),
+    ("thermal_nominal", Text::thermal_nominal),
+    ("thermal_moderate", Text::thermal_moderate),
+    ("thermal_heavy", Text::thermal_heavy),
+    ("thermal_trapping", Text::thermal_trapping),
+    ("thermal_sleeping", Text::thermal_sleeping),
+    ("issue_cpu", Text::issue_cpu),
+    ("issue_ram", Text::issue_ram),
+    ("issue_disk", Text::issue_disk),
+    ("issue_disk_indexed", Text::issue_disk_indexed),
+    ("issue_gpu", Text::issue_gpu),
+    ("deep_info", Text::deep_info),
+    ("perf_compare_title", Text::perf_compare_title),
+    ("perf_as_expected", Text::perf_as_expected),
+    ("perf_underperforming", Text::perf_underperforming),
+    ("perf_no_baseline", Text::perf_no_baseline),
+    ("perf_hint_cpu", Text::perf_hint_cpu),
+    ("perf_hint_ram", Text::perf_hint_ram),
+    ("perf_hint_disk", Text::perf_hint_disk),
+    ("perf_debug_build", Text::perf_debug_build),
+    ("disk_no_writable_location", Text::disk_no_writable_location),
+    ("turbo_boost", Text::turbo_boost),
+    ("boost_enabled", Text::boost_enabled),
+    ("boost_disabled", Text::boost_disabled),
+    ("boost_disabled_note", Text::boost_disabled_note),
+    ("hardware_not_fully_verified", Text::hardware_not_fully_verified),
+    ("unmet_criteria", Text::unmet_criteria),
+    ("criteria_no_thermal", Text::criteria_no_thermal),
+    ("criteria_smart_unverified", Text::criteria_smart_unverified),
+    ("criteria_ssd_life_low", Text::criteria_ssd_life_low),
+    ("criteria_ssd_life_unknown", Text::criteria_ssd_life_unknown),
+    ("time_per_component", Text::time_per_component),
+    ("trim", Text::trim),
+    ("trim_on", Text::trim_on),
+    ("trim_off", Text::trim_off),
+    ("trim_unsupported", Text::trim_unsupported),
+    ("trim_disabled_advisory", Text::trim_disabled_advisory),
+    ("gpu_soak_health_check", Text::gpu_soak_health_check),
+    ("testing_gpu_soak", Text::testing_gpu_soak),
+    ("steady_state_temp", Text::steady_state_temp),
+    ("time_to_stabilize", Text::time_to_stabilize),
+    ("gpu_not_stabilized", Text::gpu_not_stabilized),
+    ("gpu_load", Text::gpu_load),
+    ("gpu_monitoring_only", Text::gpu_monitoring_only),
+    ("memory_pressure", Text::memory_pressure),
+    ("pressure_normal", Text::pressure_normal),
+    ("pressure_warning", Text::pressure_warning),
+    ("pressure_critical", Text::pressure_critical),
+    ("commit_charge", Text::commit_charge),
+    ("memory_compressed", Text::memory_compressed),
+    ("memory_pressure_warning", Text::memory_pressure_warning),
+    ("idle_core_spread", Text::idle_core_spread),
+    ("idle_spread_advisory", Text::idle_spread_advisory),
+    ("retry_failed_prompt", Text::retry_failed_prompt),
+    ("retrying_failed", Text::retrying_failed),
+    ("throttle_temp", Text::throttle_temp),
+    ("throttle_observed", Text::throttle_observed),
+    ("measurable_label", Text::measurable_label),
+    ("output_write_failed", Text::output_write_failed),
+    ("ram_allocating", Text::ram_allocating),
+    ("ram_writing", Text::ram_writing),
+    ("ram_verifying", Text::ram_verifying),
+    ("disk_insufficient_space", Text::disk_insufficient_space),
+    ("interrupted", Text::interrupted),
+    ("torture_emergency_stop", Text::torture_emergency_stop),
+    ("cpu_workload", Text::cpu_workload),
+    ("gpu_compute_load", Text::gpu_compute_load),
+    ("gpu_frames", Text::gpu_frames),
+    ("gpu_thermal_only", Text::gpu_thermal_only),
+    ("gpu_vram_used", Text::gpu_vram_used),
+    ("watch_iteration", Text::watch_iteration),
+    ("watch_next_run", Text::watch_next_run),
+    ("random_read_iops", Text::random_read_iops),
+    ("uptime", Text::uptime),
+    ("load_average", Text::load_average),
+    ("battery", Text::battery),
+    ("battery_charging", Text::battery_charging),
+    ("battery_discharging", Text::battery_discharging),
+    ("battery_health", Text::battery_health),
+    ("battery_cycles", Text::battery_cycles),
+    ("network", Text::network),
+    ("no_network", Text::no_network),
+    ("report_exported", Text::report_exported),
+    ("ram_partial_coverage", Text::ram_partial_coverage),
+    ("ram_access", Text::ram_access),
+    ("expected_ops_range", Text::expected_ops_range),
+    ("perf_within_range", Text::perf_within_range),
+    ("perf_above_range", Text::perf_above_range),
+    ("perf_below_range", Text::perf_below_range),
+    ("hint_install_smartctl", Text::hint_install_smartctl),
+    ("hint_sudo_smart", Text::hint_sudo_smart),
+    ("hint_install_dmidecode", Text::hint_install_dmidecode),
+    ("hint_sudo_dmidecode", Text::hint_sudo_dmidecode),
+    ("hint_sudo_powermetrics", Text::hint_sudo_powermetrics),
+    ("hint_install_nvidia_smi", Text::hint_install_nvidia_smi),
+    ("sudo_smart_prompt", Text::sudo_smart_prompt),
+    ("sudo_smart_skipped", Text::sudo_smart_skipped),
+    ("read_retries", Text::read_retries),
+    ("write_speed_peak", Text::write_speed_peak),
+    ("write_speed_sustained", Text::write_speed_sustained),
+    ("disk_cache_cliff", Text::disk_cache_cliff),
+    ("temp_idle_to_peak", Text::temp_idle_to_peak),
+    ("fans", Text::fans),
+    ("ram_measuring_latency", Text::ram_measuring_latency),
+    ("ram_latency", Text::ram_latency),
+    ("gpu_throughput", Text::gpu_throughput),
+    ("gpu_frame", Text::gpu_frame),
+    ("menu_title", Text::menu_title),
+    ("menu_info", Text::menu_info),
+    ("menu_cpu", Text::menu_cpu),
+    ("menu_ram", Text::menu_ram),
+    ("menu_disk", Text::menu_disk),
+    ("menu_gpu", Text::menu_gpu),
+    ("menu_full_auto", Text::menu_full_auto),
+    ("menu_torture", Text::menu_torture),
+    ("menu_quit", Text::menu_quit),
+    ("menu_invalid_choice", Text::menu_invalid_choice),
+    ("cpu_working_set", Text::cpu_working_set),
+    ("fan_not_spinning", Text::fan_not_spinning),
+    ("ram_partial_coverage_in_use", Text::ram_partial_coverage_in_use),
+];
+
+
+/// Keys that are intentionally identical across languages (technical terms, units, names)
+pub const SHARED_KEYS: &[&str] = &[
+    "cpu", "gpu", "ram", "fs", "ssd", "hdd", "not_available", "firmware",
+    "realloc_sectors", "pending_sectors", "realloc_events", "metal",
+    "torture_cpu", "torture_gpu", "torture_ram", "torture_mb_s", "torture_na",
+    "language_option_en", "language_option_fr", "language_option_es", "status_ok", "turbo_boost", "trim", "issue_cpu", "issue_ram", "issue_gpu",
+    "battery_cycles",
+];
+
+/// Check every translation key for all languages
+/// Reports empty strings, mismatched placeholders, keys left identical to English,
+/// and overlay table entries that don't match any key
+pub fn missing_translations() -> Vec<String> {
+    let mut problems = Vec::new();
+    let english = Text::new(Language::English);
+
+    for lang in Language::ALL {
+        for (key, _) in lang.translation_table().unwrap_or_default() {
+            if !TEXT_KEYS.iter().any(|(k, _)| k == key) {
+                problems.push(format!("{:?}: unknown key '{}'", lang, key));
+            }
+        }
+
+        let text = Text::new(lang);
+        for (key, get) in TEXT_KEYS {
+            let value = get(&text);
+            let reference = get(&english);
+
+            if value.trim().is_empty() {
+                problems.push(format!("{:?}: '{}' is empty", lang, key));
+                continue;
+            }
+            if lang == Language::English {
+                continue;
+            }
+            if placeholders(value) != placeholders(reference) {
+                problems.push(format!("{:?}: '{}' placeholders differ from English", lang, key));
+            }
+            // Overlay languages fall back to English for keys they don't translate
+            let translated = lang.translation_table().is_none() || lang.translation(key).is_some();
+            if translated && value == reference && !SHARED_KEYS.contains(key) {
+                problems.push(format!("{:?}: '{}' is identical to English (untranslated?)", lang, key));
+            }
+        }
+    }
+
+    problems
+}
+
+/// Extract format placeholders like {}, {:.1}, {DISK} in order
+fn placeholders(s: &str) -> Vec<&str> {
+    let mut found = Vec::new();
+    let mut rest = s;
+    while let Some(start) = rest.find('{') {
+        match rest[start..].find('}') {
+            Some(end) => {
+                found.push(&rest[start..start + end + 1]);
+                rest = &rest[start + end + 1..];
+            }
+            None => break,
+        }
+    }
+    found.sort_unstable();
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_translations_present() {
+        let problems = missing_translations();
+        assert!(problems.is_empty(), "translation problems:\n{}", problems.join("\n"));
+    }
+
+    #[test]
+    fn test_text_keys_cover_all_methods() {
+        // Every `fn xxx(&self) -> &str` must be listed in TEXT_KEYS
+        let source = include_str!("lang.rs");
+        let methods: Vec<&str> = source
+            .lines()
+            .filter_map(|l| l.trim().strip_prefix("pub fn "))
+            .filter(|l| l.contains("(&self) -> &str"))
+            .filter_map(|l| l.split('(').next())
+            .collect();
+        for method in &methods {
+            assert!(
+                TEXT_KEYS.iter().any(|(key, _)| key == method),
+                "'{}' is missing from TEXT_KEYS",
+                method
+            );
+        }
+        assert_eq!(methods.len(), TEXT_KEYS.len());
+    }
+
+    #[test]
+    fn test_shared_keys_exist() {
+        for key in SHARED_KEYS {
+            assert!(TEXT_KEYS.iter().any(|(k, _)| k == key), "unknown shared key '{}'", key);
+        }
+    }
+
+    #[test]
+    fn test_overlay_falls_back_to_english() {
+        let french = Text::new(Language::French);
+        let english = Text::new(Language::English);
+        assert_eq!(french.hardware_good(), "Le matériel est en bon état");
+        assert_eq!(french.health_check(), "DIAGNOSTIC MATÉRIEL");
+        // Untranslated key
+        assert_eq!(french.torture_warning(), english.torture_warning());
+        assert_eq!(Language::English.translation("header"), None);
+        assert_eq!(Language::Spanish.translation("header"), Some("Herramienta de información de hardware"));
+    }
+
+    #[test]
+    fn test_placeholders() {
+        assert_eq!(placeholders("{DISK}: {SPEED} MB/s"), vec!["{DISK}", "{SPEED}"]);
+        assert_eq!(placeholders("{:.1}°C"), vec!["{:.1}"]);
+        assert!(placeholders("no placeholders").is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+// Spanish translations
+// Keys match TEXT_KEYS, anything missing falls back to English
+
+pub const TEXTS: &[(&str, &str)] = &[
+    ("header", "Herramienta de información de hardware"),
+    ("system", "SISTEMA"),
+    ("disk", "DISCO"),
+    ("ram_free", "libre"),
+    ("health_check", "DIAGNÓSTICO DE HARDWARE"),
+    ("testing_cpu", "Probando CPU"),
+    ("testing_ram", "Probando RAM"),
+    ("testing_disk", "Probando disco"),
+    ("testing_gpu", "Probando GPU"),
+    ("summary", "RESUMEN:"),
+    ("hardware_good", "El hardware está en buen estado"),
+    ("hardware_some_issues", "El hardware tiene algunos problemas"),
+    ("hardware_not_recommended", "No se recomienda su uso"),
+    ("hardware_not_fully_verified", "No se pudo verificar completamente el hardware"),
+    ("unmet_criteria", "Criterios no cumplidos:"),
+    ("critical_issues", "PROBLEMAS CRÍTICOS:"),
+    ("issues_detected", "PROBLEMAS DETECTADOS:"),
+    ("done_in", "Completado en"),
+    ("temperature", "temperatura"),
+    ("write_speed", "velocidad de escritura"),
+    ("read_speed", "velocidad de lectura"),
+    ("operations", "operaciones"),
+    ("frequency", "frecuencia"),
+    ("no_gpu", "No se detectó GPU dedicada"),
+    ("select_test_level", "Seleccione el nivel de prueba"),
+    ("level_quick", "Rápida"),
+    ("level_deep", "Profunda"),
+    ("your_choice", "Su elección"),
+    ("invalid_choice", "Opción no válida. Seleccione 1, 2 o 3."),
+    ("time_per_component", "Tiempo por componente:"),
+    ("tested_gb", "probado"),
+    ("errors_detected", "errores detectados"),
+    ("disk_insufficient_space", "Espacio libre insuficiente para la prueba de disco: {SIZE} MB solicitados, {FREE} GB disponibles"),
+    ("interrupted", "Interrumpido con Ctrl+C - archivos de prueba eliminados"),
+    ("torture_emergency_stop", "PARADA DE EMERGENCIA: temperatura por encima de {TEMP}°C demasiado tiempo - todas las pruebas detenidas"),
+    ("cpu_workload", "carga de trabajo"),
+    ("gpu_compute_load", "carga de cómputo"),
+    ("gpu_frames", "fotogramas"),
+    ("gpu_thermal_only", "solo monitoreo térmico"),
+    ("gpu_vram_used", "VRAM usada"),
+    ("watch_iteration", "Vigilancia n.º {N} - {TIME}"),
+    ("watch_next_run", "Próxima comprobación en {MIN} min (Ctrl+C para detener)"),
+    ("random_read_iops", "lectura 4K aleatoria"),
+    ("uptime", "ACTIVO"),
+    ("load_average", "CARGA MEDIA"),
+    ("battery", "BATERÍA"),
+    ("battery_charging", "cargando"),
+    ("battery_discharging", "con batería"),
+    ("battery_health", "salud"),
+    ("battery_cycles", "ciclos"),
+    ("network", "RED"),
+    ("no_network", "No se encontró ningún adaptador de red"),
+    ("report_exported", "Informe exportado a"),
+    ("ram_partial_coverage", "Solo se probaron {TESTED} de {TOTAL} GB ({PCT}%) - el resto de la RAM no se verificó, use --ram-gb para probar más"),
+    ("ram_partial_coverage_in_use", "Solo se probaron {TESTED} de {TOTAL} GB ({PCT}%) - el resto de la RAM está en uso por otros programas, ciérrelos para probar más"),
+    ("ram_access", "patrón de acceso"),
+    ("expected_ops_range", "esperado"),
+    ("perf_within_range", "dentro del rango"),
+    ("perf_above_range", "por encima del rango"),
+    ("perf_below_range", "por debajo del rango"),
+    ("hint_install_smartctl", "Instale smartmontools (smartctl) para ver datos SMART detallados"),
+    ("hint_sudo_smart", "Ejecute con sudo para leer los datos SMART"),
+    ("hint_install_dmidecode", "Instale dmidecode para ver los detalles de cada módulo de RAM"),
+    ("hint_sudo_dmidecode", "Ejecute con sudo para ver los detalles de cada módulo de RAM (dmidecode)"),
+    ("hint_sudo_powermetrics", "Ejecute con sudo para ver la temperatura y el consumo de la GPU (powermetrics)"),
+    ("hint_install_nvidia_smi", "Instale las herramientas del controlador NVIDIA (nvidia-smi) para la temperatura de la GPU"),
+    ("sudo_smart_prompt", "¿Introducir su contraseña para leer los datos SMART detallados?"),
+    ("sudo_smart_skipped", "Sin acceso sudo - los datos SMART solo vendrán de fuentes sin privilegios"),
+    ("read_retries", "relecturas"),
+    ("write_speed_peak", "escritura máx. (caché)"),
+    ("write_speed_sustained", "escritura sostenida"),
+    ("disk_cache_cliff", "La velocidad de escritura cae un {PCT}% cuando se llena la caché de escritura - las copias grandes irán muy por debajo de la velocidad anunciada"),
+    ("temp_idle_to_peak", "reposo → pico"),
+    ("fans", "ventiladores"),
+    ("ram_measuring_latency", "Probando RAM... Midiendo la latencia..."),
+    ("ram_latency", "latencia"),
+    ("gpu_throughput", "rendimiento de cálculo"),
+    ("gpu_frame", "fotograma"),
+    ("menu_title", "¿Qué quieres comprobar?"),
+    ("menu_info", "Solo información del hardware"),
+    ("menu_cpu", "Prueba de CPU"),
+    ("menu_ram", "Prueba de RAM"),
+    ("menu_disk", "Prueba de disco"),
+    ("menu_gpu", "Prueba de GPU"),
+    ("menu_full_auto", "Comprobación completa automática (todas las pruebas y luego la prueba de tortura)"),
+    ("menu_torture", "Prueba de tortura (todos los componentes a la vez)"),
+    ("menu_quit", "Salir"),
+    ("menu_invalid_choice", "Opción no válida. Elige entre 0 y 7."),
+    ("cpu_working_set", "conjunto de trabajo"),
+    ("fan_not_spinning", "Ventilador detenido bajo carga ({FAN} a 0 RPM, {TEMP}°C) - posible fallo del ventilador"),
+    ("ram_allocating", "Probando RAM... Asignando {SIZE} GB..."),
+    ("ram_writing", "Probando RAM... Escribiendo datos..."),
+    ("ram_verifying", "Probando RAM... Verificando datos..."),
+    ("retry_failed_prompt", "¿Repetir los componentes con fallos ({LIST})? [y/N]: "),
+    ("retrying_failed", "Repitiendo los componentes con fallos: {LIST}"),
+    ("language_select_prompt", "Seleccione el idioma"),
+    ("language_option_vi", "Vietnamita"),
+    ("language_option_en", "Inglés"),
+    ("language_choice_prompt", "Su elección [1-4]: "),
+    ("language_invalid_choice", "⚠️  Opción no válida. Seleccione entre 1 y 4."),
+];
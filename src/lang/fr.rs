@@ -0,0 +1,105 @@
+// French translations
+// Keys match TEXT_KEYS, anything missing falls back to English
+
+pub const TEXTS: &[(&str, &str)] = &[
+    ("header", "Outil d'information matérielle"),
+    ("system", "SYSTÈME"),
+    ("disk", "DISQUE"),
+    ("ram_free", "libre"),
+    ("health_check", "DIAGNOSTIC MATÉRIEL"),
+    ("testing_cpu", "Test du CPU"),
+    ("testing_ram", "Test de la RAM"),
+    ("testing_disk", "Test du disque"),
+    ("testing_gpu", "Test du GPU"),
+    ("summary", "RÉSUMÉ :"),
+    ("hardware_good", "Le matériel est en bon état"),
+    ("hardware_some_issues", "Le matériel présente quelques problèmes"),
+    ("hardware_not_recommended", "Utilisation déconseillée"),
+    ("hardware_not_fully_verified", "Impossible de vérifier entièrement le matériel"),
+    ("unmet_criteria", "Critères non remplis :"),
+    ("critical_issues", "PROBLÈMES CRITIQUES :"),
+    ("issues_detected", "PROBLÈMES DÉTECTÉS :"),
+    ("done_in", "Terminé en"),
+    ("temperature", "température"),
+    ("write_speed", "vitesse d'écriture"),
+    ("read_speed", "vitesse de lecture"),
+    ("operations", "opérations"),
+    ("frequency", "fréquence"),
+    ("no_gpu", "Aucun GPU dédié détecté"),
+    ("select_test_level", "Choisissez le niveau de test"),
+    ("level_quick", "Rapide"),
+    ("level_deep", "Approfondi"),
+    ("your_choice", "Votre choix"),
+    ("invalid_choice", "Choix invalide. Veuillez choisir 1, 2 ou 3."),
+    ("time_per_component", "Temps par composant :"),
+    ("tested_gb", "testé"),
+    ("errors_detected", "erreurs détectées"),
+    ("disk_insufficient_space", "Espace libre insuffisant pour le test du disque : {SIZE} Mo demandés, {FREE} Go disponibles"),
+    ("interrupted", "Interrompu par Ctrl+C - fichiers de test supprimés"),
+    ("torture_emergency_stop", "ARRÊT D'URGENCE : température au-dessus de {TEMP}°C trop longtemps - tous les tests arrêtés"),
+    ("cpu_workload", "charge de travail"),
+    ("gpu_compute_load", "charge de calcul"),
+    ("gpu_frames", "trames"),
+    ("gpu_thermal_only", "surveillance thermique seule"),
+    ("gpu_vram_used", "VRAM utilisée"),
+    ("watch_iteration", "Surveillance n°{N} - {TIME}"),
+    ("watch_next_run", "Prochaine vérification dans {MIN} min (Ctrl+C pour arrêter)"),
+    ("random_read_iops", "lecture 4K aléatoire"),
+    ("uptime", "EN MARCHE"),
+    ("load_average", "CHARGE MOY"),
+    ("battery", "BATTERIE"),
+    ("battery_charging", "en charge"),
+    ("battery_discharging", "sur batterie"),
+    ("battery_health", "santé"),
+    ("battery_cycles", "cycles"),
+    ("network", "RÉSEAU"),
+    ("no_network", "Aucune carte réseau trouvée"),
+    ("report_exported", "Rapport exporté vers"),
+    ("ram_partial_coverage", "Seulement {TESTED} Go testés sur {TOTAL} ({PCT} %) - le reste de la RAM n'a pas été vérifié, utilisez --ram-gb pour en tester davantage"),
+    ("ram_partial_coverage_in_use", "Seulement {TESTED} Go testés sur {TOTAL} ({PCT} %) - le reste de la RAM est utilisé par d'autres programmes, fermez-les pour en tester davantage"),
+    ("ram_access", "mode d'accès"),
+    ("expected_ops_range", "attendu"),
+    ("perf_within_range", "dans la plage"),
+    ("perf_above_range", "au-dessus de la plage"),
+    ("perf_below_range", "en dessous de la plage"),
+    ("hint_install_smartctl", "Installez smartmontools (smartctl) pour les données SMART détaillées"),
+    ("hint_sudo_smart", "Lancez avec sudo pour lire les données SMART"),
+    ("hint_install_dmidecode", "Installez dmidecode pour le détail de chaque barrette de RAM"),
+    ("hint_sudo_dmidecode", "Lancez avec sudo pour le détail de chaque barrette de RAM (dmidecode)"),
+    ("hint_sudo_powermetrics", "Lancez avec sudo pour la température et la consommation du GPU (powermetrics)"),
+    ("hint_install_nvidia_smi", "Installez les outils du pilote NVIDIA (nvidia-smi) pour la température du GPU"),
+    ("sudo_smart_prompt", "Saisir votre mot de passe pour lire les données SMART détaillées ?"),
+    ("sudo_smart_skipped", "Pas d'accès sudo - les données SMART ne viendront que de sources non privilégiées"),
+    ("read_retries", "relectures"),
+    ("write_speed_peak", "écriture max (cache)"),
+    ("write_speed_sustained", "écriture soutenue"),
+    ("disk_cache_cliff", "La vitesse d'écriture chute de {PCT}% une fois le cache d'écriture plein - les grosses copies seront bien en dessous de la vitesse annoncée"),
+    ("temp_idle_to_peak", "repos → pic"),
+    ("fans", "ventilateurs"),
+    ("ram_measuring_latency", "Test de la RAM... Mesure de la latence..."),
+    ("ram_latency", "latence"),
+    ("gpu_throughput", "débit de calcul"),
+    ("gpu_frame", "trame"),
+    ("menu_title", "Que voulez-vous vérifier ?"),
+    ("menu_info", "Informations matérielles uniquement"),
+    ("menu_cpu", "Test CPU"),
+    ("menu_ram", "Test RAM"),
+    ("menu_disk", "Test disque"),
+    ("menu_gpu", "Test GPU"),
+    ("menu_full_auto", "Vérification complète automatique (tous les tests, puis le test de torture)"),
+    ("menu_torture", "Test de torture (tous les composants en même temps)"),
+    ("menu_quit", "Quitter"),
+    ("menu_invalid_choice", "Choix invalide. Veuillez choisir entre 0 et 7."),
+    ("cpu_working_set", "jeu de données"),
+    ("fan_not_spinning", "Ventilateur arrêté en charge ({FAN} à 0 tr/min, {TEMP}°C) - panne de ventilateur possible"),
+    ("ram_allocating", "Test de la RAM... Allocation de {SIZE} Go..."),
+    ("ram_writing", "Test de la RAM... Écriture des données..."),
+    ("ram_verifying", "Test de la RAM... Vérification des données..."),
+    ("retry_failed_prompt", "Relancer les composants en échec ({LIST}) ? [y/N] : "),
+    ("retrying_failed", "Nouveau test des composants en échec : {LIST}"),
+    ("language_select_prompt", "Choisissez la langue"),
+    ("language_option_vi", "Vietnamien"),
+    ("language_option_en", "Anglais"),
+    ("language_choice_prompt", "Votre choix [1-4] : "),
+    ("language_invalid_choice", "⚠️  Choix invalide. Veuillez choisir entre 1 et 4."),
+];
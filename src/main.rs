@@ -9,11 +9,15 @@ mod prompt;
 mod stress;
 mod sensors;
 mod ai;
+mod selftest;
+mod profile;
+mod transcript;
+mod interrupt;
 
 use std::time::Instant;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 use clap::Parser;
-use hw::{CpuInfo, RamInfo, DiskInfo, GpuInfo};
+use hw::{CpuInfo, RamInfo, DiskInfo, GpuInfo, DeepInfo};
 use hw::deep::{get_platform_probe, PlatformProbe};
 use lang::{Text, Language};
 use fmt::{print_header_with_text, print_section, print_footer_with_text};
@@ -25,13 +29,18 @@ use ai::AiTechnician;
 #[command(name = "pchecker")]
 #[command(version = "0.3.0")]
 #[command(about = "Hardware detection and health check tool", long_about = None)]
+#[command(after_help = "Exit status: 0 = healthy, 1 = issues detected, 2 = failed")]
 struct Args {
     /// Show hardware info only (no tests)
     #[arg(long)]
     info: bool,
 
-    /// Run CPU test (optional duration in seconds)
-    #[arg(long, value_name = "SECONDS")]
+    /// Pick what to run from a numbered menu instead of flags
+    #[arg(long)]
+    interactive: bool,
+
+    /// Run CPU test for this long (seconds, or with a unit: 30s, 5m, 1h)
+    #[arg(long, value_name = "DURATION", value_parser = fmt::parse_duration)]
     cpu: Option<u64>,
 
     /// Run RAM test
@@ -42,31 +51,465 @@ struct Args {
     #[arg(long)]
     disk: bool,
 
-    /// Run GPU test (optional duration in seconds)
-    #[arg(long, value_name = "SECONDS")]
+    /// Run GPU test for this long (seconds, or with a unit: 30s, 5m, 1h)
+    #[arg(long, value_name = "DURATION", value_parser = fmt::parse_duration)]
     gpu: Option<u64>,
 
-    /// Run torture test - all components simultaneously (optional duration in seconds)
-    #[arg(short = 'a', long, value_name = "SECONDS")]
+    /// Run torture test - all components simultaneously, for this long (seconds, or 30s, 5m, 1h)
+    #[arg(short = 'a', long, value_name = "DURATION", value_parser = fmt::parse_duration)]
     all: Option<u64>,
+
+    /// Start the torture test without the confirmation prompt (also skipped when stdin is not a terminal)
+    #[arg(short = 'y', long)]
+    yes: bool,
+
+    /// Torture test workloads to run together, comma-separated (default: cpu,ram,disk,gpu)
+    #[arg(long, value_enum, value_name = "LIST", value_delimiter = ',')]
+    torture_components: Option<Vec<stress::torture::TortureComponent>>,
+
+    /// Torture dashboard refreshes per second (when piped, one timestamped line per refresh)
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..=10))]
+    dashboard_hz: u32,
+
+    /// Emit live progress as JSON lines on stderr instead of progress bars
+    #[arg(long)]
+    progress_json: bool,
+
+    /// Output format: text report, or one JSON/Markdown document on stdout (no prompts)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Shorthand for --format json (with --info: full hardware inventory)
+    #[arg(long)]
+    json: bool,
+
+    /// Indent JSON output for reading (default: one compact line)
+    #[arg(long)]
+    json_pretty: bool,
+
+    /// Run internal consistency checks and exit
+    #[arg(long)]
+    selftest: bool,
+
+    /// Ask for sudo up front so SMART data can be read with smartctl (macOS/Linux, with -v, --smart-csv or --export); without it no sudo is attempted
+    #[arg(long)]
+    privileged: bool,
+
+    /// Print version, target, compiled-in features and detected platform (for bug reports) and exit
+    #[arg(long)]
+    build_info: bool,
+
+    /// Run hardware detection only and print how long each probe took
+    #[arg(long)]
+    profile_detection: bool,
+
+    /// Compare test results against expected performance for the detected hardware
+    #[arg(long)]
+    compare_to_model: bool,
+
+    /// Strict verdict: require thermal data, verified SMART and SSD life >= 80%
+    #[arg(long)]
+    strict: bool,
+
+    /// Require CPU/GPU temperature data for a "good" verdict
+    #[arg(long)]
+    require_thermal: bool,
+
+    /// Require a verified SMART status for a "good" verdict
+    #[arg(long)]
+    require_smart: bool,
+
+    /// Require at least this SSD life (%) for a "good" verdict
+    #[arg(long, value_name = "PERCENT", value_parser = clap::value_parser!(u8).range(0..=100))]
+    min_ssd_life: Option<u8>,
+
+    /// Run GPU until temperature stabilizes (optional max seconds, default 600)
+    #[arg(long, visible_alias = "components-gpu-sustained", value_name = "MAX_SECONDS", num_args = 0..=1, default_missing_value = "600")]
+    gpu_sustained: Option<u64>,
+
+    /// Re-run failed components right away instead of asking
+    #[arg(long, visible_alias = "components-retry-failed")]
+    retry_failed: bool,
+
+    /// Disable the AI technician (no typewriter effect, delays or commentary)
+    #[arg(long)]
+    no_ai: bool,
+
+    /// AI typewriter delay per character in milliseconds (0 = instant)
+    #[arg(long, value_name = "N", default_value_t = 10)]
+    typewriter_ms: u64,
+
+    /// Append a timestamped plain-text transcript of the results to this file
+    #[arg(long, value_name = "PATH")]
+    output: Option<std::path::PathBuf>,
+
+    /// CPU test worker threads (default: all logical cores, at most 4x cores)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    threads: Option<u64>,
+
+    /// CPU test workload: primes (integer), float (matrix multiply), mixed or cache (working set beyond L2)
+    #[arg(long, value_enum, value_name = "KIND", default_value_t = stress::CpuWorkload::Primes)]
+    cpu_workload: stress::CpuWorkload,
+
+    /// RAM test passes, each with a different bit pattern (checkerboard, zeros, ones, 0x55, 0xAA, walking ones, address)
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    ram_passes: u32,
+
+    /// RAM to test in GB (default: 80% of available, capped at 16 GB)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    ram_gb: Option<u64>,
+
+    /// RAM access order: sequential (streaming) or random (shuffled chunks and strides, defeats the prefetcher)
+    #[arg(long, value_enum, value_name = "MODE", default_value_t = stress::RamAccess::Sequential)]
+    ram_pattern: stress::RamAccess,
+
+    /// Also measure RAM latency (random pointer chasing through a buffer larger than the L3 cache)
+    #[arg(long)]
+    ram_latency: bool,
+
+    /// Disk test file size in MB
+    #[arg(long, value_name = "N", default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+    disk_size_mb: u64,
+
+    /// Run the disk test at this file or directory (only the disk holding it is tested)
+    #[arg(long, value_name = "PATH")]
+    disk_path: Option<std::path::PathBuf>,
+
+    /// Sustained write test: write MB (default 2048) in synced windows and report peak vs steady-state speed
+    #[arg(long, value_name = "MB", num_args = 0..=1, default_missing_value = "2048", value_parser = clap::value_parser!(u64).range(1..))]
+    disk_sustained: Option<u64>,
+
+    /// Re-read mismatched disk regions; only sectors that fail twice count as bad (one-off glitches are shown as read retries)
+    #[arg(long)]
+    disk_verify: bool,
+
+    /// Test only the disk with this index (0 = first disk listed by --info; not with --disk-path)
+    #[arg(long, value_name = "N")]
+    disk_index: Option<usize>,
+
+    /// Print only the final verdict and issue list (no hardware details, result boxes or torture dashboard)
+    #[arg(long)]
+    summary_only: bool,
+
+    /// More detail during tests: -v per-core CPU rows and the SMART section, -vv also the sensor list and GPU telemetry
+    #[arg(short = 'v', long = "verbose", action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Report language: vi, en, fr or es (skips the language prompt; default English without a terminal)
+    #[arg(long, value_name = "LANG", value_parser = parse_lang_arg)]
+    lang: Option<Language>,
+
+    /// Repeat the health check every N minutes until Ctrl+C (burn-in); each run is appended to --output
+    #[arg(long, value_name = "MINUTES", value_parser = clap::value_parser!(u64).range(1..))]
+    watch: Option<u64>,
+
+    /// Write per-second CPU/torture samples (temperature, frequency, ops, per-core usage) to this JSONL file
+    #[arg(long, value_name = "PATH")]
+    telemetry: Option<std::path::PathBuf>,
+
+    /// Write the full run (system, deep info, results, SMART, CLI args) as JSON to this file, plus a .sha256 checksum
+    #[arg(long, value_name = "PATH")]
+    export: Option<std::path::PathBuf>,
+
+    /// Append one row of SMART attributes per tested disk to this CSV file
+    #[arg(long, value_name = "PATH")]
+    smart_csv: Option<std::path::PathBuf>,
+
+    /// Gzip the --output, --smart-csv, --telemetry and --export files (implied by a .gz file name)
+    #[arg(long)]
+    compress: bool,
+
+    /// CPU/GPU temperature (°C) above which a warning is reported (default 85, or TjMax - 10)
+    #[arg(long, value_name = "C", value_parser = clap::value_parser!(u8).range(40..=110))]
+    temp_warn: Option<u8>,
+
+    /// CPU/GPU temperature (°C) above which the test fails (default 95, or TjMax)
+    #[arg(long, value_name = "C", value_parser = clap::value_parser!(u8).range(40..=110))]
+    temp_fail: Option<u8>,
+}
+
+fn parse_lang_arg(value: &str) -> Result<Language, String> {
+    prompt::parse_language(value).ok_or_else(|| format!("unknown language '{}' (expected vi, en, fr or es)", value))
+}
+
+/// Output format of the health check results
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq)]
+enum OutputFormat {
+    Text,
+    Json,
+    /// Headings and tables for pasting into GitHub issues or forums
+    Markdown,
+}
+
+/// Options shared by the health check modes
+#[derive(Debug, Clone, Default)]
+struct RunOptions {
+    compare_to_model: bool,
+    /// -v count: 1 = per-core CPU rows + SMART, 2 = also sensor list + GPU telemetry
+    verbosity: u8,
+    criteria: stress::criteria::VerdictCriteria,
+    /// Run the GPU thermal-soak test (max seconds) instead of the fixed-duration test
+    gpu_soak_max_secs: Option<u64>,
+    /// Re-run failed components without prompting
+    retry_failed: bool,
+    /// Show the AI technician (off with --no-ai or JSON output)
+    ai_enabled: bool,
+    /// AI typewriter delay per character (ms)
+    typewriter_ms: u64,
+    /// Transcript file for the result boxes (--output)
+    output: Option<std::path::PathBuf>,
+    /// Requested CPU test thread count (--threads), at most 4x the logical cores (checked in main)
+    cpu_threads: Option<usize>,
+    /// CPU test workload (--cpu-workload)
+    cpu_workload: stress::CpuWorkload,
+    /// RAM write/verify passes (--ram-passes)
+    ram_passes: u32,
+    /// RAM test size (--ram-gb), None = automatic
+    ram_gb: Option<f64>,
+    /// RAM access order (--ram-pattern)
+    ram_access: stress::RamAccess,
+    /// Measure RAM latency (--ram-latency)
+    ram_latency: bool,
+    /// Disk test file size (--disk-size-mb)
+    disk_size_mb: u64,
+    /// Disk test location (--disk-path); default is each disk's mount point
+    disk_path: Option<std::path::PathBuf>,
+    /// Re-read mismatched regions before counting bad sectors (--disk-verify)
+    disk_verify: bool,
+    /// Sustained write file size in MB (--disk-sustained)
+    disk_sustained: Option<u64>,
+    /// Only test the disk with this index (--disk-index)
+    disk_index: Option<usize>,
+    /// Retry pass: only re-test these disks
+    retry_disks: Vec<usize>,
+    /// SMART trend file (--smart-csv)
+    smart_csv: Option<std::path::PathBuf>,
+    /// Report bundle file (--export)
+    export: Option<std::path::PathBuf>,
+    /// Temperature limits from --temp-warn/--temp-fail (None = automatic)
+    temp_limits: Option<stress::TempLimits>,
+}
+
+impl RunOptions {
+    fn from_args(args: &Args) -> Self {
+        let mut criteria = if args.strict {
+            stress::criteria::VerdictCriteria::strict()
+        } else {
+            stress::criteria::VerdictCriteria::default()
+        };
+        criteria.require_thermal |= args.require_thermal;
+        criteria.require_smart_verified |= args.require_smart;
+        if args.min_ssd_life.is_some() {
+            criteria.min_ssd_life_pct = args.min_ssd_life;
+        }
+
+        Self {
+            compare_to_model: args.compare_to_model,
+            criteria,
+            gpu_soak_max_secs: args.gpu_sustained,
+            retry_failed: args.retry_failed,
+            // Verbose progress prints to stdout, which belongs to the JSON/Markdown document
+            verbosity: if fmt::document_output_enabled() { 0 } else { args.verbose },
+            ai_enabled: !args.no_ai && !fmt::document_output_enabled() && !fmt::summary_only_enabled(),
+            typewriter_ms: args.typewriter_ms,
+            output: args.output.clone(),
+            cpu_threads: args.threads.map(|n| n as usize),
+            cpu_workload: args.cpu_workload,
+            ram_passes: args.ram_passes,
+            ram_gb: args.ram_gb.map(|gb| gb as f64),
+            ram_access: args.ram_pattern,
+            ram_latency: args.ram_latency,
+            disk_size_mb: args.disk_size_mb,
+            disk_path: args.disk_path.clone(),
+            disk_verify: args.disk_verify,
+            disk_sustained: args.disk_sustained,
+            disk_index: args.disk_index,
+            retry_disks: Vec::new(),
+            smart_csv: args.smart_csv.clone(),
+            export: args.export.clone(),
+            temp_limits: stress::TempLimits::from_overrides(
+                args.temp_warn.map(f32::from),
+                args.temp_fail.map(f32::from),
+            ).unwrap_or_else(|e| {
+                eprintln!("error: {}", e);
+                std::process::exit(2);
+            }),
+        }
+    }
 }
 
 fn main() {
     let args = Args::parse();
+    interrupt::install();
+    fmt::set_progress_json(args.progress_json);
+    let json_output = args.json || args.format == OutputFormat::Json;
+    fmt::set_json_output(json_output);
+    fmt::set_json_pretty(args.json_pretty);
+    fmt::gzip::set_compress(args.compress);
+    let markdown_output = !json_output && args.format == OutputFormat::Markdown;
+    fmt::set_markdown_output(markdown_output);
+    fmt::set_summary_only(args.summary_only);
+
+    // Catch missing or untranslated keys early in debug builds
+    debug_assert!(
+        lang::missing_translations().is_empty(),
+        "translation problems: {:?}",
+        lang::missing_translations()
+    );
+
+    // Handle --build-info (no prompts)
+    if args.build_info {
+        print_build_info();
+        return;
+    }
+
+    // Handle --selftest (no prompts, non-zero exit on failure)
+    if args.selftest {
+        let passed = selftest::run_selftest();
+        std::process::exit(if passed { 0 } else { 1 });
+    }
+
+    // Handle --profile-detection (diagnostic only, no stress tests)
+    if args.profile_detection {
+        profile::run_profile_detection();
+        return;
+    }
+
+    // Handle --info --json (scriptable, no language prompt)
+    if args.info && json_output {
+        println!("{}", DeepInfo::collect().to_json());
+        return;
+    }
+
+    // Handle --info --format markdown (no prompts, English unless --lang)
+    if args.info && markdown_output {
+        let text = Text::new(args.lang.unwrap_or(Language::English));
+        println!("# 🤖 PCHECKER - v0.3.0\n\n{}", fmt::markdown::system_markdown(&DeepInfo::collect(), &text));
+        return;
+    }
+
+    // Torture mode only renders a live dashboard, there is no result document to emit
+    if (json_output || markdown_output) && args.all.is_some() {
+        eprintln!("error: --format {} is not supported with --all", if json_output { "json" } else { "markdown" });
+        std::process::exit(2);
+    }
+    if args.export.is_some() && (args.all.is_some() || args.info || args.watch.is_some()) {
+        eprintln!("error: --export is not supported with --all, --info or --watch");
+        std::process::exit(2);
+    }
+    if args.privileged && !cfg!(any(target_os = "macos", target_os = "linux")) {
+        eprintln!("error: --privileged is only supported on macOS and Linux");
+        std::process::exit(2);
+    }
+    if args.json_pretty && !json_output {
+        eprintln!("error: --json-pretty requires --format json");
+        std::process::exit(2);
+    }
+    if args.compress && args.output.is_none() && args.smart_csv.is_none() && args.telemetry.is_none() && args.export.is_none() {
+        eprintln!("error: --compress requires --output, --smart-csv, --telemetry or --export");
+        std::process::exit(2);
+    }
+    if args.interactive
+        && (json_output || markdown_output || args.info || args.all.is_some() || args.watch.is_some() || args.summary_only
+            || args.cpu.is_some() || args.ram || args.disk || args.gpu.is_some() || args.gpu_sustained.is_some())
+    {
+        eprintln!("error: --interactive can't be combined with test, --info, --all, --watch or output format flags");
+        std::process::exit(2);
+    }
+    if args.torture_components.is_some() && args.all.is_none() {
+        eprintln!("error: --torture-components requires --all");
+        std::process::exit(2);
+    }
+    // Both pick the disk to test, and a path on another disk than the index would leave nothing to test
+    if args.disk_path.is_some() && args.disk_index.is_some() {
+        eprintln!("error: --disk-path is not supported with --disk-index");
+        std::process::exit(2);
+    }
+    if args.watch.is_some() && (json_output || markdown_output || args.all.is_some() || args.info) {
+        eprintln!("error: --watch is not supported with --format json/markdown, --all or --info");
+        std::process::exit(2);
+    }
 
-    // Select language first
-    let lang = select_language_standalone();
+    // Validate options before prompting for anything
+    let opts = RunOptions::from_args(&args);
+    if let Some(threads) = args.threads {
+        // Same core count the CPU test clamps against
+        let cores = CpuInfo::new().cores;
+        let max_threads = stress::cpu::clamp_thread_count(usize::MAX, cores);
+        if threads as usize > max_threads {
+            eprintln!("error: --threads {} is above the limit of {} (4x the {} logical cores)", threads, max_threads, cores);
+            std::process::exit(2);
+        }
+    }
+    if let Some(index) = args.disk_index {
+        let disks = DiskInfo::new();
+        if index >= disks.len() {
+            eprintln!("error: --disk-index {} is out of range, available disks:", index);
+            for (i, disk) in disks.iter().enumerate() {
+                eprintln!("  {}: {} ({:.0} GB)", i, disk.name, disk.total_gb);
+            }
+            std::process::exit(2);
+        }
+    }
+    if let Some(path) = &args.disk_path {
+        let disks = DiskInfo::new();
+        let mounts: Vec<&str> = disks.iter().map(|d| d.mount_point.as_str()).collect();
+        let path = resolve_disk_path(path);
+        if stress::disk::disk_index_for_path(&mounts, &path).is_none() {
+            eprintln!("error: --disk-path {} is not on any detected disk, available mount points:", path.display());
+            for (i, disk) in disks.iter().enumerate() {
+                eprintln!("  {}: {} ({})", i, disk.mount_point, disk.name);
+            }
+            std::process::exit(2);
+        }
+    }
+    if let Some(path) = &args.telemetry {
+        if let Err(e) = fmt::telemetry::start(path) {
+            eprintln!("error: cannot create {}: {}", path.display(), e);
+            std::process::exit(2);
+        }
+    }
+
+    // Select language first (JSON output is English, Markdown follows --lang; neither prompts)
+    let lang = if json_output {
+        Language::English
+    } else if markdown_output {
+        args.lang.unwrap_or(Language::English)
+    } else {
+        args.lang.unwrap_or_else(prompt::select_language)
+    };
     let text = Text::new(lang);
 
     // Determine mode
-    let has_component_flags = args.cpu.is_some() || args.ram || args.disk || args.gpu.is_some();
+    let has_component_flags = args.cpu.is_some() || args.ram || args.disk || args.gpu.is_some() || args.gpu_sustained.is_some();
     let is_info_mode = args.info;
     let is_torture_mode = args.all.is_some();
     let is_auto_mode = !is_info_mode && !has_component_flags && !is_torture_mode;
 
+    // Elevation for SMART is settled before any output, never during a test
+    // smartctl only runs after a disk test in verbose mode or for the SMART CSV / export, don't ask otherwise
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    {
+        let runs_disk_test = args.disk || args.interactive || is_auto_mode;
+        let verbose = args.verbose >= 1 && !fmt::document_output_enabled();
+        let reads_smartctl = runs_disk_test && (verbose || args.smart_csv.is_some() || args.export.is_some());
+        if args.privileged && reads_smartctl
+            && !stress::disk::smart::request_privileges(&text, !fmt::document_output_enabled())
+        {
+            eprintln!("⚠️  {}", text.sudo_smart_skipped());
+        }
+    }
+
+
+    // Handle --interactive (menu loop until quit)
+    if args.interactive {
+        let outcome = run_interactive_mode(&text, &opts, args.yes, args.dashboard_hz);
+        exit_with(outcome);
+    }
+
     // Handle --info
     if is_info_mode {
-        let ai = AiTechnician::new(text.lang);
+        let ai = AiTechnician::new(text.lang, opts.ai_enabled, opts.typewriter_ms);
         ai.greet(&text);
         run_info_mode_all(&text, &ai);
         return;
@@ -75,128 +518,248 @@ fn main() {
     // Handle --all (torture test)
     if is_torture_mode {
         let duration = args.all.unwrap_or(60);
-        run_torture_mode(duration, &text);
-        return;
+        let components = args.torture_components.clone().unwrap_or_else(|| stress::torture::TortureComponent::ALL.to_vec());
+        let outcome = run_torture_mode(duration, &text, args.yes, components, args.dashboard_hz);
+        exit_with(outcome);
+    }
+
+    // Handle --watch (loops until Ctrl+C)
+    if let Some(minutes) = args.watch {
+        run_watch_mode(minutes, &args, &text, &opts);
     }
 
     // Handle component-specific tests
     if has_component_flags {
-        run_component_tests(&args, &text);
-        return;
+        let outcome = run_component_tests(&args, &text, &opts);
+        exit_with(outcome);
+    }
+
+    // JSON/Markdown output - run every component at the normal level, no prompts or torture test
+    if is_auto_mode && (json_output || markdown_output) {
+        let ai = AiTechnician::new(text.lang, opts.ai_enabled, opts.typewriter_ms);
+        let failed = run_health_check_mode(60, &text, &ai, true, true, true, true, 60, &opts);
+        exit_with(failed.outcome);
     }
 
     // Full auto mode - prompt for level
     if is_auto_mode {
-        run_auto_mode(&text);
+        let outcome = run_auto_mode(&text, &opts);
+        exit_with(outcome);
     }
 }
 
+/// Exit with the run's status, closing the telemetry file first
+fn exit_with(outcome: stress::RunOutcome) -> ! {
+    fmt::telemetry::finish();
+    std::process::exit(outcome.exit_code());
+}
+
+/// Version, target and compiled-in features, pasted into bug reports
+fn print_build_info() {
+    let feature = |enabled: bool| if enabled { "on" } else { "off" };
+    let platform = platform::detect();
+    println!("pchecker 0.3.0");
+    println!("target:      {}-{} ({})", std::env::consts::ARCH, std::env::consts::OS, std::env::consts::FAMILY);
+    println!("profile:     {}", if cfg!(debug_assertions) { "debug" } else { "release" });
+    println!("gpu-compute: {}", feature(cfg!(feature = "gpu-compute")));
+    println!("apple-smc:   {}", feature(cfg!(feature = "apple-smc")));
+    println!(
+        "platform:    {}",
+        platform::describe(&platform.to_string(), platform.os_version().as_deref(), platform.kernel_version().as_deref())
+    );
+}
+
 /// Run torture test mode (all components simultaneously)
-fn run_torture_mode(duration: u64, text: &Text) {
+fn run_torture_mode(
+    duration: u64,
+    text: &Text,
+    skip_confirm: bool,
+    components: Vec<stress::torture::TortureComponent>,
+    dashboard_hz: u32,
+) -> stress::RunOutcome {
     let config = stress::torture::TortureConfig {
         duration_secs: duration,
         _verbose: false,
         language: text.lang,
-        skip_confirm: false,  // Ask for confirmation when using --all flag
+        skip_confirm,  // --yes; otherwise ask (auto-confirmed when stdin is not a terminal)
+        components,
+        dashboard_hz,
     };
 
-    let _result = stress::torture::run_torture_test(config);
+    let outcome = stress::torture::run_torture_test(config).outcome();
+    interrupt::exit_if_interrupted(text);
+    outcome
 }
 
 /// Run component-specific tests (--cpu, --ram, --disk, --gpu)
-fn run_component_tests(args: &Args, text: &Text) {
+fn run_component_tests(args: &Args, text: &Text, opts: &RunOptions) -> stress::RunOutcome {
     let cpu_duration = args.cpu.unwrap_or(60);
     let gpu_duration = args.gpu.unwrap_or(60);
 
     // Create AI technician for component tests
-    let ai = AiTechnician::new(text.lang);
+    let ai = AiTechnician::new(text.lang, opts.ai_enabled, opts.typewriter_ms);
+
+    // Header and deep info stay hidden with --summary-only
+    fmt::set_muted(fmt::summary_only_enabled());
 
     // Print header
-    println!();
-    println!("============================================================");
-    println!("🧪 PCHECKER {} - v0.3.0", text.health_check());
-    println!("============================================================");
-    println!();
+    outln!();
+    outln!("============================================================");
+    outln!("🧪 PCHECKER {} - v0.3.0", text.health_check());
+    outln!("============================================================");
+    outln!();
 
     let platform_probe = get_platform_probe();
+    // Markdown output has its own system section with the deep info
+    let show_deep_info = !fmt::summary_only_enabled() && !fmt::markdown_output_enabled();
 
     // CPU: Show deep info before test
-    if args.cpu.is_some() {
+    if show_deep_info && args.cpu.is_some() {
         show_cpu_deep_info(text, &platform_probe);
     }
 
     // RAM: Show deep info before test
-    if args.ram {
+    if show_deep_info && args.ram {
         show_ram_deep_info(text, &platform_probe);
     }
 
     // Disk: Show deep info before test
-    if args.disk {
+    if show_deep_info && args.disk {
         show_disk_deep_info(text, &platform_probe);
     }
 
     // GPU: Show deep info before test
-    if args.gpu.is_some() {
+    let run_gpu = args.gpu.is_some() || args.gpu_sustained.is_some();
+    if show_deep_info && run_gpu {
         show_gpu_deep_info(text, &platform_probe);
     }
 
-    run_health_check_mode(
+    let failed = run_health_check_mode(
         cpu_duration,
         text,
         &ai,
         args.cpu.is_some(),
         args.ram,
         args.disk,
-        args.gpu.is_some(),
+        run_gpu,
         gpu_duration,
+        opts,
     );
+    retry_failed_components(&failed, cpu_duration, gpu_duration, text, &ai, opts);
+    failed.outcome
+}
+
+/// Repeat the health check every `minutes` until Ctrl+C (--watch)
+/// Runs the components given on the command line, or all of them at the normal level
+fn run_watch_mode(minutes: u64, args: &Args, text: &Text, opts: &RunOptions) -> ! {
+    let ai = AiTechnician::new(text.lang, opts.ai_enabled, opts.typewriter_ms);
+    let has_component_flags = args.cpu.is_some() || args.ram || args.disk || args.gpu.is_some() || args.gpu_sustained.is_some();
+    let (duration, gpu_duration) = if has_component_flags {
+        (args.cpu.unwrap_or(60), args.gpu.unwrap_or(60))
+    } else {
+        (60, 60)
+    };
+    let run_gpu = !has_component_flags || args.gpu.is_some() || args.gpu_sustained.is_some();
+
+    let mut iteration = 0u64;
+    loop {
+        iteration += 1;
+        // Clear the previous run on a terminal, keep scrolling output when piped to a log
+        if iteration > 1 && io::stdout().is_terminal() {
+            print!("\x1b[2J\x1b[H");
+        }
+        println!("🔁 {}", text.watch_iteration()
+            .replace("{N}", &iteration.to_string())
+            .replace("{TIME}", &transcript::format_utc(std::time::SystemTime::now())));
+
+        run_health_check_mode(
+            duration,
+            text,
+            &ai,
+            !has_component_flags || args.cpu.is_some(),
+            !has_component_flags || args.ram,
+            !has_component_flags || args.disk,
+            run_gpu,
+            gpu_duration,
+            opts,
+        );
+
+        // Ctrl+C while waiting ends the watch through the normal exit path (closes the telemetry file)
+        println!();
+        println!("⏸️  {}", text.watch_next_run().replace("{MIN}", &minutes.to_string()));
+        io::stdout().flush().unwrap();
+        interrupt::sleep_interruptible(std::time::Duration::from_secs(minutes * 60));
+        interrupt::exit_if_interrupted(text);
+    }
 }
 
 /// Show CPU deep info before test
 fn show_cpu_deep_info(text: &Text, probe: &PlatformProbe) {
     let cpu = CpuInfo::new();
-    println!("🧠 {} - {}", text.cpu(), cpu.model);
-    println!("   {} {}", cpu.cores, text.cores_label());
+    outln!("🧠 {} - {}", text.cpu(), cpu.model);
+    outln!("   {}", cpu.cores_display(text.cores_label()));
 
     if let Some(cache) = probe.get_cache_info() {
         if cache.l1_kb.is_some() || cache.l2_kb.is_some() || cache.l3_kb.is_some() {
-            println!("   Cache:");
-            if let Some(l1) = cache.l1_kb { println!("     L1: {} KB", l1); }
-            if let Some(l2) = cache.l2_kb { println!("     L2: {} KB", l2); }
-            if let Some(l3) = cache.l3_kb { println!("     L3: {} KB", l3); }
+            outln!("   Cache:");
+            if let Some(l1) = cache.l1_kb { outln!("     L1: {} KB", l1); }
+            if let Some(l2) = cache.l2_kb { outln!("     L2: {} KB", l2); }
+            if let Some(l3) = cache.l3_kb { outln!("     L3: {} KB", l3); }
         }
     }
     if let Some(isa) = probe.get_instruction_sets() {
-        println!("   Features: {}", isa.features.join(", "));
+        outln!("   Features: {}", isa.features.join(", "));
     }
     if let Some(tdp) = probe.get_tdp(&cpu.model) {
-        println!("   TDP: {} W", tdp);
+        outln!("   TDP: {} W", tdp);
     }
-    println!();
+    outln!();
 }
 
 /// Show RAM deep info before test
 fn show_ram_deep_info(text: &Text, probe: &PlatformProbe) {
-    let ram = RamInfo::new();
-    println!("💾 {} - {:.1} GB", text.ram(), ram.total_gb);
+    let ram = RamInfo::with_pressure();
+    outln!("💾 {} - {:.1} GB", text.ram(), ram.total_gb);
+    if let Some(pressure) = format_memory_pressure(&ram.pressure, text) {
+        outln!("   {}: {}", text.memory_pressure(), pressure);
+    }
 
     let dimm_slots = probe.get_dimm_slots();
     if !dimm_slots.is_empty() {
-        println!("   DIMM Slots:");
+        outln!("   DIMM Slots:");
         for slot in &dimm_slots {
-            println!("     - Slot {}: {} GB {} ({})", slot.id, slot.size_gb, slot.type_, slot.bank);
+            outln!("     - Slot {}: {} GB {} ({})", slot.id, slot.size_gb, slot.type_, slot.bank);
             if let Some(speed) = slot.speed_mhz {
-                println!("       Speed: {} MHz", speed);
+                outln!("       Speed: {} MHz", speed);
             }
             if let Some(ref mfr) = slot.manufacturer {
-                println!("       Manufacturer: {}", mfr);
+                outln!("       Manufacturer: {}", mfr);
             }
             if let Some(ref pn) = slot.part_number {
-                println!("       Part Number: {}", pn);
+                outln!("       Part Number: {}", pn);
             }
         }
     }
-    println!();
+    outln!();
+}
+
+/// Format memory pressure/compression state, None if nothing was reported
+fn format_memory_pressure(pressure: &hw::MemoryPressure, text: &Text) -> Option<String> {
+    let mut parts = Vec::new();
+    if let Some(level) = pressure.level {
+        parts.push(match level {
+            hw::PressureLevel::Normal => format!("✅ {}", text.pressure_normal()),
+            hw::PressureLevel::Warning => format!("⚠️ {}", text.pressure_warning()),
+            hw::PressureLevel::Critical => format!("❌ {}", text.pressure_critical()),
+        });
+    }
+    if let Some(pct) = pressure.commit_pct {
+        parts.push(format!("{} {:.0}%", text.commit_charge(), pct));
+    }
+    if let Some(gb) = pressure.compressed_gb {
+        parts.push(format!("{} {:.1} GB", text.memory_compressed(), gb));
+    }
+    (!parts.is_empty()).then(|| parts.join(" | "))
 }
 
 /// Show disk deep info before test
@@ -204,31 +767,31 @@ fn show_disk_deep_info(text: &Text, probe: &PlatformProbe) {
     let disks = DiskInfo::new();
     if disks.len() > 1 {
         for (idx, disk) in disks.iter().enumerate() {
-            println!("💿 {} #{} - {}", text.disk(), idx, disk.name);
-            println!("   Size: {:.1} GB", disk.total_gb);
+            outln!("💿 {} #{} - {}", text.disk(), idx, disk.name);
+            outln!("   Size: {:.1} GB", disk.total_gb);
         }
     } else if let Some(disk) = disks.first() {
-        println!("💿 {} - {}", text.disk(), disk.name);
-        println!("   Size: {:.1} GB", disk.total_gb);
+        outln!("💿 {} - {}", text.disk(), disk.name);
+        outln!("   Size: {:.1} GB", disk.total_gb);
     }
 
     if let Some(health) = probe.get_disk_health() {
-        println!("   Health:");
-        println!("     Status: {}", health.status);
+        outln!("   Health:");
+        outln!("     Status: {}", health.status);
         if let Some(ref fw) = health.firmware {
-            println!("     Firmware: {}", fw);
+            outln!("     Firmware: {}", fw);
         }
         if let Some(tbw) = health.tbw {
-            println!("     TBW: {:.1} TB", tbw);
+            outln!("     TBW: {:.1} TB", tbw);
         }
         if let Some(hours) = health.hours {
-            println!("     Power-On Hours: {}", hours);
+            outln!("     Power-On Hours: {}", hours);
         }
         if let Some(pct) = health.percentage_used {
-            println!("     Life Used: {}%", pct);
+            outln!("     Life Used: {}%", pct);
         }
     }
-    println!();
+    outln!();
 }
 
 /// Show GPU deep info before test
@@ -236,30 +799,99 @@ fn show_gpu_deep_info(text: &Text, probe: &PlatformProbe) {
     let gpus = GpuInfo::new();
     if gpus.len() > 1 {
         for (idx, gpu) in gpus.iter().enumerate() {
-            println!("🎮 {} #{} - {}", text.gpu(), idx, gpu.model);
+            outln!("🎮 {} #{} - {}", text.gpu(), idx, gpu.model);
         }
     } else if let Some(gpu) = gpus.first() {
-        println!("🎮 {} - {}", text.gpu(), gpu.model);
+        outln!("🎮 {} - {}", text.gpu(), gpu.model);
     }
 
     if let Some(driver) = probe.get_gpu_driver() {
         if let Some(metal) = driver.metal {
-            println!("   Metal: {}", metal);
+            outln!("   Metal: {}", metal);
         }
     }
-    println!();
+    outln!();
 }
 
 /// Run full auto mode (prompt for level)
-fn run_auto_mode(text: &Text) {
+fn run_auto_mode(text: &Text, opts: &RunOptions) -> stress::RunOutcome {
     let duration = select_level_prompt(text);
 
     // Run full test: Info → CPU → RAM → Disk → GPU → Summary
-    run_full_auto_test(duration, text);
+    run_full_auto_test(duration, text, opts)
+}
+
+/// Numbered menu of everything pchecker can run (--interactive); returns to the menu after each run
+/// Returns the worst outcome of the runs made; `skip_confirm` (--yes) skips the torture confirmation
+fn run_interactive_mode(text: &Text, opts: &RunOptions, skip_confirm: bool, dashboard_hz: u32) -> stress::RunOutcome {
+    use prompt::MenuChoice;
+
+    let ai = AiTechnician::new(text.lang, opts.ai_enabled, opts.typewriter_ms);
+    ai.greet(text);
+    let mut outcome = stress::RunOutcome::default();
+
+    loop {
+        println!();
+        println!("============================================================");
+        println!("{} - pchecker v0.3.0", text.menu_title());
+        println!("============================================================");
+        println!();
+        println!("[1] {}", text.menu_info());
+        println!("[2] {}", text.menu_cpu());
+        println!("[3] {}", text.menu_ram());
+        println!("[4] {}", text.menu_disk());
+        println!("[5] {}", text.menu_gpu());
+        println!("[6] {}", text.menu_full_auto());
+        println!("[7] {}", text.menu_torture());
+        println!("[0] {}", text.menu_quit());
+        println!();
+
+        // Quit when nobody can answer
+        let choice = prompt::read_choice_or_default(
+            &format!("{} [0-7]: ", text.your_choice()),
+            &format!("⚠️  {}", text.menu_invalid_choice()),
+            MenuChoice::Quit,
+            prompt::parse_menu_choice,
+        );
+
+        let run = match choice {
+            MenuChoice::Quit => return outcome,
+            MenuChoice::Info => {
+                run_info_mode_all(text, &ai);
+                continue;
+            }
+            MenuChoice::FullAuto => run_full_auto_test(select_level_prompt(text), text, opts),
+            MenuChoice::Torture => {
+                let components = stress::torture::TortureComponent::ALL.to_vec();
+                run_torture_mode(select_level_prompt(text), text, skip_confirm, components, dashboard_hz)
+            }
+            MenuChoice::Cpu | MenuChoice::Ram | MenuChoice::Disk | MenuChoice::Gpu => {
+                let duration = select_level_prompt(text);
+                let failed = run_health_check_mode(
+                    duration,
+                    text,
+                    &ai,
+                    choice == MenuChoice::Cpu,
+                    choice == MenuChoice::Ram,
+                    choice == MenuChoice::Disk,
+                    choice == MenuChoice::Gpu,
+                    duration,
+                    opts,
+                );
+                retry_failed_components(&failed, duration, duration, text, &ai, opts);
+                failed.outcome
+            }
+        };
+        outcome = outcome.max(run);
+    }
 }
 
 /// Level selection prompt
 fn select_level_prompt(text: &Text) -> u64 {
+    if !prompt::interactive() {
+        return 60;
+    }
+
     println!();
     println!("============================================================");
     println!("{} - pchecker v0.3.0", text.select_test_level());
@@ -270,44 +902,36 @@ fn select_level_prompt(text: &Text) -> u64 {
     println!("[3] {} (~480s)", text.level_deep());
     println!();
 
-    let stdin = io::stdin();
-    let mut input = String::new();
-
-    loop {
-        print!("{} [1-3]: ", text.your_choice());
-        io::stdout().flush().unwrap();
-
-        input.clear();
-        match stdin.read_line(&mut input) {
-            Ok(_) => {
-                match input.trim() {
-                    "1" => return 15,
-                    "2" => return 60,
-                    "3" => return 120,
-                    _ => {
-                        println!("⚠️  {}", text.invalid_choice());
-                        continue;
-                    }
-                }
-            }
-            Err(_) => return 60,
-        }
-    }
+    // Normal level when nobody can answer
+    prompt::read_choice_or_default(
+        &format!("{} [1-3]: ", text.your_choice()),
+        &format!("⚠️  {}", text.invalid_choice()),
+        60,
+        |choice| match choice {
+            "1" => Some(15),
+            "2" => Some(60),
+            "3" => Some(120),
+            _ => None,
+        },
+    )
 }
 
 /// Run full auto test with selected duration
-fn run_full_auto_test(duration: u64, text: &Text) {
+/// Returns the worst outcome of the health check and the torture test
+fn run_full_auto_test(duration: u64, text: &Text, opts: &RunOptions) -> stress::RunOutcome {
     // Create AI technician
-    let ai = AiTechnician::new(text.lang);
+    let ai = AiTechnician::new(text.lang, opts.ai_enabled, opts.typewriter_ms);
 
     // AI greeting
     ai.greet(text);
 
     // First show info
-    run_info_mode_all(text, &ai);
+    if !fmt::summary_only_enabled() {
+        run_info_mode_all(text, &ai);
+    }
 
     // Then run individual tests
-    run_health_check_mode(
+    let failed = run_health_check_mode(
         duration,
         text,
         &ai,
@@ -316,23 +940,31 @@ fn run_full_auto_test(duration: u64, text: &Text) {
         true,  // Disk
         true,  // GPU
         duration,
+        opts,
     );
+    retry_failed_components(&failed, duration, duration, text, &ai, opts);
 
     // Finally run torture test (test tổng - all components simultaneously)
-    println!();
-    println!("============================================================");
-    println!("⚡ {} - pchecker v0.3.0", text.torture_final());
-    println!("============================================================");
-    println!();
+    if !fmt::summary_only_enabled() {
+        println!();
+        println!("============================================================");
+        println!("⚡ {} - pchecker v0.3.0", text.torture_final());
+        println!("============================================================");
+        println!();
+    }
 
     let torture_config = stress::torture::TortureConfig {
         duration_secs: duration,
         _verbose: false,
         language: text.lang,
         skip_confirm: true,  // Skip confirmation in auto mode
+        components: stress::torture::TortureComponent::ALL.to_vec(),
+        dashboard_hz: 1,
     };
 
-    let _result = stress::torture::run_torture_test(torture_config);
+    let torture_result = stress::torture::run_torture_test(torture_config);
+    interrupt::exit_if_interrupted(text);
+    failed.outcome.max(torture_result.outcome())
 }
 
 /// Run info mode - show ALL hardware info (including deep info)
@@ -342,23 +974,21 @@ fn run_info_mode_all(text: &Text, ai: &AiTechnician) {
     // Print header
     print_header_with_text("v0.3.0", text.header());
 
-    // Detect platform
-    let platform = platform::detect();
-    print_section("💻", text.system(), &platform.to_string());
-
     // AI intro
     ai.intro_detect(text);
 
-    // Get platform probe for deep info
-    let platform_probe = get_platform_probe();
+    // Detect everything up front, then render
+    let info = DeepInfo::collect();
+    print_section("💻", text.system(), &info.system_display());
+    print_section("⏱️", text.uptime(), &fmt::format_uptime(info.uptime_secs));
+    if let Some([one, five, fifteen]) = info.load_average {
+        print_section("📊", text.load_average(), &format!("{:.2} {:.2} {:.2}", one, five, fifteen));
+    }
 
-    // Detect CPU + Deep Info
-    let cpu = CpuInfo::new();
-    let cpu_display = format!("{} ({} {})", cpu.model, cpu.cores, text.cores_label());
+    // CPU + Deep Info
+    let cpu_display = format!("{} ({})", info.cpu.model, info.cpu.cores_display(text.cores_label()));
     print_section("🧠", text.cpu(), &cpu_display);
-
-    // CPU Deep Info
-    if let Some(cache) = platform_probe.get_cache_info() {
+    if let Some(ref cache) = info.cache {
         if cache.l1_kb.is_some() || cache.l2_kb.is_some() || cache.l3_kb.is_some() {
             println!("   Cache:");
             if let Some(l1) = cache.l1_kb { println!("     L1: {} KB", l1); }
@@ -366,44 +996,42 @@ fn run_info_mode_all(text: &Text, ai: &AiTechnician) {
             if let Some(l3) = cache.l3_kb { println!("     L3: {} KB", l3); }
         }
     }
-    if let Some(isa) = platform_probe.get_instruction_sets() {
+    if let Some(ref isa) = info.instruction_sets {
         println!("   Features: {}", isa.features.join(", "));
     }
-    if let Some(tdp) = platform_probe.get_tdp(&cpu.model) {
+    if let Some(tdp) = info.tdp_w {
         println!("   TDP: {} W", tdp);
     }
     println!();
 
-    // Detect GPU + Deep Info
-    let gpus = GpuInfo::new();
-    if gpus.len() > 1 {
-        for (idx, gpu) in gpus.iter().enumerate() {
+    // GPU + Deep Info
+    if info.gpus.len() > 1 {
+        for (idx, gpu) in info.gpus.iter().enumerate() {
             print_section("🎮", &format!("{} #{}", text.gpu(), idx), &gpu.display_localized(text));
         }
-    } else if let Some(gpu) = gpus.first() {
+    } else if let Some(gpu) = info.gpus.first() {
         print_section("🎮", text.gpu(), &gpu.display_localized(text));
     } else {
         print_section("🎮", text.gpu(), text.no_gpu());
     }
 
     // GPU Deep Info (Metal version)
-    if let Some(driver) = platform_probe.get_gpu_driver() {
-        if let Some(metal) = driver.metal {
-            println!("   Metal: {}", metal);
-        }
+    if let Some(metal) = info.gpu_driver.as_ref().and_then(|d| d.metal.as_ref()) {
+        println!("   Metal: {}", metal);
     }
     println!();
 
-    // Detect RAM + Deep Info
-    let ram = RamInfo::new();
-    let ram_display = format!("{:.1} GB ({:.1} GB {})", ram.total_gb, ram.used_gb, text.ram_free());
+    // RAM + Deep Info
+    let ram_display = format!("{:.1} GB ({:.1} GB {})", info.ram.total_gb, info.ram.used_gb, text.ram_free());
     print_section("💾", text.ram(), &ram_display);
+    if let Some(pressure) = format_memory_pressure(&info.ram.pressure, text) {
+        println!("   {}: {}", text.memory_pressure(), pressure);
+    }
 
     // RAM Deep Info (DIMM slots)
-    let dimm_slots = platform_probe.get_dimm_slots();
-    if !dimm_slots.is_empty() {
+    if !info.dimm_slots.is_empty() {
         println!("   DIMM Slots:");
-        for slot in &dimm_slots {
+        for slot in &info.dimm_slots {
             println!("     - Slot {}: {} GB {} ({})", slot.id, slot.size_gb, slot.type_, slot.bank);
             if let Some(speed) = slot.speed_mhz {
                 println!("       Speed: {} MHz", speed);
@@ -418,18 +1046,17 @@ fn run_info_mode_all(text: &Text, ai: &AiTechnician) {
     }
     println!();
 
-    // Detect ALL disks + Deep Info
-    let disks = DiskInfo::new();
-    if disks.len() > 1 {
-        for (idx, disk) in disks.iter().enumerate() {
+    // ALL disks + Deep Info
+    if info.disks.len() > 1 {
+        for (idx, disk) in info.disks.iter().enumerate() {
             print_section("💿", &format!("{} #{}", text.disk(), idx), &disk.display());
         }
-    } else if let Some(disk) = disks.first() {
+    } else if let Some(disk) = info.disks.first() {
         print_section("💿", text.disk(), &disk.display());
     }
 
     // Disk Deep Info (Health)
-    if let Some(health) = platform_probe.get_disk_health() {
+    if let Some(ref health) = info.disk_health {
         println!("   Health:");
         println!("     Status: {}", health.status);
         if let Some(ref fw) = health.firmware {
@@ -447,106 +1074,182 @@ fn run_info_mode_all(text: &Text, ai: &AiTechnician) {
     }
     println!();
 
+    // Network adapters
+    if info.network.len() > 1 {
+        for (idx, nic) in info.network.iter().enumerate() {
+            print_section("🌐", &format!("{} #{}", text.network(), idx), &nic.display());
+        }
+    } else if let Some(nic) = info.network.first() {
+        print_section("🌐", text.network(), &nic.display());
+    } else {
+        print_section("🌐", text.network(), text.no_network());
+    }
+    println!();
+
+    // Battery (laptops only)
+    if let Some(ref battery) = info.battery {
+        print_section("🔋", text.battery(), &battery.display_localized(text));
+        if let HealthStatus::IssuesDetected(issues) = battery.health() {
+            for issue in issues {
+                println!("   ⚠️  {}", issue);
+            }
+        }
+        println!();
+    }
+
+    // What the health check will be able to measure
+    println!("🔎 {}: {}", text.measurable_label(), info.capabilities.badge_row());
+    for hint in info.tool_hints() {
+        println!("💡 {}", hint.message(text));
+    }
+    println!();
+
     // AI reaction to specs
-    let is_good_config = cpu.cores >= 8 || ram.total_gb >= 16.0;
+    let is_good_config = info.cpu.cores >= 8 || info.ram.total_gb >= 16.0;
     ai.react_specs(text, is_good_config);
 
     // Print footer
     print_footer_with_text(start_time, text.done_in());
 }
 
-/// Standalone language selection
-fn select_language_standalone() -> Language {
-    // Create a default Text for Vietnamese (default language)
-    let default_text = Text::new(Language::Vietnamese);
-
-    println!();
-    println!("============================================================");
-    println!("🤖 PCHECKER v0.2.0");
-    println!("============================================================");
-    println!();
-    println!("{}", default_text.language_select_prompt());
-    println!();
-    println!("  [1] {}", default_text.language_option_vi());
-    println!("  [2] {}", default_text.language_option_en());
-    println!();
-
-    let stdin = io::stdin();
-    let mut input = String::new();
-
-    loop {
-        print!("{}", default_text.language_choice_prompt());
-        io::stdout().flush().unwrap();
-
-        input.clear();
-        match stdin.read_line(&mut input) {
-            Ok(_) => {
-                match input.trim() {
-                    "1" | "vi" | "VI" | "vietnamese" => return Language::Vietnamese,
-                    "2" | "en" | "EN" | "english" => return Language::English,
-                    _ => {
-                        println!("{}", default_text.language_invalid_choice());
-                        continue;
-                    }
-                }
-            }
-            Err(_) => return Language::Vietnamese,
-        }
-    }
+/// Absolute --disk-path for matching against mount points (symlinks resolved when the path exists)
+fn resolve_disk_path(path: &std::path::Path) -> std::path::PathBuf {
+    path.canonicalize()
+        .or_else(|_| std::path::absolute(path))
+        .unwrap_or_else(|_| path.to_path_buf())
 }
 
 /// Run health check mode (v0.3.0 feature)
-fn run_health_check_mode(duration: u64, text: &Text, ai: &AiTechnician, run_cpu: bool, run_ram: bool, run_disk: bool, run_gpu: bool, gpu_duration: u64) {
+/// Returns the components that failed or showed issues
+#[allow(clippy::too_many_arguments)]
+fn run_health_check_mode(duration: u64, text: &Text, ai: &AiTechnician, run_cpu: bool, run_ram: bool, run_disk: bool, run_gpu: bool, gpu_duration: u64, opts: &RunOptions) -> FailedComponents {
     let start_time = Instant::now();
 
-    println!();
-    println!("============================================================");
-    println!("🧪 PCHECKER {} - v0.3.0", text.health_check());
-    println!("============================================================");
-    println!();
+    // With --summary-only, everything before the summary block only goes to the --output capture
+    fmt::set_muted(fmt::summary_only_enabled());
+
+    outln!();
+    outln!("============================================================");
+    outln!("🧪 PCHECKER {} - v0.3.0", text.health_check());
+    outln!("============================================================");
+    outln!();
 
     let mut all_healthy = true;
     let mut all_issues: Vec<String> = Vec::new();
     let mut critical_issues: Vec<String> = Vec::new();
+    let mut perf_checks: Vec<stress::expected::PerfCheck> = Vec::new();
+    let mut perf_missing: Vec<(&str, String)> = Vec::new();
+    let mut evidence: Vec<stress::criteria::Evidence> = Vec::new();
+    let mut timings: Vec<(String, f64)> = Vec::new();
+    let mut failed = FailedComponents::default();
+    let mut report = fmt::json::HealthReport::new();
+    let mut transcript = opts.output.as_ref().map(|_| transcript::Transcript::new());
+    let mut smart_csv_rows: Vec<String> = Vec::new();
+    let mut result_tables: Vec<fmt::report::ResultTable> = Vec::new();
+    let run_timestamp = transcript::format_rfc3339(std::time::SystemTime::now());
 
     // Detect hardware info first
     let cpu_info = CpuInfo::new();
-    let ram_info = RamInfo::new();
+    // Memory pressure is only needed for the warning before the RAM test
+    let ram_info = if run_ram { RamInfo::with_pressure() } else { RamInfo::new() };
     let disk_info_list = DiskInfo::new();
     let gpu_info_list = GpuInfo::new();
 
     // Test ALL disks
-    let disks_to_test: Vec<(usize, crate::hw::DiskInfo)> = disk_info_list
+    let mut disks_to_test: Vec<(usize, crate::hw::DiskInfo)> = disk_info_list
         .iter()
         .enumerate()
         .map(|(i, d)| (i, d.clone()))
         .collect();
 
+    // An explicit --disk-path tests only the disk that holds it (checked to exist in main)
+    if let Some(ref path) = opts.disk_path {
+        let path = resolve_disk_path(path);
+        let mounts: Vec<&str> = disk_info_list.iter().map(|d| d.mount_point.as_str()).collect();
+        let holder = stress::disk::disk_index_for_path(&mounts, &path);
+        disks_to_test.retain(|(idx, _)| Some(*idx) == holder);
+    }
+
+    // --disk-index N tests only the Nth detected disk (range checked in main)
+    if let Some(index) = opts.disk_index {
+        disks_to_test.retain(|(idx, _)| *idx == index);
+    }
+    if !opts.retry_disks.is_empty() {
+        disks_to_test.retain(|(idx, _)| opts.retry_disks.contains(idx));
+    }
+
     // CPU Test
     if run_cpu {
-        println!("⏳ {} ({}s)", text.testing_cpu(), duration);
+        let component_start = Instant::now();
+        outln!("⏳ {} ({}s)", text.testing_cpu(), duration);
+        // Hybrid CPUs: the P/E split explains uneven per-core speeds and temperatures
+        if cpu_info.core_split().is_some() {
+            outln!("   {}", cpu_info.cores_display(text.cores_label()));
+        }
+
+        // Passive pre-test check: uneven idle temperatures point to cooler contact problems
+        if let Some(spread) = sensors::core_temp_spread(&sensors::get_core_temps()) {
+            outln!("   {}: {:.1}°C ({:.0}-{:.0}°C)", text.idle_core_spread(), spread.spread(), spread.min, spread.max);
+            if spread.is_excessive() {
+                outln!("   ⚠️  {}", text.idle_spread_advisory()
+                    .replace("{CORE}", &spread.hottest_label)
+                    .replace("{SPREAD}", &format!("{:.0}", spread.spread())));
+            }
+        }
         io::stdout().flush().unwrap();
 
         // Create AI callback for CPU
         let ai_clone = (*ai).clone();
         let cpu_config = CpuTestConfig {
             duration_secs: duration,
-            thread_count: None,
-            verbose: false,
+            thread_count: opts.cpu_threads.map(|n| stress::cpu::clamp_thread_count(n, cpu_info.cores)),
+            workload: opts.cpu_workload,
+            verbose: opts.verbosity >= 1,
+            show_sensors: opts.verbosity >= 2,
+            temp_limits: opts.temp_limits,
+            l2_cache_kb: if opts.cpu_workload == stress::CpuWorkload::Cache {
+                get_platform_probe().get_cache_info().and_then(|cache| cache.l2_kb)
+            } else {
+                None
+            },
             on_comment: Some(Box::new(move |msg| {
                 ai_clone.comment_realtime(msg);
             })),
         };
         let cpu_result = stress::run_cpu_test(cpu_config, cpu_info.model.clone(), cpu_info.cores);
+        interrupt::exit_if_interrupted(text);
 
-        let (cpu_healthy, cpu_issues) = print_cpu_result(&cpu_result, text);
+        if transcript.is_some() {
+            fmt::start_capture();
+        }
+        let cpu_table = cpu_result_table(&cpu_result, text);
+        let (cpu_healthy, cpu_issues) = print_result_table(&cpu_table);
+        result_tables.push(cpu_table);
+        if let Some(t) = transcript.as_mut() {
+            t.add_result("CPU", &fmt::take_capture(), &cpu_result.health);
+        }
+
+        evidence.push(stress::criteria::Evidence::Thermal {
+            component: "CPU".to_string(),
+            available: cpu_result.temperature.is_some(),
+        });
+
+        // Baselines are for the prime workload, other workloads aren't compared
+        if opts.compare_to_model && cpu_result.workload == stress::CpuWorkload::Primes {
+            match stress::expected::cpu_check(&cpu_info.model, cpu_result.parallel_threads(), cpu_result.ops_per_second) {
+                Some(check) => perf_checks.push(check),
+                None => perf_missing.push(("CPU", cpu_info.model.clone())),
+            }
+        }
 
         // AI post-test reaction
         let has_warning = matches!(cpu_result.health, HealthStatus::IssuesDetected(_));
+        failed.outcome = failed.outcome.max(stress::RunOutcome::from_result(&cpu_result.health, cpu_healthy));
         ai.react_result(text, cpu_healthy, has_warning);
 
         if !cpu_healthy {
             all_healthy = false;
+            failed.cpu = true;
             if matches!(cpu_result.health, HealthStatus::Failed(_)) {
                 if let HealthStatus::Failed(ref msg) = cpu_result.health {
                     critical_issues.push(format!("CPU: {}", msg));
@@ -554,33 +1257,72 @@ fn run_health_check_mode(duration: u64, text: &Text, ai: &AiTechnician, run_cpu:
             }
         }
         all_issues.extend(cpu_issues);
-        println!();
+        timings.push(("CPU".to_string(), component_start.elapsed().as_secs_f64()));
+        report.cpu = Some(cpu_result);
+        outln!();
     }
 
     // RAM Test
     if run_ram {
-        let ram_duration = (duration / 2).max(10);
-        println!("⏳ {} (~{}s)", text.testing_ram(), ram_duration);
+        let component_start = Instant::now();
+        let ram_duration = (duration / 2).max(10) * opts.ram_passes.max(1) as u64;
+        outln!("⏳ {} (~{}s)", text.testing_ram(), ram_duration);
+        if ram_info.pressure.is_significant(ram_info.total_gb) {
+            outln!("⚠️  {}", text.memory_pressure_warning());
+        }
         io::stdout().flush().unwrap();
 
         // Create AI callback for RAM
         let ai_clone = (*ai).clone();
         let ram_config = RamTestConfig {
-            max_gb: None,
+            max_gb: opts.ram_gb,
+            passes: opts.ram_passes,
+            access: opts.ram_access,
+            latency: opts.ram_latency,
+            l3_cache_kb: if opts.ram_latency {
+                get_platform_probe().get_cache_info().and_then(|cache| cache.l3_kb)
+            } else {
+                None
+            },
+            text: text.clone(),
             on_comment: Some(Box::new(move |msg| {
                 ai_clone.comment_realtime(msg);
             })),
+            on_progress: None,
         };
-        let ram_result = stress::run_ram_test(ram_config, ram_info.total_gb);
+        let mut ram_result = stress::run_ram_test(ram_config, ram_info.total_gb);
+        interrupt::exit_if_interrupted(text);
+
+        // Missing DIMMs only show up when comparing slots against the OS total
+        let dimm_slots = get_platform_probe().get_dimm_slots();
+        let dimm_sizes: Vec<f64> = dimm_slots.iter().map(|s| s.size_gb).collect();
+        if let Some(issue) = stress::ram::check_dimm_total(&dimm_sizes, ram_info.total_gb) {
+            ram_result.health = ram_result.health.with_issue(issue);
+        }
 
-        let (ram_healthy, ram_issues) = print_ram_result(&ram_result, text);
+        if transcript.is_some() {
+            fmt::start_capture();
+        }
+        let ram_table = ram_result_table(&ram_result, text);
+        let (ram_healthy, ram_issues) = print_result_table(&ram_table);
+        result_tables.push(ram_table);
+        if let Some(t) = transcript.as_mut() {
+            t.add_result("RAM", &fmt::take_capture(), &ram_result.health);
+        }
+
+        if opts.compare_to_model {
+            let ram_type = dimm_slots.first().map(|s| s.type_.clone());
+            perf_checks.push(stress::expected::ram_check(ram_type.as_deref(), ram_result.write_speed_gb_s));
+        }
 
         // AI post-test reaction
         let has_warning = matches!(ram_result.health, HealthStatus::IssuesDetected(_));
+        failed.outcome = failed.outcome.max(stress::RunOutcome::from_result(&ram_result.health, ram_healthy));
         ai.react_result(text, ram_healthy, has_warning);
 
         if !ram_healthy {
             all_healthy = false;
+            failed.ram = true;
             if matches!(ram_result.health, HealthStatus::Failed(_)) {
                 if let HealthStatus::Failed(ref msg) = ram_result.health {
                     critical_issues.push(format!("RAM: {}", msg));
@@ -588,49 +1330,93 @@ fn run_health_check_mode(duration: u64, text: &Text, ai: &AiTechnician, run_cpu:
             }
         }
         all_issues.extend(ram_issues);
-        println!();
+        timings.push(("RAM".to_string(), component_start.elapsed().as_secs_f64()));
+        report.ram = Some(ram_result);
+        outln!();
     }
 
     // Disk Test
     if run_disk {
         for (idx, disk_info) in &disks_to_test {
+            let component_start = Instant::now();
             if disks_to_test.len() > 1 {
-                println!("⏳ {} #{} (~30s)", text.testing_disk(), idx);
+                outln!("⏳ {} #{} (~30s)", text.testing_disk(), idx);
             } else {
-                println!("⏳ {} (~30s)", text.testing_disk());
+                outln!("⏳ {} (~30s)", text.testing_disk());
             }
             io::stdout().flush().unwrap();
 
             // Create AI callback for Disk
             let ai_clone = (*ai).clone();
             let disk_config = DiskTestConfig {
-                test_path: None,
-                test_size_mb: 100,
+                test_path: opts.disk_path.as_ref().map(|p| p.to_string_lossy().to_string()),
+                test_size_mb: opts.disk_size_mb,
                 include_seek_test: true,
+                verify_mismatches: opts.disk_verify,
+                sustained_mb: opts.disk_sustained,
                 text: text.clone(),
-                verbose: false,
+                verbose: opts.verbosity >= 1,
                 on_comment: Some(Box::new(move |msg| {
                     ai_clone.comment_realtime(msg);
                 })),
             };
-            let disk_result = stress::run_disk_test(
+            let mut disk_result = stress::run_disk_test(
                 disk_config,
                 disk_info.name.clone(),
                 disk_info.total_gb,
                 disk_info.used_gb,
                 disk_info.available_gb,
-                "APFS".to_string(),
+                disk_info.file_system.clone(),
                 &disk_info.mount_point,
             );
+            interrupt::exit_if_interrupted(text);
+
+            if transcript.is_some() {
+                fmt::start_capture();
+            }
+            let disk_table = disk_result_table(&disk_result, text);
+            let (disk_healthy, disk_issues) = print_result_table(&disk_table);
+            result_tables.push(disk_table);
+            if let Some(t) = transcript.as_mut() {
+                let label = if disks_to_test.len() > 1 { format!("Disk #{} ({})", idx, disk_info.name) } else { "Disk".to_string() };
+                t.add_result(&label, &fmt::take_capture(), &disk_result.health);
+            }
+
+            if opts.criteria.is_strict() || opts.smart_csv.is_some() || opts.export.is_some() {
+                // SMART is only collected in verbose mode, fetch it here otherwise
+                // (full attributes for the CSV and export, the basic status for the criteria)
+                let full = opts.smart_csv.is_some() || opts.export.is_some();
+                let smart = disk_result.smart.clone()
+                    .unwrap_or_else(|| stress::disk::smart::get_smart_data(&disk_info.mount_point, full));
+                if opts.export.is_some() && disk_result.smart.is_none() {
+                    disk_result.smart = Some(smart.clone());
+                }
+                if opts.criteria.is_strict() {
+                    evidence.push(stress::criteria::Evidence::Disk {
+                        disk: disk_info.name.clone(),
+                        is_ssd: disk_result.is_ssd,
+                        smart_verified: smart.status == stress::disk::smart::SmartStatus::Verified,
+                        life_pct: smart.ssd_life_left.or(smart.health_percentage),
+                    });
+                }
+                if opts.smart_csv.is_some() {
+                    smart_csv_rows.push(fmt::csv::smart_csv_row(&run_timestamp, &disk_info.name, &smart));
+                }
+            }
 
-            let (disk_healthy, disk_issues) = print_disk_result(&disk_result, text);
+            if opts.compare_to_model && disk_result.read_speed_mb_s > 0.0 {
+                perf_checks.push(stress::expected::disk_check(&disk_info.name, disk_result.is_ssd, disk_result.read_speed_mb_s));
+            }
 
             // AI post-test reaction
             let has_warning = matches!(disk_result.health, HealthStatus::IssuesDetected(_));
+            failed.outcome = failed.outcome.max(stress::RunOutcome::from_result(&disk_result.health, disk_healthy));
             ai.react_result(text, disk_healthy, has_warning);
 
             if !disk_healthy {
                 all_healthy = false;
+                failed.disk = true;
+                failed.disks.push(*idx);
                 if matches!(disk_result.health, HealthStatus::Failed(_)) {
                     if let HealthStatus::Failed(ref msg) = disk_result.health {
                         if disks_to_test.len() > 1 {
@@ -642,40 +1428,94 @@ fn run_health_check_mode(duration: u64, text: &Text, ai: &AiTechnician, run_cpu:
                 }
             }
             all_issues.extend(disk_issues);
-            println!();
+            let label = if disks_to_test.len() > 1 { format!("Disk #{}", idx) } else { "Disk".to_string() };
+            timings.push((label, component_start.elapsed().as_secs_f64()));
+            report.disks.push(disk_result);
+            outln!();
         }
     }
 
     // GPU Test
     if run_gpu {
         if gpu_info_list.is_empty() {
-            println!("⏠️  {}", text.no_gpu());
-            println!();
+            outln!("⏠️  {}", text.no_gpu());
+            outln!();
         } else {
             for (idx, gpu_info) in gpu_info_list.iter().enumerate() {
-                if gpu_info_list.len() > 1 {
-                    println!("⏳ {} #{} (~{}s)", text.testing_gpu(), idx, gpu_duration);
+                let component_start = Instant::now();
+                let gpu_label = if gpu_info_list.len() > 1 { format!("GPU #{} ({})", idx, gpu_info.model) } else { "GPU".to_string() };
+                let (gpu_health, gpu_healthy, gpu_issues, gpu_thermal) = if let Some(max_secs) = opts.gpu_soak_max_secs {
+                    // Sustained mode: run until temperature stabilizes instead of a fixed duration
+                    outln!("⏳ {} (≤{}s)", text.testing_gpu_soak(), max_secs);
+                    io::stdout().flush().unwrap();
+
+                    let soak_config = stress::gpu::soak::GpuSoakConfig {
+                        max_secs,
+                        temp_limits: opts.temp_limits.unwrap_or_default(),
+                        ..Default::default()
+                    };
+                    let soak_result = stress::gpu::soak::run_sustained_test(soak_config, gpu_info.model.clone());
+                    if transcript.is_some() {
+                        fmt::start_capture();
+                    }
+                    let gpu_soak_table = gpu_soak_result_table(&soak_result, text);
+                    let (healthy, issues) = print_result_table(&gpu_soak_table);
+                    result_tables.push(gpu_soak_table);
+                    if let Some(t) = transcript.as_mut() {
+                        t.add_result(&gpu_label, &fmt::take_capture(), &soak_result.health);
+                    }
+                    let thermal = soak_result.max_temp_c.is_some();
+                    let health = soak_result.health.clone();
+                    report.gpu_soak.push(soak_result);
+                    (health, healthy, issues, thermal)
                 } else {
-                    println!("⏳ {} (~{}s)", text.testing_gpu(), gpu_duration);
-                }
-                io::stdout().flush().unwrap();
+                    if gpu_info_list.len() > 1 {
+                        outln!("⏳ {} #{} (~{}s)", text.testing_gpu(), idx, gpu_duration);
+                    } else {
+                        outln!("⏳ {} (~{}s)", text.testing_gpu(), gpu_duration);
+                    }
+                    io::stdout().flush().unwrap();
+
+                    let gpu_config = stress::GpuTestConfig {
+                        duration_secs: gpu_duration,
+                        verbose: opts.verbosity >= 2,
+                        temp_limits: opts.temp_limits.unwrap_or_default(),
+                    };
+                    let gpu_result = stress::run_gpu_test(
+                        gpu_config,
+                        gpu_info.model.clone(),
+                        gpu_info.gpu_type.as_str().to_string(),
+                        gpu_info.vram_gb,
+                    );
+
+                    if transcript.is_some() {
+                        fmt::start_capture();
+                    }
+                    let gpu_table = gpu_result_table(&gpu_result, text);
+                    let (healthy, issues) = print_result_table(&gpu_table);
+                    result_tables.push(gpu_table);
+                    if let Some(t) = transcript.as_mut() {
+                        t.add_result(&gpu_label, &fmt::take_capture(), &gpu_result.health);
+                    }
 
-                let gpu_config = stress::GpuTestConfig {
-                    duration_secs: gpu_duration,
-                    verbose: false,
+                    // Apple Silicon has no separate GPU sensor, thermal pressure counts as thermal data
+                    let thermal = gpu_result.temperature_max.is_some()
+                        || gpu_result.apple_gpu_metrics.as_ref().is_some_and(|m| m.thermal_pressure.is_some());
+                    let health = gpu_result.health.clone();
+                    report.gpus.push(gpu_result);
+                    (health, healthy, issues, thermal)
                 };
-                let gpu_result = stress::run_gpu_test(
-                    gpu_config,
-                    gpu_info.model.clone(),
-                    gpu_info.gpu_type.as_str().to_string(),
-                    gpu_info.vram_gb,
-                );
 
-                let (gpu_healthy, gpu_issues) = print_gpu_result(&gpu_result, text);
+                evidence.push(stress::criteria::Evidence::Thermal {
+                    component: if gpu_info_list.len() > 1 { format!("GPU #{}", idx) } else { "GPU".to_string() },
+                    available: gpu_thermal,
+                });
+                failed.outcome = failed.outcome.max(stress::RunOutcome::from_result(&gpu_health, gpu_healthy));
                 if !gpu_healthy {
                     all_healthy = false;
-                    if matches!(gpu_result.health, HealthStatus::Failed(_)) {
-                        if let HealthStatus::Failed(ref msg) = gpu_result.health {
+                    failed.gpu = true;
+                    if matches!(gpu_health, HealthStatus::Failed(_)) {
+                        if let HealthStatus::Failed(ref msg) = gpu_health {
                             if gpu_info_list.len() > 1 {
                                 critical_issues.push(format!("GPU #{} ({}): {}", idx, gpu_info.model, msg));
                             } else {
@@ -685,47 +1525,239 @@ fn run_health_check_mode(duration: u64, text: &Text, ai: &AiTechnician, run_cpu:
                     }
                 }
                 all_issues.extend(gpu_issues);
-                println!();
+                let label = if gpu_info_list.len() > 1 { format!("GPU #{}", idx) } else { "GPU".to_string() };
+                timings.push((label, component_start.elapsed().as_secs_f64()));
+                outln!();
             }
         }
     }
 
     // Overall summary
-    println!("============================================================");
+    fmt::set_muted(false);
+    outln!("============================================================");
     if !critical_issues.is_empty() {
-        println!("❌ {}", text.critical_issues());
+        outln!("❌ {}", text.critical_issues());
         for issue in &critical_issues {
-            println!("   • {}", issue);
+            outln!("   • {}", issue);
         }
     } else if !all_issues.is_empty() {
-        println!("⚠️  {}", text.issues_detected());
+        outln!("⚠️  {}", text.issues_detected());
         for issue in &all_issues {
-            println!("   • {}", issue);
+            outln!("   • {}", issue);
         }
     }
-    println!("{}", text.summary());
-    if all_healthy && critical_issues.is_empty() {
-        println!("✅ {}", text.hardware_good());
-    } else if critical_issues.is_empty() {
-        println!("⚠️  {}", text.hardware_some_issues());
-    } else {
-        println!("❌ {}", text.hardware_not_recommended());
+    outln!("{}", text.summary());
+    let unmet = opts.criteria.unmet(&evidence, text);
+    let verdict = fmt::json::Verdict::from_run(all_healthy, !critical_issues.is_empty(), !unmet.is_empty());
+    let verdict_line = match verdict {
+        fmt::json::Verdict::NotFullyVerified => format!("⚠️  {}", text.hardware_not_fully_verified()),
+        fmt::json::Verdict::Good => format!("✅ {}", text.hardware_good()),
+        fmt::json::Verdict::SomeIssues => format!("⚠️  {}", text.hardware_some_issues()),
+        fmt::json::Verdict::NotRecommended => format!("❌ {}", text.hardware_not_recommended()),
+    };
+    outln!("{}", verdict_line);
+    if verdict == fmt::json::Verdict::NotFullyVerified {
+        outln!("   {}", text.unmet_criteria());
+        for criterion in &unmet {
+            outln!("   • {}", criterion);
+        }
     }
-    println!("============================================================");
-    println!();
-    println!("{} {:.2}s", text.done_in(), start_time.elapsed().as_secs_f64());
-    println!("============================================================");
+    let timings_line = (!timings.is_empty()).then(|| {
+        let breakdown: Vec<String> = timings.iter()
+            .map(|(name, secs)| format!("{} {:.1}s", name, secs))
+            .collect();
+        format!("⏱️  {} {}", text.time_per_component(), breakdown.join(" | "))
+    });
+    if let Some(ref line) = timings_line {
+        outln!("{}", line);
+    }
+    outln!("============================================================");
+
+    if opts.compare_to_model {
+        print_perf_comparison(&perf_checks, &perf_missing, text);
+    }
+
+    outln!();
+    outln!("{} {:.2}s", text.done_in(), start_time.elapsed().as_secs_f64());
+    outln!("============================================================");
+
+    if let (Some(t), Some(path)) = (&transcript, &opts.output) {
+        if let Err(e) = t.append_to(path, start_time.elapsed()) {
+            eprintln!("⚠️  {} {}: {}", text.output_write_failed(), path.display(), e);
+        }
+    }
+
+    if let Some(ref path) = opts.smart_csv {
+        if let Err(e) = fmt::csv::append_smart_csv(path, &smart_csv_rows) {
+            eprintln!("⚠️  {} {}: {}", text.output_write_failed(), path.display(), e);
+        }
+    }
+
+    if fmt::markdown_output_enabled() {
+        // Critical issues first, then the rest (same order as the text summary)
+        let issues: Vec<String> = if critical_issues.is_empty() { all_issues.clone() } else { critical_issues.clone() };
+        let issues: Vec<String> = issues.into_iter().chain(unmet.iter().cloned()).collect();
+        let footer: Vec<String> = timings_line.into_iter()
+            .chain(std::iter::once(format!("{} {:.2}s", text.done_in(), start_time.elapsed().as_secs_f64())))
+            .collect();
+        println!("{}", fmt::markdown::report_markdown(
+            &format!("🧪 PCHECKER {} - v0.3.0", text.health_check()),
+            &fmt::markdown::system_markdown(&DeepInfo::collect(), text),
+            &result_tables,
+            &verdict_line,
+            &issues,
+            &footer,
+        ));
+    }
+
+    if fmt::json_output_enabled() || opts.export.is_some() {
+        report.verdict = verdict;
+        report.critical_issues = critical_issues;
+        report.issues = all_issues;
+        report.unmet_criteria = unmet;
+        report.perf_checks = perf_checks;
+        report.timings = timings.into_iter()
+            .map(|(component, seconds)| fmt::json::ComponentTiming { component, seconds })
+            .collect();
+        report.elapsed_s = start_time.elapsed().as_secs_f64();
+    }
+    if fmt::json_output_enabled() {
+        println!("{}", report.to_json());
+    }
+
+    if let Some(ref path) = opts.export {
+        let system = DeepInfo::collect();
+        let bundle = fmt::export::ExportBundle {
+            schema_version: fmt::export::SCHEMA_VERSION,
+            pchecker_version: "0.3.0",
+            generated_at: run_timestamp.clone(),
+            args: std::env::args().collect(),
+            system: &system,
+            report: &report,
+        };
+        match bundle.write(path) {
+            Ok(checksum) => outln!("📦 {} {} ({})", text.report_exported(), path.display(), checksum.display()),
+            Err(e) => eprintln!("⚠️  {} {}: {}", text.output_write_failed(), path.display(), e),
+        }
+    }
+
+    failed
+}
+
+/// Components that failed or showed issues in a health check run
+#[derive(Debug, Clone, Default)]
+struct FailedComponents {
+    cpu: bool,
+    ram: bool,
+    disk: bool,
+    /// Indices of the disks behind `disk`
+    disks: Vec<usize>,
+    gpu: bool,
+    /// Worst status across all components (process exit code)
+    outcome: stress::RunOutcome,
 }
 
-fn print_cpu_result(result: &stress::CpuTestResult, text: &Text) -> (bool, Vec<String>) {
+impl FailedComponents {
+    fn any(&self) -> bool {
+        self.cpu || self.ram || self.disk || self.gpu
+    }
+
+    fn names(&self) -> Vec<&'static str> {
+        [(self.cpu, "CPU"), (self.ram, "RAM"), (self.disk, "Disk"), (self.gpu, "GPU")]
+            .into_iter()
+            .filter_map(|(failed, name)| failed.then_some(name))
+            .collect()
+    }
+}
+
+/// Re-run only the failed components to check whether the fault reproduces
+/// Runs straight away with --retry-failed, otherwise asks first
+fn retry_failed_components(failed: &FailedComponents, duration: u64, gpu_duration: u64, text: &Text, ai: &AiTechnician, opts: &RunOptions) {
+    // With --format json/markdown stdout holds exactly one document, a second run would append another
+    if !failed.any() || fmt::document_output_enabled() {
+        return;
+    }
+    let list = failed.names().join(", ");
+
+    if !opts.retry_failed {
+        // Never block a scripted run on a prompt
+        if fmt::progress_json_enabled() || !prompt::interactive() {
+            return;
+        }
+        outln!();
+        out!("{}", text.retry_failed_prompt().replace("{LIST}", &list));
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input).is_err() || !matches!(input.trim(), "y" | "Y" | "yes") {
+            return;
+        }
+    }
+
+    outln!();
+    outln!("🔁 {}", text.retrying_failed().replace("{LIST}", &list));
+    // The first run's files stay as they are: the --export bundle keeps the full report, and the
+    // transcript and SMART CSV get no duplicate entries; only the disks that failed are tested again
+    let retry_opts = RunOptions {
+        export: None,
+        output: None,
+        smart_csv: None,
+        retry_disks: failed.disks.clone(),
+        ..opts.clone()
+    };
+    run_health_check_mode(duration, text, ai, failed.cpu, failed.ram, failed.disk, failed.gpu, gpu_duration, &retry_opts);
+}
+
+/// Print measured vs expected performance per component (--compare-to-model)
+fn print_perf_comparison(checks: &[stress::expected::PerfCheck], missing: &[(&str, String)], text: &Text) {
+    outln!();
+    outln!("📈 {}", text.perf_compare_title());
+    if cfg!(debug_assertions) {
+        outln!("   ⚠️  {}", text.perf_debug_build());
+    }
+
+    for check in checks {
+        let line = if check.is_underperforming() {
+            let hint = match check.component {
+                "CPU" => text.perf_hint_cpu(),
+                "RAM" => text.perf_hint_ram(),
+                _ => text.perf_hint_disk(),
+            };
+            format!("⚠️  {} - {}", text.perf_underperforming(), hint)
+                .replace("{PCT}", &format!("{:.0}", check.shortfall_pct()))
+        } else {
+            format!("✅ {}", text.perf_as_expected())
+                .replace("{PCT}", &format!("{:.0}", check.index_pct()))
+        };
+        outln!("   {} ({:.1} / {:.1} {})",
+            line.replace("{COMPONENT}", check.component).replace("{MODEL}", &check.reference),
+            check.measured, check.expected, check.unit);
+    }
+
+    for (component, model) in missing {
+        outln!("   ? {}", text.perf_no_baseline()
+            .replace("{COMPONENT}", component)
+            .replace("{MODEL}", model));
+    }
+    outln!("============================================================");
+}
+
+/// Print a result table as a box and return (healthy, issues) for the summary
+fn print_result_table(table: &fmt::report::ResultTable) -> (bool, Vec<String>) {
+    // Reset any colors from progress bars before printing result box
+    out!("{}", fmt::color(fmt::RESET));
+    for line in table.box_lines() {
+        outln!("{}", line);
+    }
+    (table.healthy, table.issues.clone())
+}
+
+fn cpu_result_table(result: &stress::CpuTestResult, text: &Text) -> fmt::report::ResultTable {
     let ops_str = format_number(result.operations);
     let ops_sec_str = format!("{:.0}", result.ops_per_second);
     let time_str = format!("{:.3}ms", result.avg_op_time_ms);
     let var_str = format!("{:.1}%", result.variance_pct);
 
-    // Reset any colors from progress bars before printing result box
-    print!("\x1b[0m");
-
     // Temperature display
     let temp_str = if let Some(temp) = &result.temperature {
         format!("{:.1}°C", temp.current)
@@ -737,33 +1769,42 @@ fn print_cpu_result(result: &stress::CpuTestResult, text: &Text) -> (bool, Vec<S
     let freq_start_str = format!("{:.2} GHz", result.frequency_start.current_ghz);
     let freq_end_str = format!("{:.2} GHz", result.frequency_end.current_ghz);
     let freq_drop_str = if result.freq_drop_pct > 1.0 {
-        format!("(-{:.0}%)", result.freq_drop_pct)
+        format!("(-{:.0}% / max {:.2} GHz)", result.freq_drop_pct, result.frequency_max_observed as f64 / 1000.0)
     } else {
         String::new()
     };
 
-    let (status_icon, healthy, issues) = match &result.health {
-        HealthStatus::Healthy => ("✅", true, vec![]),
-        HealthStatus::IssuesDetected(issues) => ("⚠️", false, issues.clone()),
-        HealthStatus::Failed(msg) => ("❌", false, vec![msg.clone()]),
-    };
-
-    // Calculate header padding
-    let header_text = text.cpu_health_check();
-    let header_len = header_text.chars().count();
-    let header_padding = 52 - header_len - 4; // 4 for emoji + spaces
-
-    println!("┌──────────────────────────────────────────────────────────┐");
-    println!("│ 🧠 {} {:>width$} │", header_text, status_icon, width = header_padding + 2);
-    println!("├──────────────────────────────────────────────────────────┤");
+    let mut table = fmt::report::ResultTable::new("🧠", text.cpu_health_check(), &result.health);
     // Hardware info
-    println!("{}", table_row(text.cpu(), &result.cpu_model));
-    println!("{}", table_row(text.cores_label(), &format!("{}", result.cpu_cores)));
-    println!("{}", table_row(text.operations(), &ops_str));
-    println!("{}", table_row(text.ops_per_sec(), &ops_sec_str));
-    println!("{}", table_row(text.avg_op_time(), &time_str));
-    println!("{}", table_row(text.variance(), &var_str));
-    println!("{}", table_row(text.temperature(), &temp_str));
+    table.row(text.cpu(), &result.cpu_model);
+    table.row(text.cores_label(), &format!("{}", result.cpu_cores));
+    table.row(text.cpu_workload(), result.workload.name());
+    if let Some(kb) = result.working_set_kb {
+        table.row(text.cpu_working_set(), &format!("{} KB", kb));
+    }
+    table.row(text.operations(), &ops_str);
+    table.row(text.ops_per_sec(), &ops_sec_str);
+    // Baselines are for the prime workload; unknown models skip the comparison
+    let expected = hw::deep::cpu::expected_ops_range(&result.cpu_model)
+        .filter(|_| result.workload == stress::CpuWorkload::Primes);
+    if let Some((low, high)) = expected {
+        let threads = result.parallel_threads() as f64;
+        let (low, high) = (low * threads, high * threads);
+        let position = if result.ops_per_second < low {
+            text.perf_below_range()
+        } else if result.ops_per_second > high {
+            text.perf_above_range()
+        } else {
+            text.perf_within_range()
+        };
+        table.row(text.expected_ops_range(), &format!("{:.0}-{:.0} ({})", low, high, position));
+    }
+    table.row(text.avg_op_time(), &time_str);
+    table.row(text.variance(), &var_str);
+    table.row(text.temperature(), &temp_str);
+    if let Some(rise) = fmt::format_temp_rise(result.temperature_idle, result.temperature_peak) {
+        table.row(text.temp_idle_to_peak(), &rise);
+    }
 
     // Frequency row is special (has arrow + optional drop)
     let freq_value = if freq_drop_str.is_empty() {
@@ -771,10 +1812,26 @@ fn print_cpu_result(result: &stress::CpuTestResult, text: &Text) -> (bool, Vec<S
     } else {
         format!("{} -> {} {}", freq_start_str, freq_end_str, freq_drop_str)
     };
-    println!("{}", table_row(text.frequency(), &freq_value));
-    println!("└──────────────────────────────────────────────────────────┘");
+    table.row(text.frequency(), &freq_value);
+    if let Some(boost) = result.boost_enabled {
+        let boost_str = if boost { text.boost_enabled() } else { text.boost_disabled() };
+        table.row(text.turbo_boost(), boost_str);
+    }
+    if let Some(point) = result.throttle_point {
+        let source = match point.source {
+            sensors::ThrottleSource::Reported => "TjMax",
+            sensors::ThrottleSource::Observed => text.throttle_observed(),
+        };
+        table.row(text.throttle_temp(), &format!("{:.0}°C ({})", point.temp_c, source));
+    }
+    if !result.fans.is_empty() {
+        table.row(text.fans(), &sensors::format_fan_speeds(&result.fans));
+    }
+    if result.boost_enabled == Some(false) {
+        table.note(text.boost_disabled_note().to_string());
+    }
 
-    (healthy, issues)
+    table
 }
 
 // Simple number formatter with thousands separator
@@ -809,63 +1866,31 @@ fn create_health_bar(percentage: u8) -> String {
     format!("{}{}{}{}{}", open, filled_char.repeat(filled), empty_char.repeat(empty), close, percentage)
 }
 
-/// Format a table row with proper alignment
-/// Box width is 52 chars internally (between │ borders)
-/// Format: │ label: value │ where value is right-aligned
-fn table_row(label: &str, value: &str) -> String {
-    const BOX_WIDTH: usize = 52;  // Internal width between borders
-    let label_len = label.chars().count();
-    let value_len = value.chars().count();
-
-    // Calculate padding: BOX_WIDTH - label_len - ": " - value_len
-    let padding = BOX_WIDTH.saturating_sub(label_len + 2 + value_len);
-    format!("│ {}: {:>padding$} │", label, value, padding = value_len + padding)
-}
-
-fn print_ram_result(result: &stress::RamTestResult, text: &Text) -> (bool, Vec<String>) {
-    // Reset any colors from progress bars before printing result box
-    print!("\x1b[0m");
-
-    let (status_icon, healthy, issues) = match &result.health {
-        HealthStatus::Healthy => ("✅", true, vec![]),
-        HealthStatus::IssuesDetected(issues) => ("⚠️", false, issues.clone()),
-        HealthStatus::Failed(msg) => ("❌", false, vec![msg.clone()]),
-    };
-
-    // Calculate header padding
-    let header_text = text.ram_health_check();
-    let header_len = header_text.chars().count();
-    let header_padding = 52 - header_len - 4; // 4 for emoji + spaces
-
-    println!("┌──────────────────────────────────────────────────────────┐");
-    println!("│ 💾 {} {:>width$} │", header_text, status_icon, width = header_padding + 2);
-    println!("├──────────────────────────────────────────────────────────┤");
+fn ram_result_table(result: &stress::RamTestResult, text: &Text) -> fmt::report::ResultTable {
+    let mut table = fmt::report::ResultTable::new("💾", text.ram_health_check(), &result.health);
     // Hardware info
-    println!("{}", table_row(text.ram(), &format!("{:.1} GB", result.ram_total_gb)));
-    println!("{}", table_row(text.tested_gb(), &format!("{:.1} GB", result.tested_gb)));
-    println!("{}", table_row(text.write_speed(), &format!("{:.1} GB/s", result.write_speed_gb_s)));
-    println!("{}", table_row(text.read_speed(), &format!("{:.1} GB/s", result.read_speed_gb_s)));
-    println!("{}", table_row(text.errors_detected(), &format!("{}", result.errors)));
-    println!("└──────────────────────────────────────────────────────────┘");
-
-    (healthy, issues)
+    table.row(text.ram(), &format!("{:.1} GB", result.ram_total_gb));
+    table.row(text.tested_gb(), &format!("{:.1} GB", result.tested_gb));
+    table.row(text.ram_access(), result.access.name());
+    table.row(text.write_speed(), &format!("{:.1} GB/s", result.write_speed_gb_s));
+    table.row(text.read_speed(), &format!("{:.1} GB/s", result.read_speed_gb_s));
+    if let Some(latency) = result.latency_ns {
+        table.row(text.ram_latency(), &format!("{:.1} ns", latency));
+    }
+    table.row(text.errors_detected(), &format!("{}", result.errors));
+    if result.is_partial_coverage() {
+        let note = if result.can_test_more() { text.ram_partial_coverage() } else { text.ram_partial_coverage_in_use() };
+        table.note(
+            note
+                .replace("{TESTED}", &format!("{:.1}", result.tested_gb))
+                .replace("{TOTAL}", &format!("{:.1}", result.ram_total_gb))
+                .replace("{PCT}", &format!("{:.0}", result.coverage_pct())),
+        );
+    }
+    table
 }
 
-fn print_disk_result(result: &stress::DiskTestResult, text: &Text) -> (bool, Vec<String>) {
-    // Reset any colors from progress bars before printing result box
-    print!("\x1b[0m");
-
-    let (status_icon, healthy, issues) = match &result.health {
-        HealthStatus::Healthy => ("✅", true, vec![]),
-        HealthStatus::IssuesDetected(issues) => ("⚠️", false, issues.clone()),
-        HealthStatus::Failed(msg) => ("❌", false, vec![msg.clone()]),
-    };
-
-    // Calculate header padding
-    let header_text = text.disk_health_check();
-    let header_len = header_text.chars().count();
-    let header_padding = 52 - header_len - 4; // 4 for emoji + spaces
-
+fn disk_result_table(result: &stress::DiskTestResult, text: &Text) -> fmt::report::ResultTable {
     let disk_type = if result.is_ssd { text.ssd() } else { text.hdd() };
     let size_str = if result.disk_size_gb >= 1000.0 {
         format!("{:.1} TB", result.disk_size_gb / 1024.0)
@@ -875,127 +1900,128 @@ fn print_disk_result(result: &stress::DiskTestResult, text: &Text) -> (bool, Vec
     let usage_str = format!("{:.0} GB / {:.0} GB", result.disk_used_gb, result.disk_size_gb);
     let avail_str = format!("{:.0} GB", result.disk_available_gb);
 
-    let has_smart = result.smart.is_some();
-    let verbose = has_smart;
-
-    println!("┌──────────────────────────────────────────────────────────┐");
-    println!("│ 💿 {} {:>width$} │", header_text, status_icon, width = header_padding + 2);
-    println!("├──────────────────────────────────────────────────────────┤");
+    let mut table = fmt::report::ResultTable::new("💿", text.disk_health_check(), &result.health);
     // Hardware info
-    println!("{}", table_row(text.disk_label(), &result.disk_name));
+    table.row(text.disk_label(), &result.disk_name);
     if let Some(ref device) = result.disk_device {
-        println!("{}", table_row(text.device(), device));
-    }
-    println!("{}", table_row(text.size(), &size_str));
-    println!("{}", table_row(text.usage(), &usage_str));
-    println!("{}", table_row(text.available(), &avail_str));
-    println!("{}", table_row(text.fs(), &result.disk_fs));
-    println!("{}", table_row(text.type_label(), disk_type));
-
-    // Verbose mode: Add separator and SMART section
-    if verbose {
-        println!("├──────────────────────────────────────────────────────────┤");
-        println!("{}", table_row(text.performance_test(), ""));
-        println!("{}", table_row(text.write_speed(), &format!("{:.1} MB/s", result.write_speed_mb_s)));
-        println!("{}", table_row(text.read_speed(), &format!("{:.1} MB/s", result.read_speed_mb_s)));
-        println!("{}", table_row(text.seek_time(), &format!("{:.1} ms", result.seek_time_ms)));
-        println!("{}", table_row(text.bad_sectors(), &format!("{}", result.bad_sectors)));
-
-        if let Some(ref smart) = result.smart {
-            println!("├──────────────────────────────────────────────────────────┤");
-            println!("{}", table_row(text.smart_health(), ""));
-
-            let status_str = match smart.status {
-                crate::stress::disk::smart::SmartStatus::Verified => "✅ Verified",
-                crate::stress::disk::smart::SmartStatus::Failing => "❌ Failing",
-                crate::stress::disk::smart::SmartStatus::Unknown => "? Unknown",
-            };
-            println!("{}", table_row(text.smart_status(), status_str));
+        table.row(text.device(), device);
+    }
+    table.row(text.size(), &size_str);
+    table.row(text.usage(), &usage_str);
+    table.row(text.available(), &avail_str);
+    table.row(text.fs(), &result.disk_fs);
+    table.row(text.type_label(), disk_type);
+    if result.is_ssd {
+        let trim_str = match (result.trim.supported, result.trim.enabled) {
+            (Some(false), _) => Some(text.trim_unsupported()),
+            (_, Some(true)) => Some(text.trim_on()),
+            (_, Some(false)) => Some(text.trim_off()),
+            _ => None,
+        };
+        if let Some(trim_str) = trim_str {
+            table.row(text.trim(), trim_str);
+        }
+    }
 
-            // Health percentage with bar
-            if let Some(pct) = smart.health_percentage {
-                let bar = create_health_bar(pct);
-                println!("{}", table_row(text.health(), &format!("{} {}", bar, pct)));
-            }
+    // Verbose mode (SMART collected): performance gets its own section with a heading
+    if result.smart.is_some() {
+        table.section();
+        table.row(text.performance_test(), "");
+    }
+    table.row(text.write_speed(), &format!("{:.1} MB/s", result.write_speed_mb_s));
+    if let (Some(peak), Some(sustained)) = (result.write_speed_peak_mb_s, result.write_speed_sustained_mb_s) {
+        table.row(text.write_speed_peak(), &format!("{:.1} MB/s", peak));
+        table.row(text.write_speed_sustained(), &format!("{:.1} MB/s", sustained));
+    }
+    if let Some(drop_pct) = result.cache_cliff_pct() {
+        table.note(text.disk_cache_cliff().replace("{PCT}", &format!("{:.0}", drop_pct)));
+    }
+    table.row(text.read_speed(), &format!("{:.1} MB/s", result.read_speed_mb_s));
+    table.row(text.seek_time(), &format!("{:.1} ms", result.seek_time_ms));
+    if result.random_read_iops > 0.0 {
+        table.row(text.random_read_iops(), &format!("{} IOPS", format_number(result.random_read_iops.round() as u64)));
+    }
+    table.row(text.bad_sectors(), &format!("{}", result.bad_sectors));
+    if result.read_retries > 0 {
+        table.row(text.read_retries(), &format!("{}", result.read_retries));
+    }
 
-            // SSD life left with bar
-            if let Some(life) = smart.ssd_life_left {
-                let bar = create_health_bar(life);
-                println!("{}", table_row(text.ssd_life(), &format!("{} {}", bar, life)));
-            }
+    if let Some(ref smart) = result.smart {
+        table.section();
+        table.row(text.smart_health(), "");
 
-            if let Some(temp) = smart.temperature_c {
-                println!("{}", table_row(text.temperature(), &format!("{:.0}°C", temp)));
-            }
-            if let Some(hours) = smart.power_on_hours {
-                println!("{}", table_row(text.power_on_hours(), &format!("{} hrs", hours)));
-            }
-            if let Some(cycles) = smart.power_cycle_count {
-                println!("{}", table_row(text.power_cycles(), &format!("{}", cycles)));
-            }
-            if let Some(ref model) = smart.model {
-                println!("{}", table_row(text.model(), model));
-            }
-            if let Some(ref serial) = smart.serial {
-                println!("{}", table_row(text.serial(), serial));
-            }
-            if let Some(ref firmware) = smart.firmware {
-                println!("{}", table_row(text.firmware(), firmware));
-            }
-            // Extended SMART attributes
-            if let Some(realloc) = smart.realloc_sectors {
-                if realloc > 0 {
-                    println!("{}", table_row(text.realloc_sectors(), &format!("{}", realloc)));
-                }
-            }
-            if let Some(pending) = smart.pending_sectors {
-                if pending > 0 {
-                    println!("{}", table_row(text.pending_sectors(), &format!("{}", pending)));
-                }
-            }
-            if let Some(events) = smart.reallocated_events {
-                if events > 0 {
-                    println!("{}", table_row(text.realloc_events(), &format!("{}", events)));
-                }
+        let status_str = match smart.status {
+            crate::stress::disk::smart::SmartStatus::Verified => "✅ Verified",
+            crate::stress::disk::smart::SmartStatus::Failing => "❌ Failing",
+            crate::stress::disk::smart::SmartStatus::Unknown => "? Unknown",
+        };
+        table.row(text.smart_status(), status_str);
+
+        // Health percentage with bar
+        if let Some(pct) = smart.health_percentage {
+            let bar = create_health_bar(pct);
+            table.row(text.health(), &format!("{} {}", bar, pct));
+        }
+
+        // SSD life left with bar
+        if let Some(life) = smart.ssd_life_left {
+            let bar = create_health_bar(life);
+            table.row(text.ssd_life(), &format!("{} {}", bar, life));
+        }
+
+        if let Some(temp) = smart.temperature_c {
+            table.row(text.temperature(), &format!("{:.0}°C", temp));
+        }
+        if let Some(hours) = smart.power_on_hours {
+            table.row(text.power_on_hours(), &format!("{} hrs", hours));
+        }
+        if let Some(cycles) = smart.power_cycle_count {
+            table.row(text.power_cycles(), &format!("{}", cycles));
+        }
+        if let Some(ref model) = smart.model {
+            table.row(text.model(), model);
+        }
+        if let Some(ref serial) = smart.serial {
+            table.row(text.serial(), serial);
+        }
+        if let Some(ref firmware) = smart.firmware {
+            table.row(text.firmware(), firmware);
+        }
+        // Extended SMART attributes
+        if let Some(realloc) = smart.realloc_sectors {
+            if realloc > 0 {
+                table.row(text.realloc_sectors(), &format!("{}", realloc));
             }
-            // Total bytes written/read
-            if let Some(lbas_written) = smart.total_lbas_written {
-                let tb_written = (lbas_written as f64 * 512.0) / (1024.0 * 1024.0 * 1024.0 * 1024.0);
-                println!("{}", table_row(text.total_written(), &format!("{:.1} TB", tb_written)));
+        }
+        if let Some(pending) = smart.pending_sectors {
+            if pending > 0 {
+                table.row(text.pending_sectors(), &format!("{}", pending));
             }
-            if let Some(lbas_read) = smart.total_lbas_read {
-                let tb_read = (lbas_read as f64 * 512.0) / (1024.0 * 1024.0 * 1024.0 * 1024.0);
-                println!("{}", table_row(text.total_read(), &format!("{:.1} TB", tb_read)));
+        }
+        if let Some(events) = smart.reallocated_events {
+            if events > 0 {
+                table.row(text.realloc_events(), &format!("{}", events));
             }
         }
-    } else {
-        // Normal mode: just show performance
-        println!("{}", table_row(text.write_speed(), &format!("{:.1} MB/s", result.write_speed_mb_s)));
-        println!("{}", table_row(text.read_speed(), &format!("{:.1} MB/s", result.read_speed_mb_s)));
-        println!("{}", table_row(text.seek_time(), &format!("{:.1} ms", result.seek_time_ms)));
-        println!("{}", table_row(text.bad_sectors(), &format!("{}", result.bad_sectors)));
+        // Total bytes written/read
+        if let Some(lbas_written) = smart.total_lbas_written {
+            let tb_written = (lbas_written as f64 * 512.0) / (1024.0 * 1024.0 * 1024.0 * 1024.0);
+            table.row(text.total_written(), &format!("{:.1} TB", tb_written));
+        }
+        if let Some(lbas_read) = smart.total_lbas_read {
+            let tb_read = (lbas_read as f64 * 512.0) / (1024.0 * 1024.0 * 1024.0 * 1024.0);
+            table.row(text.total_read(), &format!("{:.1} TB", tb_read));
+        }
     }
 
-    println!("└──────────────────────────────────────────────────────────┘");
+    if result.trim.needs_attention() {
+        table.note(text.trim_disabled_advisory().replace("{DISK}", &result.disk_name));
+    }
 
-    (healthy, issues)
+    table
 }
 
-fn print_gpu_result(result: &stress::GpuTestResult, text: &Text) -> (bool, Vec<String>) {
-    // Reset any colors from progress bars before printing result box
-    print!("\x1b[0m");
-
-    let (status_icon, healthy, issues) = match &result.health {
-        HealthStatus::Healthy => ("✅", true, vec![]),
-        HealthStatus::IssuesDetected(issues) => ("⚠️", false, issues.clone()),
-        HealthStatus::Failed(msg) => ("❌", false, vec![msg.clone()]),
-    };
-
-    // Calculate header padding
-    let header_text = text.gpu_health_check();
-    let header_len = header_text.chars().count();
-    let header_padding = 52 - header_len - 4; // 4 for emoji + spaces
-
+fn gpu_result_table(result: &stress::GpuTestResult, text: &Text) -> fmt::report::ResultTable {
     // Format VRAM
     let vram_str = if let Some(vram) = result.vram_gb {
         format!("{:.0} GB", vram)
@@ -1031,28 +2057,40 @@ fn print_gpu_result(result: &stress::GpuTestResult, text: &Text) -> (bool, Vec<S
         text.not_available().to_string()
     };
 
-    println!("┌──────────────────────────────────────────────────────────┐");
-    println!("│ 🎮 {} {:>width$} │", header_text, status_icon, width = header_padding + 2);
-    println!("├──────────────────────────────────────────────────────────┤");
+    let mut table = fmt::report::ResultTable::new("🎮", text.gpu_health_check(), &result.health);
     // Hardware info
-    println!("{}", table_row(text.model(), &result.gpu_model));
-    println!("{}", table_row(text.type_label(), &text.translate_gpu_type(&result.gpu_type)));
-    println!("{}", table_row(text.ram(), &vram_str));
-    println!("{}", table_row(text.temperature(), &temp_str));
+    table.row(text.model(), &result.gpu_model);
+    table.row(text.type_label(), &text.translate_gpu_type(&result.gpu_type));
+    table.row(text.ram(), &vram_str);
+    table.row(text.temperature(), &temp_str);
+    if let Some(rise) = fmt::format_temp_rise(result.temperature_start.as_ref().map(|t| t.current), result.temperature_max) {
+        table.row(text.temp_idle_to_peak(), &rise);
+    }
+    let compute_str = match (&result.backend, result.frames_dispatched) {
+        (Some(backend), Some(frames)) => match result.compute_frame_ms {
+            Some(ms) => format!("{} · {} {} · {:.1} ms/{}", backend, format_number(frames as u64), text.gpu_frames(), ms, text.gpu_frame()),
+            None => format!("{} · {} {}", backend, format_number(frames as u64), text.gpu_frames()),
+        },
+        _ => text.gpu_thermal_only().to_string(),
+    };
+    table.row(text.gpu_compute_load(), &compute_str);
+    if let Some(gflops) = result.compute_gflops.filter(|&g| g > 0.0) {
+        table.row(text.gpu_throughput(), &format!("~{:.0} GFLOPS", gflops));
+    }
 
     // Apple Silicon GPU metrics (verbose mode)
     if let Some(ref metrics) = result.apple_gpu_metrics {
-        println!("├──────────────────────────────────────────────────────────┤");
-        println!("{}", table_row(text.gpu_freq(), &metrics.frequency_mhz.map_or(text.not_available().to_string(), |f| format!("{} MHz", f))));
-        println!("{}", table_row(text.gpu_power(), &metrics.power_mw.map_or(text.not_available().to_string(), |p| format!("{} mW", p))));
-        println!("{}", table_row(text.gpu_usage(), &metrics.residency_pct.map_or(text.not_available().to_string(), |r| format!("{:.1}%", r))));
+        table.section();
+        table.row(text.gpu_freq(), &metrics.frequency_mhz.map_or(text.not_available().to_string(), |f| format!("{} MHz", f)));
+        table.row(text.gpu_power(), &metrics.power_mw.map_or(text.not_available().to_string(), |p| format!("{} mW", p)));
+        table.row(text.gpu_usage(), &metrics.residency_pct.map_or(text.not_available().to_string(), |r| format!("{:.1}%", r)));
         // Show GPU cores if available
         if let Some(cores) = metrics.gpu_cores {
-            println!("{}", table_row(text.gpu_cores(), &format!("{}", cores)));
+            table.row(text.gpu_cores(), &format!("{}", cores));
         }
         // Show Metal version if available
         if let Some(ref metal) = metrics.metal_version {
-            println!("{}", table_row(text.metal(), metal));
+            table.row(text.metal(), metal);
         }
         // Show thermal pressure if available
         if let Some(ref pressure) = metrics.thermal_pressure {
@@ -1064,17 +2102,51 @@ fn print_gpu_result(result: &stress::GpuTestResult, text: &Text) -> (bool, Vec<S
                 stress::gpu::ThermalPressure::Sleeping => "💤 Sleeping",
                 stress::gpu::ThermalPressure::Unknown => "?",
             };
-            println!("{}", table_row(text.thermal_state(), pressure_str));
+            table.row(text.thermal_state(), pressure_str);
         }
         // Show SMC temperature if different from powermetrics
         if let Some(smc_temp) = metrics.smc_temperature_c {
             if metrics.temperature_c.is_some() && Some(smc_temp) != metrics.temperature_c {
-                println!("{}", table_row(text.smc_temp(), &format!("{:.1}°C", smc_temp)));
+                table.row(text.smc_temp(), &format!("{:.1}°C", smc_temp));
             }
         }
     }
 
-    println!("└──────────────────────────────────────────────────────────┘");
+    // NVIDIA peak readings from nvidia-smi
+    if let Some(ref metrics) = result.nvidia_gpu_metrics {
+        table.section();
+        table.row(text.gpu_usage(), &metrics.utilization_pct.map_or(text.not_available().to_string(), |u| format!("{:.0}%", u)));
+        table.row(text.gpu_power(), &metrics.power_w.map_or(text.not_available().to_string(), |p| format!("{:.1} W", p)));
+        table.row(text.gpu_vram_used(), &metrics.memory_used_mb.map_or(text.not_available().to_string(), |mb| format!("{} MB", mb)));
+    }
+
+    // AMD peak readings from amdgpu sysfs
+    if let Some(ref metrics) = result.amd_gpu_metrics {
+        table.section();
+        table.row(text.gpu_freq(), &metrics.frequency_mhz.map_or(text.not_available().to_string(), |f| format!("{} MHz", f)));
+        table.row(text.gpu_power(), &metrics.power_w.map_or(text.not_available().to_string(), |p| format!("{:.1} W", p)));
+        table.row(text.gpu_usage(), &metrics.utilization_pct.map_or(text.not_available().to_string(), |u| format!("{:.0}%", u)));
+    }
+
+    table
+}
 
-    (healthy, issues)
+/// GPU sustained (thermal-soak) test result
+fn gpu_soak_result_table(result: &stress::gpu::soak::GpuSoakResult, text: &Text) -> fmt::report::ResultTable {
+    let fmt_temp = |t: Option<f32>| t.map_or_else(|| text.not_available().to_string(), |t| format!("{:.1}°C", t));
+    let stable_str = match result.time_to_stable_secs {
+        Some(secs) => format!("{}s", secs),
+        None => text.gpu_not_stabilized().to_string(),
+    };
+
+    let mut table = fmt::report::ResultTable::new("🎮", text.gpu_soak_health_check(), &result.health);
+    table.row(text.model(), &result.gpu_model);
+    table.row(text.steady_state_temp(), &fmt_temp(result.steady_temp_c));
+    table.row(text.time_to_stabilize(), &stable_str);
+    table.row(text.temperature(), &fmt_temp(result.max_temp_c));
+    table.row(text.duration(), &format!("{}s", result.elapsed_secs));
+    if !result.compute_active && result.max_temp_c.is_some() {
+        table.row(text.gpu_load(), text.gpu_monitoring_only());
+    }
+    table
 }
@@ -2,16 +2,48 @@
 // Provides OS-specific implementations using Strategy Pattern
 
 use std::fmt;
+#[cfg(not(target_os = "linux"))]
+use std::process::Command;
+
+#[cfg(not(target_os = "linux"))]
+use crate::profile::TimedOutput;
 
 /// Platform trait for OS-specific operations
-pub trait Platform: fmt::Display {}
+pub trait Platform: fmt::Display {
+    /// Kernel release, e.g. "6.8.0-45-generic" (Linux), "23.5.0" (Darwin), "10.0.22631.4317" (NT)
+    fn kernel_version(&self) -> Option<String>;
+
+    /// Marketing OS version, e.g. "Ubuntu 22.04.4 LTS", "macOS 14.5"
+    fn os_version(&self) -> Option<String>;
+
+}
+
+/// Platform name plus versions, e.g. "Linux (Ubuntu 22.04.4 LTS, kernel 6.8.0-45-generic)"
+pub fn describe(name: &str, os_version: Option<&str>, kernel_version: Option<&str>) -> String {
+    let details: Vec<String> = os_version.map(str::to_string).into_iter()
+        .chain(kernel_version.map(|k| format!("kernel {}", k)))
+        .collect();
+    if details.is_empty() {
+        name.to_string()
+    } else {
+        format!("{} ({})", name, details.join(", "))
+    }
+}
 
 /// macOS platform implementation
 #[cfg(target_os = "macos")]
 pub struct MacOS;
 
 #[cfg(target_os = "macos")]
-impl Platform for MacOS {}
+impl Platform for MacOS {
+    fn kernel_version(&self) -> Option<String> {
+        command_stdout("sysctl", &["-n", "kern.osrelease"])
+    }
+
+    fn os_version(&self) -> Option<String> {
+        command_stdout("sw_vers", &["-productVersion"]).map(|v| format!("macOS {}", v))
+    }
+}
 
 #[cfg(target_os = "macos")]
 impl fmt::Display for MacOS {
@@ -25,7 +57,18 @@ impl fmt::Display for MacOS {
 pub struct Windows;
 
 #[cfg(target_os = "windows")]
-impl Platform for Windows {}
+impl Platform for Windows {
+    fn kernel_version(&self) -> Option<String> {
+        command_stdout("cmd", &["/c", "ver"]).and_then(|out| parse_windows_ver(&out))
+    }
+
+    fn os_version(&self) -> Option<String> {
+        // Windows 11 still reports 10.0, the build number tells them apart
+        let kernel = self.kernel_version()?;
+        let build: u32 = kernel.split('.').nth(2)?.parse().ok()?;
+        Some(if build >= 22000 { "Windows 11" } else { "Windows 10" }.to_string())
+    }
+}
 
 #[cfg(target_os = "windows")]
 impl fmt::Display for Windows {
@@ -39,7 +82,15 @@ impl fmt::Display for Windows {
 pub struct Linux;
 
 #[cfg(target_os = "linux")]
-impl Platform for Linux {}
+impl Platform for Linux {
+    fn kernel_version(&self) -> Option<String> {
+        std::fs::read_to_string("/proc/version").ok().and_then(|v| parse_proc_version(&v))
+    }
+
+    fn os_version(&self) -> Option<String> {
+        std::fs::read_to_string("/etc/os-release").ok().and_then(|v| parse_os_release(&v))
+    }
+}
 
 #[cfg(target_os = "linux")]
 impl fmt::Display for Linux {
@@ -48,6 +99,42 @@ impl fmt::Display for Linux {
     }
 }
 
+/// Trimmed stdout of a command, None if it failed or printed nothing
+#[cfg(not(target_os = "linux"))]
+fn command_stdout(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).timed_output().ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !stdout.is_empty()).then_some(stdout)
+}
+
+/// Kernel release from /proc/version ("Linux version 6.8.0-45-generic (buildd@...) ...")
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn parse_proc_version(content: &str) -> Option<String> {
+    let mut words = content.split_whitespace();
+    match (words.next(), words.next(), words.next()) {
+        (Some("Linux"), Some("version"), Some(release)) => Some(release.to_string()),
+        _ => None,
+    }
+}
+
+/// PRETTY_NAME from /etc/os-release (PRETTY_NAME="Ubuntu 22.04.4 LTS")
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn parse_os_release(content: &str) -> Option<String> {
+    content
+        .lines()
+        .find_map(|line| line.strip_prefix("PRETTY_NAME="))
+        .map(|v| v.trim().trim_matches('"').to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Version from `ver` output ("Microsoft Windows [Version 10.0.22631.4317]")
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+pub fn parse_windows_ver(output: &str) -> Option<String> {
+    let start = output.find("Version ")? + "Version ".len();
+    let version = output[start..].split(']').next()?.trim();
+    (!version.is_empty()).then(|| version.to_string())
+}
+
 /// Detect current platform at runtime
 pub fn detect() -> Box<dyn Platform> {
     #[cfg(target_os = "macos")]
@@ -62,3 +149,26 @@ pub fn detect() -> Box<dyn Platform> {
     #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
     compile_error!("Unsupported platform");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_versions() {
+        let proc_version = "Linux version 6.8.0-45-generic (buildd@lcy02-amd64-115) (x86_64-linux-gnu-gcc-13) #45-Ubuntu SMP\n";
+        assert_eq!(parse_proc_version(proc_version).as_deref(), Some("6.8.0-45-generic"));
+        assert_eq!(parse_proc_version(""), None);
+
+        let os_release = "NAME=\"Ubuntu\"\nVERSION_ID=\"22.04\"\nPRETTY_NAME=\"Ubuntu 22.04.4 LTS\"\n";
+        assert_eq!(parse_os_release(os_release).as_deref(), Some("Ubuntu 22.04.4 LTS"));
+        assert_eq!(parse_os_release("NAME=Arch\n"), None);
+
+        let ver = "\r\nMicrosoft Windows [Version 10.0.22631.4317]\r\n";
+        assert_eq!(parse_windows_ver(ver).as_deref(), Some("10.0.22631.4317"));
+        assert_eq!(parse_windows_ver("garbage"), None);
+
+        assert_eq!(describe("Linux", Some("Ubuntu 22.04.4 LTS"), Some("6.8.0")), "Linux (Ubuntu 22.04.4 LTS, kernel 6.8.0)");
+        assert_eq!(describe("Windows", None, None), "Windows");
+    }
+}
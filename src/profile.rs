@@ -0,0 +1,156 @@
+// Detection profiling module
+// Times every hardware detector and external command to find slow startup probes (--profile-detection)
+
+use std::io;
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::hw::deep::get_platform_probe;
+use crate::hw::{CpuInfo, DiskInfo, GpuInfo, RamInfo};
+use crate::sensors::{get_boost_enabled, get_cpu_temp};
+use crate::stress::disk::smart::get_smart_data;
+use crate::stress::disk::trim::get_trim_status;
+use crate::stress::gpu::get_gpu_temp;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static TIMINGS: Mutex<Vec<Timing>> = Mutex::new(Vec::new());
+
+/// A single timed detector or external command
+#[derive(Debug, Clone)]
+pub struct Timing {
+    pub label: String,
+    pub duration: Duration,
+    /// External command (true) or detector function (false)
+    pub is_command: bool,
+}
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+fn record(label: String, duration: Duration, is_command: bool) {
+    if let Ok(mut timings) = TIMINGS.lock() {
+        timings.push(Timing { label, duration, is_command });
+    }
+}
+
+/// Take all recorded timings, clearing the list
+pub fn take_timings() -> Vec<Timing> {
+    TIMINGS.lock().map(|mut t| std::mem::take(&mut *t)).unwrap_or_default()
+}
+
+/// Run a detector, recording its duration when profiling is enabled
+pub fn timed<T>(label: &str, f: impl FnOnce() -> T) -> T {
+    if !enabled() {
+        return f();
+    }
+    let start = Instant::now();
+    let value = f();
+    record(label.to_string(), start.elapsed(), false);
+    value
+}
+
+/// `Command::output()` that records its duration when profiling is enabled
+pub trait TimedOutput {
+    fn timed_output(&mut self) -> io::Result<Output>;
+}
+
+impl TimedOutput for Command {
+    fn timed_output(&mut self) -> io::Result<Output> {
+        if !enabled() {
+            return self.output();
+        }
+        let start = Instant::now();
+        let output = self.output();
+        record(command_label(self), start.elapsed(), true);
+        output
+    }
+}
+
+/// Program plus its first argument, e.g. "system_profiler SPDisplaysDataType"
+fn command_label(cmd: &Command) -> String {
+    let program = cmd.get_program().to_string_lossy().to_string();
+    match cmd.get_args().next() {
+        Some(arg) => format!("{} {}", program, arg.to_string_lossy()),
+        None => program,
+    }
+}
+
+/// Run all detection paths (no stress tests) and print the timing breakdown
+pub fn run_profile_detection() {
+    println!();
+    println!("============================================================");
+    println!("⏱️  PCHECKER DETECTION PROFILE - v0.3.0");
+    println!("============================================================");
+    println!();
+
+    set_enabled(true);
+    let start = Instant::now();
+
+    let cpu = timed("CpuInfo::new", CpuInfo::new);
+    timed("RamInfo::new", RamInfo::new);
+    timed("RamInfo::with_pressure", RamInfo::with_pressure);
+    let disks = timed("DiskInfo::new", DiskInfo::new);
+    timed("GpuInfo::new", GpuInfo::new);
+
+    let probe = timed("get_platform_probe", get_platform_probe);
+    timed("probe.get_cache_info", || probe.get_cache_info());
+    timed("probe.get_instruction_sets", || probe.get_instruction_sets());
+    timed("probe.get_tdp", || probe.get_tdp(&cpu.model));
+    timed("probe.get_dimm_slots", || probe.get_dimm_slots());
+    timed("probe.get_disk_health", || probe.get_disk_health());
+    timed("probe.get_gpu_driver", || probe.get_gpu_driver());
+    timed("probe.get_pcie_link", || probe.get_pcie_link());
+
+    timed("get_cpu_temp", get_cpu_temp);
+    timed("get_gpu_temp", get_gpu_temp);
+    timed("get_boost_enabled", get_boost_enabled);
+    for disk in &disks {
+        timed(&format!("get_smart_data {}", disk.mount_point), || get_smart_data(&disk.mount_point, false));
+        timed(&format!("get_trim_status {}", disk.mount_point), || get_trim_status(&disk.mount_point));
+    }
+
+    let total = start.elapsed();
+    set_enabled(false);
+
+    print_breakdown(&take_timings(), total);
+}
+
+fn print_breakdown(timings: &[Timing], total: Duration) {
+    for (title, is_command) in [("Detectors:", false), ("External commands:", true)] {
+        let mut group: Vec<&Timing> = timings.iter().filter(|t| t.is_command == is_command).collect();
+        if group.is_empty() {
+            continue;
+        }
+        group.sort_by_key(|t| std::cmp::Reverse(t.duration));
+
+        println!("{}", title);
+        for t in group {
+            println!("   {:<44} {:>7.2}s", t.label, t.duration.as_secs_f64());
+        }
+        println!();
+    }
+
+    println!("============================================================");
+    println!("Total detection time: {:.2}s", total.as_secs_f64());
+    println!("============================================================");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_label() {
+        let mut cmd = Command::new("system_profiler");
+        cmd.args(["SPDisplaysDataType", "-json"]);
+        assert_eq!(command_label(&cmd), "system_profiler SPDisplaysDataType");
+        assert_eq!(command_label(&Command::new("vm_stat")), "vm_stat");
+    }
+}
@@ -1,9 +1,127 @@
-// Interactive prompt module for language selection
+// Interactive prompt module
+// Language selection and a reusable choice reader that never blocks a non-interactive run
 
-use crate::lang::Language;
+use std::io::{self, IsTerminal, Write};
 
-#[allow(dead_code)]
-pub fn select_language_silent() -> Language {
-    // For non-interactive mode (future: --lang flag support)
-    Language::Vietnamese
+use crate::lang::{Language, Text};
+
+/// Prompts are only shown when stdin is a terminal (not in CI, pipes or with stdin closed)
+pub fn interactive() -> bool {
+    io::stdin().is_terminal()
+}
+
+/// Print `prompt` and read lines until `parse` accepts one
+/// Returns `default` without prompting when stdin is not a terminal, and on EOF or read errors
+pub fn read_choice_or_default<T>(prompt: &str, invalid: &str, default: T, parse: impl Fn(&str) -> Option<T>) -> T {
+    if !interactive() {
+        return default;
+    }
+
+    let stdin = io::stdin();
+    let mut input = String::new();
+    loop {
+        print!("{}", prompt);
+        io::stdout().flush().unwrap();
+
+        input.clear();
+        match stdin.read_line(&mut input) {
+            Ok(0) | Err(_) => return default,
+            Ok(_) => match parse(input.trim()) {
+                Some(choice) => return choice,
+                None => println!("{}", invalid),
+            },
+        }
+    }
+}
+
+/// Map a menu number or language code to a language ("2", "en", "english", ...)
+pub fn parse_language(input: &str) -> Option<Language> {
+    match input.to_lowercase().as_str() {
+        "1" | "vi" | "vietnamese" => Some(Language::Vietnamese),
+        "2" | "en" | "english" => Some(Language::English),
+        "3" | "fr" | "french" => Some(Language::French),
+        "4" | "es" | "spanish" => Some(Language::Spanish),
+        _ => None,
+    }
+}
+
+/// Entry of the --interactive main menu
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MenuChoice {
+    Info,
+    Cpu,
+    Ram,
+    Disk,
+    Gpu,
+    FullAuto,
+    Torture,
+    Quit,
+}
+
+/// Map a menu number to its entry ("0", "q" or "quit" leave the menu)
+pub fn parse_menu_choice(input: &str) -> Option<MenuChoice> {
+    match input.to_lowercase().as_str() {
+        "1" => Some(MenuChoice::Info),
+        "2" => Some(MenuChoice::Cpu),
+        "3" => Some(MenuChoice::Ram),
+        "4" => Some(MenuChoice::Disk),
+        "5" => Some(MenuChoice::Gpu),
+        "6" => Some(MenuChoice::FullAuto),
+        "7" => Some(MenuChoice::Torture),
+        "0" | "q" | "quit" => Some(MenuChoice::Quit),
+        _ => None,
+    }
+}
+
+/// Language selection menu (English when not interactive, e.g. in scripts or CI)
+pub fn select_language() -> Language {
+    if !interactive() {
+        return Language::English;
+    }
+
+    // Create a default Text for Vietnamese (default language)
+    let default_text = Text::new(Language::Vietnamese);
+
+    println!();
+    println!("============================================================");
+    println!("🤖 PCHECKER v0.2.0");
+    println!("============================================================");
+    println!();
+    println!("{}", default_text.language_select_prompt());
+    println!();
+    println!("  [1] {}", default_text.language_option_vi());
+    println!("  [2] {}", default_text.language_option_en());
+    println!("  [3] {}", default_text.language_option_fr());
+    println!("  [4] {}", default_text.language_option_es());
+    println!();
+
+    read_choice_or_default(
+        default_text.language_choice_prompt(),
+        default_text.language_invalid_choice(),
+        Language::Vietnamese,
+        parse_language,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_language() {
+        assert_eq!(parse_language("2"), Some(Language::English));
+        assert_eq!(parse_language("FR"), Some(Language::French));
+        assert_eq!(parse_language("spanish"), Some(Language::Spanish));
+        assert_eq!(parse_language("de"), None);
+    }
+
+    #[test]
+    fn test_parse_menu_choice() {
+        assert_eq!(parse_menu_choice("1"), Some(MenuChoice::Info));
+        assert_eq!(parse_menu_choice("7"), Some(MenuChoice::Torture));
+        assert_eq!(parse_menu_choice("Q"), Some(MenuChoice::Quit));
+        assert_eq!(parse_menu_choice("0"), Some(MenuChoice::Quit));
+        assert_eq!(parse_menu_choice("8"), None);
+        assert_eq!(parse_menu_choice(""), None);
+    }
 }
@@ -0,0 +1,189 @@
+// Self-test module
+// Runs pchecker's internal consistency checks against known inputs (--selftest)
+
+use crate::hw::deep::estimate_tdp_from_model;
+use crate::lang::{missing_translations, Language, Text};
+use crate::sensors::CpuTemp;
+use crate::stress::HealthStatus;
+use crate::stress::cpu::evaluate_cpu_health;
+use crate::stress::disk::evaluate_disk_health;
+use crate::stress::gpu::evaluate_gpu_health;
+use crate::stress::ram::evaluate_ram_health;
+
+/// Result of a single self-test check
+pub struct CheckResult {
+    pub name: &'static str,
+    pub failures: Vec<String>,
+}
+
+impl CheckResult {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Run all checks and print a report, returns true if everything passed
+pub fn run_selftest() -> bool {
+    println!();
+    println!("============================================================");
+    println!("🔧 PCHECKER SELF-TEST - v0.3.0");
+    println!("============================================================");
+    println!();
+
+    let results = run_checks();
+    for result in &results {
+        if result.passed() {
+            println!("✅ {}", result.name);
+        } else {
+            println!("❌ {}", result.name);
+            for failure in &result.failures {
+                println!("   • {}", failure);
+            }
+        }
+    }
+
+    let failed = results.iter().filter(|r| !r.passed()).count();
+    println!();
+    println!("============================================================");
+    if failed == 0 {
+        println!("✅ All {} checks passed", results.len());
+    } else {
+        println!("❌ {} of {} checks failed", failed, results.len());
+    }
+    println!("============================================================");
+
+    failed == 0
+}
+
+/// Run all checks without printing
+pub fn run_checks() -> Vec<CheckResult> {
+    vec![
+        check("CPU health verdicts", check_cpu_health),
+        check("RAM health verdicts", check_ram_health),
+        check("Disk health verdicts", check_disk_health),
+        check("GPU health verdicts", check_gpu_health),
+        check("TDP database", check_tdp_database),
+        check("Number formatter", check_number_format),
+        check("Table alignment", check_table_alignment),
+        check("Translations", check_translations),
+    ]
+}
+
+fn check(name: &'static str, f: fn(&mut Vec<String>)) -> CheckResult {
+    let mut failures = Vec::new();
+    f(&mut failures);
+    CheckResult { name, failures }
+}
+
+/// Short verdict name for failure messages
+fn verdict(status: &HealthStatus) -> &'static str {
+    match status {
+        HealthStatus::Healthy => "Healthy",
+        HealthStatus::IssuesDetected(_) => "IssuesDetected",
+        HealthStatus::Failed(_) => "Failed",
+    }
+}
+
+fn expect(failures: &mut Vec<String>, case: &str, got: &HealthStatus, want: &str) {
+    if verdict(got) != want {
+        failures.push(format!("{}: expected {}, got {}", case, want, verdict(got)));
+    }
+}
+
+fn check_cpu_health(failures: &mut Vec<String>) {
+    let cool = CpuTemp { current: 60.0 };
+    let hot = CpuTemp { current: 90.0 };
+    let overheating = CpuTemp { current: 100.0 };
+
+    expect(failures, "normal run", &evaluate_cpu_health(true, 10.0, Some(&cool), 0.0), "Healthy");
+    expect(failures, "crashed thread", &evaluate_cpu_health(false, 10.0, Some(&cool), 0.0), "Failed");
+    expect(failures, "90°C", &evaluate_cpu_health(true, 10.0, Some(&hot), 0.0), "IssuesDetected");
+    expect(failures, "100°C", &evaluate_cpu_health(true, 10.0, Some(&overheating), 0.0), "Failed");
+    expect(failures, "20% throttle", &evaluate_cpu_health(true, 10.0, None, 20.0), "IssuesDetected");
+    expect(failures, "extreme variance", &evaluate_cpu_health(true, 250.0, None, 0.0), "Failed");
+}
+
+fn check_ram_health(failures: &mut Vec<String>) {
+    expect(failures, "normal speeds", &evaluate_ram_health(8.0, 15.0, 20.0, 0), "Healthy");
+    expect(failures, "memory errors", &evaluate_ram_health(8.0, 15.0, 20.0, 1), "Failed");
+    expect(failures, "allocation failed", &evaluate_ram_health(0.0, 15.0, 20.0, 0), "Failed");
+    expect(failures, "extremely slow", &evaluate_ram_health(8.0, 0.1, 0.1, 0), "Failed");
+}
+
+fn check_disk_health(failures: &mut Vec<String>) {
+    let text = Text::new(Language::English);
+    expect(failures, "fast SSD", &evaluate_disk_health(1000.0, 2000.0, 0.1, 0, true, &text), "Healthy");
+    expect(failures, "bad sectors", &evaluate_disk_health(1000.0, 2000.0, 0.1, 3, true, &text), "Failed");
+    expect(failures, "slow SSD read", &evaluate_disk_health(1000.0, 10.0, 0.1, 0, true, &text), "Failed");
+    expect(failures, "normal HDD", &evaluate_disk_health(120.0, 150.0, 12.0, 0, false, &text), "Healthy");
+}
+
+fn check_gpu_health(failures: &mut Vec<String>) {
+    expect(failures, "70°C", &evaluate_gpu_health(Some(70.0), false), "Healthy");
+    expect(failures, "90°C", &evaluate_gpu_health(Some(90.0), false), "IssuesDetected");
+    expect(failures, "100°C", &evaluate_gpu_health(Some(100.0), false), "Failed");
+    expect(failures, "Apple Silicon without sensor", &evaluate_gpu_health(None, true), "Healthy");
+}
+
+fn check_tdp_database(failures: &mut Vec<String>) {
+    let cases = [
+        ("Intel Core i7-1165G7", Some(15)),
+        ("Intel Core i7-11800H", Some(45)),
+        ("Apple M2 Max", Some(30)),
+        ("Unknown CPU Model XYZ", None),
+    ];
+    for (model, want) in cases {
+        let got = estimate_tdp_from_model(model);
+        if got != want {
+            failures.push(format!("{}: expected {:?}, got {:?}", model, want, got));
+        }
+    }
+}
+
+fn check_number_format(failures: &mut Vec<String>) {
+    let cases = [(0, "0"), (999, "999"), (1000, "1,000"), (1234567, "1,234,567")];
+    for (n, want) in cases {
+        let got = crate::fmt::format_number(n);
+        if got != want {
+            failures.push(format!("format_number({}): expected {}, got {}", n, want, got));
+        }
+        let got = crate::format_number(n);
+        if got != want {
+            failures.push(format!("report format_number({}): expected {}, got {}", n, want, got));
+        }
+    }
+}
+
+fn check_table_alignment(failures: &mut Vec<String>) {
+    // Every row must line up with the 60-char box border
+    let border_width = "┌──────────────────────────────────────────────────────────┐".chars().count();
+    for lang in Language::ALL {
+        let text = Text::new(lang);
+        let rows = [
+            crate::fmt::report::table_row(text.cpu(), "Apple M4 Pro"),
+            crate::fmt::report::table_row(text.temperature(), "61.2°C"),
+            crate::fmt::report::table_row(text.write_speed(), "1234.5 MB/s"),
+        ];
+        for row in rows {
+            if row.chars().count() != border_width {
+                failures.push(format!("{:?}: row width {} != {}: {}", lang, row.chars().count(), border_width, row));
+            }
+        }
+    }
+}
+
+fn check_translations(failures: &mut Vec<String>) {
+    failures.extend(missing_translations());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_checks_pass() {
+        for result in run_checks() {
+            assert!(result.passed(), "{}: {:?}", result.name, result.failures);
+        }
+    }
+}
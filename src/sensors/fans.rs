@@ -0,0 +1,190 @@
+// Fan speed reading
+// Linux: hwmon fan*_input, macOS: SMC F<n>Ac keys (apple-smc feature); other platforms report no fans
+
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+
+/// One fan's current speed
+#[derive(Debug, Clone, Serialize)]
+pub struct FanReading {
+    pub label: String,
+    pub rpm: u32,
+}
+
+/// Above this temperature every fan that spun earlier in the run should still be spinning
+pub const FAN_STUCK_TEMP_C: f32 = 70.0;
+
+/// Minimum rise over the idle reading before a stopped fan counts (rules out a machine that was already warm)
+pub const FAN_STUCK_MIN_RISE_C: f32 = 10.0;
+
+/// All fan speeds the platform exposes (empty when unknown, e.g. fanless or no access)
+pub fn get_fan_speeds() -> Vec<FanReading> {
+    #[cfg(target_os = "linux")]
+    {
+        get_linux_fan_speeds()
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        get_macos_fan_speeds()
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        Vec::new()
+    }
+}
+
+/// Fans seen spinning during one test run
+/// Unconnected headers and zero-RPM modes (e.g. amdgpu at idle) read 0 throughout, so only fans that spun are judged
+#[derive(Debug, Clone, Default)]
+pub struct FanHistory {
+    spun: BTreeSet<String>,
+}
+
+impl FanHistory {
+    pub fn from_readings(fans: &[FanReading]) -> Self {
+        let mut history = Self::default();
+        history.record(fans);
+        history
+    }
+
+    pub fn record(&mut self, fans: &[FanReading]) {
+        self.spun.extend(fans.iter().filter(|fan| fan.rpm > 0).map(|fan| fan.label.clone()));
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        self.spun.extend(other.spun.iter().cloned());
+    }
+
+    /// First fan that spun earlier in the run but reads 0 RPM in `fans`, while the temperature climbed from
+    /// `idle_c` past `FAN_STUCK_TEMP_C` by at least `FAN_STUCK_MIN_RISE_C` (no idle reading, no verdict)
+    pub fn stuck_fan<'a>(&self, fans: &'a [FanReading], idle_c: Option<f32>, peak_c: Option<f32>) -> Option<&'a FanReading> {
+        let (idle, peak) = (idle_c?, peak_c?);
+        if peak <= FAN_STUCK_TEMP_C || peak - idle < FAN_STUCK_MIN_RISE_C {
+            return None;
+        }
+        fans.iter().find(|fan| fan.rpm == 0 && self.spun.contains(&fan.label))
+    }
+}
+
+/// "1450, 1500 RPM"
+pub fn format_fan_speeds(fans: &[FanReading]) -> String {
+    let speeds: Vec<String> = fans.iter().map(|fan| fan.rpm.to_string()).collect();
+    format!("{} RPM", speeds.join(", "))
+}
+
+// =============================================================================
+// Linux implementation
+// =============================================================================
+
+#[cfg(target_os = "linux")]
+fn get_linux_fan_speeds() -> Vec<FanReading> {
+    let Ok(hwmons) = std::fs::read_dir("/sys/class/hwmon") else {
+        return Vec::new();
+    };
+
+    let mut fans = Vec::new();
+    for hwmon in hwmons.flatten() {
+        let dir = hwmon.path();
+        let chip = std::fs::read_to_string(dir.join("name")).unwrap_or_default();
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().into_owned();
+            let Some(index) = hwmon_fan_index(&file_name) else {
+                continue;
+            };
+            let Some(rpm) = std::fs::read_to_string(entry.path()).ok().and_then(|s| s.trim().parse().ok()) else {
+                continue;
+            };
+            // fanN_label is optional ("CPU Fan" on some boards)
+            let label = std::fs::read_to_string(dir.join(format!("fan{}_label", index)))
+                .map(|l| l.trim().to_string())
+                .unwrap_or_else(|_| format!("{} fan{}", chip.trim(), index));
+            fans.push(FanReading { label, rpm });
+        }
+    }
+    fans.sort_by(|a, b| a.label.cmp(&b.label));
+    fans
+}
+
+/// "fan2_input" -> "2"
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn hwmon_fan_index(file_name: &str) -> Option<&str> {
+    let index = file_name.strip_prefix("fan")?.strip_suffix("_input")?;
+    (!index.is_empty() && index.chars().all(|c| c.is_ascii_digit())).then_some(index)
+}
+
+// =============================================================================
+// macOS implementation
+// =============================================================================
+
+/// SMC exposes fans as F0Ac, F1Ac, ... (actual speed in RPM)
+#[cfg(all(target_os = "macos", feature = "apple-smc"))]
+fn get_macos_fan_speeds() -> Vec<FanReading> {
+    use smc::SMC;
+
+    let Ok(smc) = SMC::new() else {
+        return Vec::new();
+    };
+    (0..MAX_SMC_FANS)
+        .map_while(|i| smc.read_key::<f32>(format!("F{}Ac", i).as_str().into()).ok().map(|rpm| (i, rpm)))
+        .map(|(i, rpm)| FanReading { label: format!("Fan {}", i), rpm: rpm.max(0.0) as u32 })
+        .collect()
+}
+
+#[cfg(all(target_os = "macos", feature = "apple-smc"))]
+const MAX_SMC_FANS: u32 = 4;
+
+/// Stub for SMC fans when feature is not enabled
+#[cfg(all(target_os = "macos", not(feature = "apple-smc")))]
+fn get_macos_fan_speeds() -> Vec<FanReading> {
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hwmon_fan_index() {
+        assert_eq!(hwmon_fan_index("fan2_input"), Some("2"));
+        assert_eq!(hwmon_fan_index("fan10_input"), Some("10"));
+        assert_eq!(hwmon_fan_index("fan2_label"), None);
+        assert_eq!(hwmon_fan_index("fan_input"), None);
+        assert_eq!(hwmon_fan_index("temp1_input"), None);
+    }
+
+    #[test]
+    fn test_format_fan_speeds() {
+        let fan = |rpm| FanReading { label: "fan1".to_string(), rpm };
+        assert_eq!(format_fan_speeds(&[fan(1450), fan(1500)]), "1450, 1500 RPM");
+    }
+
+    #[test]
+    fn test_stuck_fan() {
+        let fan = |label: &str, rpm| FanReading { label: label.to_string(), rpm };
+        let idle = [fan("cpu_fan", 900), fan("sys_fan", 700), fan("nct6775 fan4", 0)];
+        let history = FanHistory::from_readings(&idle);
+        let under_load = [fan("cpu_fan", 1800), fan("sys_fan", 0), fan("nct6775 fan4", 0)];
+
+        assert_eq!(history.stuck_fan(&under_load, Some(45.0), Some(78.0)).map(|f| f.label.as_str()), Some("sys_fan"));
+        // Below the threshold, not a real rise from idle, or no idle reading
+        assert!(history.stuck_fan(&under_load, Some(45.0), Some(65.0)).is_none());
+        assert!(history.stuck_fan(&under_load, Some(72.0), Some(78.0)).is_none());
+        assert!(history.stuck_fan(&under_load, None, Some(78.0)).is_none());
+        assert!(history.stuck_fan(&under_load, Some(45.0), None).is_none());
+
+        // An unconnected header (or zero-RPM mode) that never spun is not a stuck fan
+        let unconnected_only = [fan("cpu_fan", 1800), fan("nct6775 fan4", 0)];
+        assert!(history.stuck_fan(&unconnected_only, Some(45.0), Some(78.0)).is_none());
+
+        // Spinning seen only in a later sample still counts
+        let mut history = FanHistory::default();
+        history.merge(&FanHistory::from_readings(&[fan("gpu_fan", 1200)]));
+        assert!(history.stuck_fan(&[fan("gpu_fan", 0)], Some(40.0), Some(80.0)).is_some());
+    }
+}
@@ -2,16 +2,17 @@
 // Cross-platform support for Linux, macOS, Windows
 
 use sysinfo::{System, RefreshKind, CpuRefreshKind};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use serde::Serialize;
 
 /// CPU frequency reading
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CpuFrequency {
     pub current_mhz: u64,
     pub current_ghz: f64,
     pub cores: usize,
-    #[allow(dead_code)]
-    pub per_core_mhz: HashMap<usize, u64>,
+    /// Ordered by core index so JSON output is stable between runs
+    pub per_core_mhz: BTreeMap<usize, u64>,
 }
 
 /// Get current CPU frequency from sysinfo
@@ -29,18 +30,22 @@ pub fn get_cpu_frequency() -> CpuFrequency {
             current_mhz: 0,
             current_ghz: 0.0,
             cores: 0,
-            per_core_mhz: HashMap::new(),
+            per_core_mhz: BTreeMap::new(),
         };
     }
 
     // Get per-core frequency
-    let mut per_core_mhz = HashMap::new();
+    let mut per_core_mhz = BTreeMap::new();
     for (i, cpu) in cpus.iter().enumerate() {
         per_core_mhz.insert(i, cpu.frequency());
     }
 
+    // Live per-core clocks from cpufreq where available
+    #[cfg(target_os = "linux")]
+    per_core_mhz.extend(get_linux_per_core_mhz(cores));
+
     // Get current frequency (average of all cores)
-    let total_mhz: u64 = cpus.iter().map(|c| c.frequency()).sum();
+    let total_mhz: u64 = per_core_mhz.values().sum();
     let current_mhz = total_mhz / cores as u64;
     let current_ghz = current_mhz as f64 / 1000.0;
 
@@ -52,10 +57,116 @@ pub fn get_cpu_frequency() -> CpuFrequency {
     }
 }
 
+/// Read each core's current clock from cpufreq (cores without cpufreq are skipped)
+#[cfg(target_os = "linux")]
+fn get_linux_per_core_mhz(cores: usize) -> BTreeMap<usize, u64> {
+    (0..cores)
+        .filter_map(|i| {
+            let path = format!("/sys/devices/system/cpu/cpu{}/cpufreq/scaling_cur_freq", i);
+            let mhz = parse_scaling_cur_freq(&std::fs::read_to_string(path).ok()?)?;
+            Some((i, mhz))
+        })
+        .collect()
+}
+
+/// Parse cpufreq `scaling_cur_freq` (kHz) into MHz
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_scaling_cur_freq(s: &str) -> Option<u64> {
+    let khz: u64 = s.trim().parse().ok()?;
+    (khz > 0).then_some(khz / 1000)
+}
+
+/// Check whether Turbo Boost / Precision Boost is enabled
+/// Returns None if the setting can't be read on this platform
+pub fn get_boost_enabled() -> Option<bool> {
+    #[cfg(target_os = "linux")]
+    {
+        get_linux_boost_enabled()
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        get_windows_boost_enabled()
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    {
+        None
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn get_linux_boost_enabled() -> Option<bool> {
+    // intel_pstate: no_turbo = 1 means boost is disabled
+    if let Ok(s) = std::fs::read_to_string("/sys/devices/system/cpu/intel_pstate/no_turbo") {
+        if let Some(no_turbo) = parse_sysfs_flag(&s) {
+            return Some(!no_turbo);
+        }
+    }
+
+    // acpi-cpufreq / amd-pstate: boost = 1 means boost is enabled
+    std::fs::read_to_string("/sys/devices/system/cpu/cpufreq/boost")
+        .ok()
+        .and_then(|s| parse_sysfs_flag(&s))
+}
+
+#[cfg(target_os = "windows")]
+fn get_windows_boost_enabled() -> Option<bool> {
+    use std::process::Command;
+    use crate::profile::TimedOutput;
+
+    // Processor performance boost mode of the active power plan
+    let output = Command::new("powercfg")
+        .args(["/query", "SCHEME_CURRENT", "SUB_PROCESSOR", "PERFBOOSTMODE"])
+        .timed_output()
+        .ok()?;
+    parse_powercfg_boost(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse a sysfs 0/1 flag
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_sysfs_flag(s: &str) -> Option<bool> {
+    match s.trim() {
+        "0" => Some(false),
+        "1" => Some(true),
+        _ => None,
+    }
+}
+
+/// Parse `powercfg /query ... PERFBOOSTMODE` output
+/// Boost mode 0 = Disabled, anything else = some form of boost enabled
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_powercfg_boost(output: &str) -> Option<bool> {
+    let line = output.lines().find(|l| l.contains("Current AC Power Setting Index"))?;
+    let hex = line.split(':').nth(1)?.trim().trim_start_matches("0x");
+    u32::from_str_radix(hex, 16).ok().map(|mode| mode != 0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_boost_settings() {
+        assert_eq!(parse_sysfs_flag("1\n"), Some(true));
+        assert_eq!(parse_sysfs_flag("0"), Some(false));
+        assert_eq!(parse_sysfs_flag(""), None);
+
+        let powercfg = "    Current AC Power Setting Index: 0x00000002\n    Current DC Power Setting Index: 0x00000001";
+        assert_eq!(parse_powercfg_boost(powercfg), Some(true));
+        let powercfg = "    Current AC Power Setting Index: 0x00000000";
+        assert_eq!(parse_powercfg_boost(powercfg), Some(false));
+        assert_eq!(parse_powercfg_boost("garbage"), None);
+    }
+
+    #[test]
+    fn test_parse_scaling_cur_freq() {
+        assert_eq!(parse_scaling_cur_freq("4200000\n"), Some(4200));
+        assert_eq!(parse_scaling_cur_freq("799999"), Some(799));
+        assert_eq!(parse_scaling_cur_freq("0"), None);
+        assert_eq!(parse_scaling_cur_freq("<unknown>"), None);
+    }
+
     #[test]
     fn test_get_cpu_frequency() {
         let freq = get_cpu_frequency();
@@ -4,7 +4,12 @@
 pub mod temp;
 pub mod frequency;
 pub mod monitor;
+pub mod throttle;
+pub mod registry;
+pub mod fans;
 
-pub use temp::{CpuTemp, get_cpu_temp, get_all_sensors};
-pub use frequency::{CpuFrequency, get_cpu_frequency};
+pub use temp::{CpuTemp, SensorReading, get_cpu_temp, get_all_sensors, get_core_temps, core_temp_spread};
+pub use frequency::{CpuFrequency, get_cpu_frequency, get_boost_enabled};
 pub use monitor::CpuMonitorHandle;
+pub use fans::{FanReading, get_fan_speeds, format_fan_speeds, FanHistory};
+pub use throttle::{ThrottleCause, ThrottlePoint, ThrottleSource, classify_throttle, detect_throttle_point};
@@ -0,0 +1,105 @@
+// Sensor registry - one sysinfo Components list, enumerated once and refreshed in place
+// Enumerating sensors (hwmon scan, SMC/WMI walk) is the slow part; re-reading known sensors is cheap
+
+use std::sync::Mutex;
+use sysinfo::Components;
+
+use super::temp::{CpuTemp, SensorReading};
+
+/// Readings outside this range are bogus (e.g. negative values on Apple Silicon)
+fn is_valid_temp(temp: f32) -> bool {
+    (-1000.0..=150.0).contains(&temp)
+}
+
+/// CPU die/package sensors, incl. Apple Silicon "PMU tdie" and calibration "tcal"
+fn is_cpu_sensor(label: &str) -> bool {
+    let lower = label.to_lowercase();
+    ["cpu", "tdie", "core", "package", "tcal"].iter().any(|key| lower.contains(key))
+}
+
+/// GPU sensors; PCH is included because on some boards it covers the integrated GPU
+fn is_gpu_sensor(label: &str) -> bool {
+    let lower = label.to_lowercase();
+    ["gpu", "graphic", "nvidia", "amd", "radeon", "intel", "gt", "pch"].iter().any(|key| lower.contains(key))
+}
+
+/// Highest reading among the sensors matching `matches`
+fn hottest(readings: &[SensorReading], matches: fn(&str) -> bool) -> Option<f32> {
+    readings.iter().filter(|r| matches(&r.label)).map(|r| r.temp).reduce(f32::max)
+}
+
+/// Temperature sensors found at startup; `refresh` re-reads their values
+pub struct SensorRegistry {
+    components: Components,
+}
+
+impl SensorRegistry {
+    /// Enumerate all hardware sensors (slow - done once)
+    pub fn new() -> Self {
+        Self { components: Components::new_with_refreshed_list() }
+    }
+
+    /// Re-read every known sensor without enumerating them again
+    pub fn refresh(&mut self) {
+        for component in self.components.list_mut() {
+            component.refresh();
+        }
+    }
+
+    /// All sensors with a valid reading
+    pub fn all(&self) -> Vec<SensorReading> {
+        self.components
+            .iter()
+            .filter_map(|comp| {
+                let temp = comp.temperature().filter(|&t| is_valid_temp(t))?;
+                Some(SensorReading { label: comp.label().to_string(), temp })
+            })
+            .collect()
+    }
+
+    /// Hottest CPU sensor
+    pub fn cpu_temp(&self) -> Option<CpuTemp> {
+        hottest(&self.all(), is_cpu_sensor).map(|current| CpuTemp { current })
+    }
+
+    /// Hottest GPU sensor (sensors only - nvidia-smi/amdgpu are tried first by stress::gpu::get_gpu_temp)
+    pub fn gpu_temp(&self) -> Option<f32> {
+        hottest(&self.all(), is_gpu_sensor)
+    }
+}
+
+/// Process-wide registry, enumerated on first use
+static SHARED: Mutex<Option<SensorRegistry>> = Mutex::new(None);
+
+/// Refresh the shared registry and read from it (one refresh per call, never a re-enumeration)
+pub fn with_fresh<T>(read: impl FnOnce(&SensorRegistry) -> T) -> T {
+    let mut guard = SHARED.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let registry = guard.get_or_insert_with(SensorRegistry::new);
+    registry.refresh();
+    read(registry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sensor_matching() {
+        assert!(is_cpu_sensor("coretemp Package id 0"));
+        assert!(is_cpu_sensor("PMU tdie4"));
+        assert!(!is_cpu_sensor("acpitz temp1"));
+        assert!(is_gpu_sensor("amdgpu edge"));
+        assert!(is_gpu_sensor("GPU MTR Temp Sensor1"));
+
+        let reading = |label: &str, temp| SensorReading { label: label.to_string(), temp };
+        let readings = [reading("Core 0", 55.0), reading("Core 1", 61.0), reading("amdgpu edge", 48.0)];
+        assert_eq!(hottest(&readings, is_cpu_sensor), Some(61.0));
+        assert_eq!(hottest(&readings, is_gpu_sensor), Some(48.0));
+        assert_eq!(hottest(&readings[..2], is_gpu_sensor), None);
+        assert!(!is_valid_temp(-1500.0) && !is_valid_temp(200.0) && is_valid_temp(45.0));
+
+        // Repeated reads reuse the enumerated list
+        let first = with_fresh(|r| r.all().len());
+        assert_eq!(with_fresh(|r| r.all().len()), first);
+    }
+}
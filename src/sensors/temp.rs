@@ -1,7 +1,9 @@
 // CPU temperature reading using sysinfo Components
 // Works on Linux, Windows (WMI), and macOS (x86 + Apple Silicon)
 
-use sysinfo::Components;
+use serde::Serialize;
+
+use super::registry::with_fresh;
 
 /// Sensor reading for verbose mode
 #[derive(Debug, Clone)]
@@ -12,29 +14,11 @@ pub struct SensorReading {
 
 /// Get all available temperature sensors (for verbose mode)
 pub fn get_all_sensors() -> Vec<SensorReading> {
-    let components = Components::new_with_refreshed_list();
-    let mut sensors = Vec::new();
-
-    for comp in components.iter() {
-        let label = comp.label().to_string();
-        let temp = comp.temperature();
-
-        // Filter out invalid readings
-        if let Some(t) = temp {
-            if t > -1000.0 && t < 150.0 {
-                sensors.push(SensorReading {
-                    label,
-                    temp: t,
-                });
-            }
-        }
-    }
-
-    sensors
+    with_fresh(|registry| registry.all())
 }
 
 /// CPU temperature reading
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CpuTemp {
     pub current: f32,
 }
@@ -43,50 +27,79 @@ pub struct CpuTemp {
 /// On Apple Silicon, reads from PMU tdie components (CPU die temp)
 /// Returns None if temperature not available
 pub fn get_cpu_temp() -> Option<CpuTemp> {
-    let components = Components::new_with_refreshed_list();
-
-    // Try to find CPU temperature from components
-    // Look for tdie (CPU die) or other CPU-related sensors
-    let mut cpu_temps: Vec<f32> = Vec::new();
+    with_fresh(|registry| registry.cpu_temp())
+}
 
-    for comp in components.iter() {
-        let label = comp.label();
-        let temp = comp.temperature()?;
+/// Idle spread above this suggests uneven cooler contact
+pub const IDLE_SPREAD_WARN_C: f32 = 15.0;
 
-        // Filter out invalid readings (negative temps on Apple Silicon)
-        if !(-1000.0..=150.0).contains(&temp) {
-            continue;
-        }
+/// Spread between the coolest and hottest core
+#[derive(Debug, Clone)]
+pub struct CoreTempSpread {
+    pub min: f32,
+    pub max: f32,
+    pub hottest_label: String,
+}
 
-        // Look for CPU-related temperature sensors
-        let label_lower = label.to_lowercase();
-        if label_lower.contains("cpu")
-            || label_lower.contains("tdie")
-            || label_lower.contains("core")
-            || label_lower.contains("package")
-            || label_lower.contains("tcal")  // Apple Silicon calibration
-        {
-            cpu_temps.push(temp);
-        }
+impl CoreTempSpread {
+    pub fn spread(&self) -> f32 {
+        self.max - self.min
     }
 
-    if cpu_temps.is_empty() {
-        return None;
+    pub fn is_excessive(&self) -> bool {
+        self.spread() >= IDLE_SPREAD_WARN_C
     }
+}
+
+/// Per-core / per-die sensors only (not package or ambient)
+/// Linux coretemp "Core 3", AMD k10temp "Tccd1", Apple Silicon "PMU tdie4"
+fn is_core_sensor(label: &str) -> bool {
+    let lower = label.to_lowercase();
+    let numbered_after = |prefix: &str| {
+        lower.split(prefix).skip(1).any(|rest| rest.trim_start().starts_with(|c: char| c.is_ascii_digit()))
+    };
+    numbered_after("core") || numbered_after("tccd") || numbered_after("tdie")
+}
 
-    // Use the highest temperature from CPU sensors
-    let temp = cpu_temps
-        .into_iter()
-        .reduce(|a, b| a.max(b))
-        .unwrap();
+/// Get per-core temperature readings
+pub fn get_core_temps() -> Vec<SensorReading> {
+    get_all_sensors().into_iter().filter(|s| is_core_sensor(&s.label)).collect()
+}
 
-    Some(CpuTemp { current: temp })
+/// Spread between coolest and hottest core, None with fewer than 2 core sensors
+pub fn core_temp_spread(readings: &[SensorReading]) -> Option<CoreTempSpread> {
+    if readings.len() < 2 {
+        return None;
+    }
+    let hottest = readings.iter().max_by(|a, b| a.temp.total_cmp(&b.temp))?;
+    let min = readings.iter().map(|r| r.temp).fold(f32::MAX, f32::min);
+
+    Some(CoreTempSpread {
+        min,
+        max: hottest.temp,
+        hottest_label: hottest.label.clone(),
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_core_temp_spread() {
+        assert!(is_core_sensor("coretemp Core 3"));
+        assert!(is_core_sensor("k10temp Tccd1"));
+        assert!(is_core_sensor("PMU tdie4"));
+        assert!(!is_core_sensor("coretemp Package id 0"));
+        assert!(!is_core_sensor("acpitz temp1"));
+
+        let reading = |label: &str, temp| SensorReading { label: label.to_string(), temp };
+        let spread = core_temp_spread(&[reading("Core 0", 40.0), reading("Core 1", 57.0), reading("Core 2", 42.0)]).unwrap();
+        assert_eq!(spread.hottest_label, "Core 1");
+        assert!(spread.is_excessive());
+        assert!(core_temp_spread(&[reading("Core 0", 40.0)]).is_none());
+    }
+
     #[test]
     fn test_get_cpu_temp() {
         let temp = get_cpu_temp();
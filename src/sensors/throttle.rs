@@ -0,0 +1,186 @@
+// CPU throttle point (TjMax) detection
+// Linux: coretemp temp*_crit / thermal zone passive trip points, otherwise inferred from test behavior
+
+use serde::Serialize;
+
+/// Where the throttle temperature came from
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum ThrottleSource {
+    /// Reported by the platform (coretemp crit or thermal trip point)
+    Reported,
+    /// Temperature at which frequency started dropping during the test
+    Observed,
+}
+
+/// Throttle temperature of the CPU
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ThrottlePoint {
+    pub temp_c: f32,
+    pub source: ThrottleSource,
+}
+
+/// What limited the clocks when the frequency dropped under load
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum ThrottleCause {
+    /// Dropped while hot (at or above MIN_THERMAL_THROTTLE_C)
+    Thermal,
+    /// Dropped while still cool: power limit or VRM
+    Power,
+}
+
+/// Frequency must fall this far below the peak to count as throttling
+const THROTTLE_DROP_PCT: f64 = 10.0;
+/// Ignore drops below this temperature (power limits, idle states - not thermal)
+const MIN_THERMAL_THROTTLE_C: f32 = 70.0;
+
+/// Get the platform-reported throttle temperature (TjMax), None if unknown
+pub fn get_tjmax() -> Option<f32> {
+    #[cfg(target_os = "linux")]
+    {
+        get_linux_tjmax()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn get_linux_tjmax() -> Option<f32> {
+    use std::fs;
+
+    // Intel coretemp / AMD k10temp: temp*_crit is TjMax
+    if let Ok(entries) = fs::read_dir("/sys/class/hwmon") {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = fs::read_to_string(path.join("name")).unwrap_or_default();
+            if !matches!(name.trim(), "coretemp" | "k10temp" | "zenpower") {
+                continue;
+            }
+            for i in 1..=8 {
+                if let Some(crit) = fs::read_to_string(path.join(format!("temp{}_crit", i)))
+                    .ok()
+                    .and_then(|s| parse_millidegrees(&s))
+                {
+                    return Some(crit);
+                }
+            }
+        }
+    }
+
+    // ACPI / SoC thermal zones: the passive trip point is where the kernel starts throttling
+    let entries = fs::read_dir("/sys/class/thermal").ok()?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let zone_type = fs::read_to_string(path.join("type")).unwrap_or_default().to_lowercase();
+        if !(zone_type.contains("x86_pkg") || zone_type.contains("cpu") || zone_type.contains("soc")) {
+            continue;
+        }
+        for i in 0..8 {
+            let trip_type = match fs::read_to_string(path.join(format!("trip_point_{}_type", i))) {
+                Ok(t) => t,
+                Err(_) => break,
+            };
+            if trip_type.trim() == "passive" {
+                if let Some(temp) = fs::read_to_string(path.join(format!("trip_point_{}_temp", i)))
+                    .ok()
+                    .and_then(|s| parse_millidegrees(&s))
+                {
+                    return Some(temp);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Parse a sysfs millidegree value ("100000" -> 100.0), rejecting nonsense
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_millidegrees(s: &str) -> Option<f32> {
+    let temp = s.trim().parse::<i64>().ok()? as f32 / 1000.0;
+    (50.0..=130.0).contains(&temp).then_some(temp)
+}
+
+/// Infer the throttle temperature from (temperature, frequency MHz) samples taken during load
+/// Returns the temperature at the first sample where frequency fell >10% below its peak
+pub fn infer_throttle_temp(samples: &[(f32, u64)]) -> Option<f32> {
+    let mut peak_mhz = 0u64;
+    for &(temp, mhz) in samples {
+        peak_mhz = peak_mhz.max(mhz);
+        if peak_mhz == 0 || temp < MIN_THERMAL_THROTTLE_C {
+            continue;
+        }
+        let drop_pct = (peak_mhz - mhz.min(peak_mhz)) as f64 / peak_mhz as f64 * 100.0;
+        if drop_pct > THROTTLE_DROP_PCT {
+            return Some(temp);
+        }
+    }
+    None
+}
+
+/// Classify throttling from (temperature, frequency MHz) samples
+/// Returns the cause and the hottest temperature seen while clocks were >10% below their peak
+pub fn classify_throttle(samples: &[(f32, u64)]) -> Option<(ThrottleCause, f32)> {
+    let mut peak_mhz = 0u64;
+    let mut throttled_temp: Option<f32> = None;
+    for &(temp, mhz) in samples {
+        peak_mhz = peak_mhz.max(mhz);
+        if peak_mhz == 0 {
+            continue;
+        }
+        let drop_pct = (peak_mhz - mhz.min(peak_mhz)) as f64 / peak_mhz as f64 * 100.0;
+        if drop_pct > THROTTLE_DROP_PCT {
+            throttled_temp = Some(throttled_temp.map_or(temp, |t| t.max(temp)));
+        }
+    }
+    throttled_temp.map(|temp| {
+        let cause = if temp >= MIN_THERMAL_THROTTLE_C { ThrottleCause::Thermal } else { ThrottleCause::Power };
+        (cause, temp)
+    })
+}
+
+/// Platform TjMax if known, otherwise the throttle point observed during the test
+pub fn detect_throttle_point(samples: &[(f32, u64)]) -> Option<ThrottlePoint> {
+    if let Some(temp_c) = get_tjmax() {
+        return Some(ThrottlePoint { temp_c, source: ThrottleSource::Reported });
+    }
+    infer_throttle_temp(samples).map(|temp_c| ThrottlePoint { temp_c, source: ThrottleSource::Observed })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_millidegrees() {
+        assert_eq!(parse_millidegrees("100000\n"), Some(100.0));
+        assert_eq!(parse_millidegrees("0"), None);
+        assert_eq!(parse_millidegrees("garbage"), None);
+    }
+
+    #[test]
+    fn test_infer_throttle_temp() {
+        // Cool frequency dip (power limit) is ignored, hot drop counts
+        let samples = [(55.0, 4200), (60.0, 3500), (80.0, 4200), (88.0, 4100), (92.0, 3600)];
+        assert_eq!(infer_throttle_temp(&samples), Some(92.0));
+
+        // Steady clocks: no throttling observed
+        assert_eq!(infer_throttle_temp(&[(85.0, 4000), (90.0, 3950)]), None);
+        assert_eq!(infer_throttle_temp(&[]), None);
+    }
+
+    #[test]
+    fn test_classify_throttle() {
+        let hot = [(70.0, 4200), (88.0, 4100), (94.0, 3500)];
+        assert_eq!(classify_throttle(&hot), Some((ThrottleCause::Thermal, 94.0)));
+
+        // Clocks fall while the CPU is still cool: power/VRM limit
+        let cool = [(50.0, 4200), (58.0, 3400), (61.0, 3300)];
+        assert_eq!(classify_throttle(&cool), Some((ThrottleCause::Power, 61.0)));
+
+        assert_eq!(classify_throttle(&[(85.0, 4000), (90.0, 3950)]), None);
+        assert_eq!(classify_throttle(&[]), None);
+    }
+}
@@ -0,0 +1,79 @@
+// Cache workload (--cpu-workload cache)
+// Each thread rewrites and verifies a working set just beyond L2, so every pass goes through L3 and the memory controller
+
+/// Working set per thread, in multiples of the L2 size
+const L2_MULTIPLIER: u64 = 2;
+/// Used when the L2 size is unknown
+const DEFAULT_L2_KB: u32 = 1024;
+/// Words per 64-byte cache line
+const LINE_WORDS: usize = 64 / std::mem::size_of::<u64>();
+/// Lines skipped between accesses (~4 KB, past the adjacent-line prefetcher)
+const STRIDE_LINES: usize = 67;
+
+/// Working-set size in KB for the given L2 cache size
+pub fn working_set_kb(l2_kb: Option<u32>) -> u64 {
+    l2_kb.filter(|&kb| kb > 0).unwrap_or(DEFAULT_L2_KB) as u64 * L2_MULTIPLIER
+}
+
+/// One thread's working set plus the pass its contents were written by
+pub struct CacheStress {
+    buffer: Vec<u64>,
+    pass: u64,
+}
+
+impl CacheStress {
+    pub fn new(working_set_kb: u64) -> Self {
+        let lines = (working_set_kb as usize * 1024 / 64).max(1);
+        let buffer = (0..lines * LINE_WORDS).map(|i| expected_word(0, i)).collect();
+        Self { buffer, pass: 0 }
+    }
+
+    /// Visit every line at a stride, checking what the previous pass wrote and writing the next pattern
+    /// Returns the number of words that didn't hold their expected value
+    pub fn run_pass(&mut self) -> u64 {
+        let (prev, next) = (self.pass, self.pass + 1);
+        let lines = self.buffer.len() / LINE_WORDS;
+        let mut errors = 0;
+        for first in 0..STRIDE_LINES.min(lines) {
+            for line in (first..lines).step_by(STRIDE_LINES) {
+                for i in line * LINE_WORDS..(line + 1) * LINE_WORDS {
+                    if self.buffer[i] != expected_word(prev, i) {
+                        errors += 1;
+                    }
+                    self.buffer[i] = expected_word(next, i);
+                }
+            }
+        }
+        self.pass = next;
+        errors
+    }
+}
+
+/// Pattern for word `index` after `pass`: differs per word and per pass, so stale or misplaced data is caught
+fn expected_word(pass: u64, index: usize) -> u64 {
+    (index as u64) ^ pass.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_working_set_kb() {
+        assert_eq!(working_set_kb(Some(2048)), 4096);
+        assert_eq!(working_set_kb(None), 2048);
+        assert_eq!(working_set_kb(Some(0)), 2048);
+    }
+
+    #[test]
+    fn test_pass_detects_corruption() {
+        let mut stress = CacheStress::new(16);
+        assert_eq!(stress.run_pass(), 0);
+        assert_eq!(stress.run_pass(), 0);
+
+        // A flipped bit is reported once, then overwritten by the pass that found it
+        stress.buffer[123] ^= 1 << 7;
+        assert_eq!(stress.run_pass(), 1);
+        assert_eq!(stress.run_pass(), 0);
+    }
+}
@@ -2,6 +2,7 @@
 // Tests CPU by running intensive calculations on all cores
 
 mod platform;
+mod cache;
 
 use std::thread;
 use std::time::{Duration, Instant};
@@ -9,17 +10,53 @@ use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::io::{self, Write};
 use std::collections::HashMap;
+use serde::Serialize;
+
+use super::{HealthStatus, Issue, IssueCode, TempLimits, fan_failure_issue, test_mode};
+use crate::interrupt::{TestGuard, interrupted};
+use crate::sensors::{CpuTemp, CpuFrequency, get_cpu_temp, get_cpu_frequency, get_boost_enabled, CpuMonitorHandle, get_all_sensors, SensorReading, FanReading, FanHistory, get_fan_speeds, format_fan_speeds, detect_throttle_point, classify_throttle, ThrottleCause, ThrottlePoint, ThrottleSource};
+use crate::fmt::{RESET, CYAN, color, temp_color, temp_status, format_large_number, format_eta, progress_bar, quiet_progress, plain_progress, progress_log_line, emit_progress_json};
+
+/// Work done by each CPU test thread per operation
+#[derive(clap::ValueEnum, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CpuWorkload {
+    /// Integer prime search (cache-friendly)
+    #[default]
+    Primes,
+    /// Dense f64 matrix multiply (FPU/SIMD)
+    Float,
+    /// Alternate primes and float every operation
+    Mixed,
+    /// Strided read/verify/write over a working set beyond L2 (L3 and memory controller)
+    Cache,
+}
 
-use super::HealthStatus;
-use crate::sensors::{CpuTemp, CpuFrequency, get_cpu_temp, get_cpu_frequency, CpuMonitorHandle, get_all_sensors};
-use crate::fmt::{RESET, CYAN, temp_color, temp_status, format_large_number, progress_bar};
+impl CpuWorkload {
+    pub fn name(self) -> &'static str {
+        match self {
+            CpuWorkload::Primes => "primes",
+            CpuWorkload::Float => "float",
+            CpuWorkload::Mixed => "mixed",
+            CpuWorkload::Cache => "cache",
+        }
+    }
+}
 
 pub struct CpuTestConfig {
     pub duration_secs: u64,
     pub thread_count: Option<usize>,
+    pub workload: CpuWorkload,
+    /// Per-core usage/frequency rows in the live display (-v)
     pub verbose: bool,
+    /// Also list all temperature sensors under the per-core rows (-vv)
+    pub show_sensors: bool,
+    /// Temperature limits (default: this CPU's reported TjMax, else 85/95°C)
+    pub temp_limits: Option<TempLimits>,
+    /// Detected L2 size (CacheInfo), sizes the cache workload's working set
+    pub l2_cache_kb: Option<u32>,
     // AI commentary callbacks (optional, for real-time comments)
-    pub on_comment: Option<Box<dyn Fn(&str) + Send>>,
+    pub on_comment: Option<super::CommentCallback>,
 }
 
 impl Default for CpuTestConfig {
@@ -27,25 +64,51 @@ impl Default for CpuTestConfig {
         Self {
             duration_secs: 60,
             thread_count: None,
+            workload: CpuWorkload::default(),
             verbose: false,
+            show_sensors: false,
+            temp_limits: None,
+            l2_cache_kb: None,
             on_comment: None,
         }
     }
 }
 
+#[derive(Serialize)]
 pub struct CpuTestResult {
     // Hardware info
     pub cpu_model: String,
     pub cpu_cores: usize,
+    /// Worker threads that ran (--threads, else one per logical core)
+    pub threads: usize,
+    pub workload: CpuWorkload,
+    /// Per-thread working set of the cache workload (KB), None for the other workloads
+    pub working_set_kb: Option<u64>,
+    /// Words the cache workload read back wrong
+    pub cache_errors: u64,
     // Test metrics
     pub operations: u64,
     pub ops_per_second: f64,
     pub avg_op_time_ms: f64,
     pub variance_pct: f64,
+    /// End-of-test reading
     pub temperature: Option<CpuTemp>,
+    /// Idle baseline, read before the workload starts (°C)
+    pub temperature_idle: Option<f32>,
+    /// Hottest reading during the run (°C)
+    pub temperature_peak: Option<f32>,
     pub frequency_start: CpuFrequency,
     pub frequency_end: CpuFrequency,
+    /// Highest average MHz sampled during the run (catches CPUs already throttled at start)
+    pub frequency_max_observed: u64,
+    /// End frequency drop relative to `frequency_max_observed`
     pub freq_drop_pct: f64,
+    /// Turbo/Precision Boost state (None if unknown)
+    pub boost_enabled: Option<bool>,
+    /// CPU throttle temperature (TjMax or observed), None if unknown
+    pub throttle_point: Option<ThrottlePoint>,
+    /// Fan speeds read at the end of the workload (empty when the platform exposes none)
+    pub fans: Vec<FanReading>,
     pub health: HealthStatus,
 }
 
@@ -53,17 +116,41 @@ pub struct CpuTestResult {
 /// Higher values = more CPU-intensive test
 const CPU_PRIME_WORKLOAD: usize = 10000;
 
+/// Matrix dimension for the float workload (one multiply per operation)
+const CPU_MATRIX_SIZE: usize = 160;
+
+/// Workload and progress tick used in test mode
+const TEST_MODE_PRIME_WORKLOAD: usize = 3000;
+const TEST_MODE_MATRIX_SIZE: usize = 64;
+const TEST_MODE_TICK: Duration = Duration::from_millis(50);
+
+/// Percent the end frequency sits below the highest frequency seen during the run
+pub fn frequency_drop_pct(max_observed_mhz: u64, end_mhz: u64) -> f64 {
+    if max_observed_mhz == 0 {
+        return 0.0;
+    }
+    max_observed_mhz.saturating_sub(end_mhz) as f64 / max_observed_mhz as f64 * 100.0
+}
+
+/// Limit a requested worker thread count to 1..=4x logical cores
+pub fn clamp_thread_count(requested: usize, cpu_cores: usize) -> usize {
+    requested.clamp(1, cpu_cores.max(1) * 4)
+}
+
 /// Run CPU health check
 /// Spawns threads equal to logical CPU cores and runs intensive calculations
 pub fn run_stress_test(config: CpuTestConfig, cpu_model: String, cpu_cores: usize) -> CpuTestResult {
+    let _guard = TestGuard::new();
     let thread_count = config.thread_count.unwrap_or(cpu_cores);
     let running = Arc::new(AtomicBool::new(true));
 
     // Start background CPU usage monitor
     let monitor = CpuMonitorHandle::start();
 
-    // Capture start frequency
+    // Capture idle temperature, start frequency and boost state before any load
+    let temperature_idle = get_cpu_temp().map(|t| t.current);
     let frequency_start = get_cpu_frequency();
+    let boost_enabled = get_boost_enabled();
 
     // Shared counter for total operations (for progress display)
     let total_ops = Arc::new(AtomicU64::new(0));
@@ -71,20 +158,46 @@ pub fn run_stress_test(config: CpuTestConfig, cpu_model: String, cpu_cores: usiz
     // Clone callback for use in loop
     let comment_callback = config.on_comment;
 
+    let workload = config.workload;
+    let (prime_count, matrix_size, tick) = if test_mode() {
+        (TEST_MODE_PRIME_WORKLOAD, TEST_MODE_MATRIX_SIZE, TEST_MODE_TICK)
+    } else {
+        (CPU_PRIME_WORKLOAD, CPU_MATRIX_SIZE, Duration::from_secs(1))
+    };
+    let working_set_kb = (workload == CpuWorkload::Cache).then(|| cache::working_set_kb(config.l2_cache_kb));
+    let cache_errors = Arc::new(AtomicU64::new(0));
+    let run_start = Instant::now();
+
     // Spawn worker threads
     let threads: Vec<_> = (0..thread_count)
         .map(|_| {
             let running = Arc::clone(&running);
             let total_ops = Arc::clone(&total_ops);
+            let cache_errors = Arc::clone(&cache_errors);
             thread::spawn(move || {
                 let mut ops = 0u64;
                 let mut times = Vec::new();
+                let mut cache_stress = working_set_kb.map(cache::CacheStress::new);
 
                 while running.load(Ordering::Relaxed) {
                     let start = Instant::now();
 
-                    // CPU-intensive work: calculate primes
-                    calculate_primes(CPU_PRIME_WORKLOAD);
+                    // CPU-intensive work
+                    let float_op = match workload {
+                        CpuWorkload::Primes | CpuWorkload::Cache => false,
+                        CpuWorkload::Float => true,
+                        CpuWorkload::Mixed => ops % 2 == 1,
+                    };
+                    if let Some(ref mut cache_stress) = cache_stress {
+                        let errors = cache_stress.run_pass();
+                        if errors > 0 {
+                            cache_errors.fetch_add(errors, Ordering::Relaxed);
+                        }
+                    } else if float_op {
+                        std::hint::black_box(multiply_matrices(matrix_size));
+                    } else {
+                        std::hint::black_box(calculate_primes(prime_count));
+                    }
 
                     let elapsed = start.elapsed().as_micros() as f64;
                     times.push(elapsed);
@@ -97,15 +210,44 @@ pub fn run_stress_test(config: CpuTestConfig, cpu_model: String, cpu_cores: usiz
         })
         .collect();
 
+    // (temperature, MHz) samples for inferring the throttle point
+    let mut thermal_samples: Vec<(f32, u64)> = Vec::new();
+    let mut frequency_max_observed = frequency_start.current_mhz;
+    // Lines the live display drew last tick (cursor-up distance for the next redraw)
+    let mut drawn_lines = 0;
+    let mut sensor_list: Vec<SensorReading> = Vec::new();
+    let mut fan_list: Vec<FanReading> = get_fan_speeds();
+    let mut fan_history = FanHistory::from_readings(&fan_list);
+
     // Run for specified duration with progress updates
     for elapsed in 0..config.duration_secs {
-        thread::sleep(Duration::from_secs(1));
+        thread::sleep(tick);
+        if interrupted() {
+            break;
+        }
 
         // Get current stats for progress display
         let ops = total_ops.load(Ordering::Relaxed);
         let temp = get_cpu_temp();
         let freq = get_cpu_frequency();
         let cpu_usage = monitor.get_per_core_usage();
+        frequency_max_observed = frequency_max_observed.max(freq.current_mhz);
+        crate::fmt::telemetry::record(
+            "cpu",
+            elapsed + 1,
+            temp.as_ref().map(|t| t.current),
+            freq.current_mhz,
+            ops,
+            &cpu_usage,
+        );
+        if let Some(ref t) = temp {
+            thermal_samples.push((t.current, freq.current_mhz));
+        }
+        // Fans every 5 seconds: which ones spin under load, and the -vv fan line
+        if elapsed % 5 == 0 {
+            fan_list = get_fan_speeds();
+            fan_history.record(&fan_list);
+        }
 
         // AI commentary based on temperature (every 10 seconds or at start)
         if let Some(ref callback) = comment_callback {
@@ -120,43 +262,60 @@ pub fn run_stress_test(config: CpuTestConfig, cpu_model: String, cpu_cores: usiz
             }
         }
 
+        // JSON modes: nothing drawn on stdout, progress event (if enabled) goes to stderr
+        if quiet_progress() {
+            let percent = ((elapsed + 1) * 100 / config.duration_secs) as u8;
+            emit_progress_json("cpu", percent, temp.as_ref().map(|t| t.current), elapsed + 1);
+            continue;
+        }
+
+        // Sensor list refreshes every 5 seconds to avoid flicker
+        if config.verbose && config.show_sensors && elapsed % 5 == 0 {
+            sensor_list = get_all_sensors();
+            sensor_list.truncate(MAX_LISTED_SENSORS);
+        }
+
         // Print progress box (overwrites previous)
-        // Track if first iteration to avoid moving cursor up before first print
-        let is_first = elapsed == 0;
-        print_cpu_progress_box(
+        drawn_lines = print_cpu_progress_box(
             elapsed + 1,
             config.duration_secs,
             ops,
             &temp,
             &freq,
             &cpu_usage,
-            is_first,
+            drawn_lines,
             config.verbose,
+            &sensor_list,
+            &fan_list,
         );
     }
 
+    // Fans are read while the workload is still running
+    let fans = get_fan_speeds();
+
     // Stop test
     running.store(false, Ordering::Relaxed);
 
     // Clear the progress lines before showing results
     // Normal mode: 1 line, Verbose mode: varies based on core count
-    let lines_to_clear = if config.verbose {
-        // Main line + per-core rows + sensor section (max 4 sensors + 1 header + 1 blank)
-        let freq = get_cpu_frequency();
-        use platform::cores_per_row_verbose;
-        let core_rows = freq.cores.div_ceil(cores_per_row_verbose());
-        1 + core_rows + 6 // +6 for sensor section (blank + header + max 4 sensors)
+    let redrawn = !quiet_progress() && !plain_progress();
+    let lines_to_clear = if !redrawn {
+        0 // Nothing was drawn in place on stdout
+    } else if config.verbose {
+        drawn_lines // Main line + per-core rows + sensor section
     } else {
         1 // Normal mode: only 1 line
     };
 
-    for _ in 0..lines_to_clear {
-        print!("\r\x1b[2K");  // Clear line
-        print!("\x1b[1A");     // Move up
+    if redrawn {
+        for _ in 0..lines_to_clear {
+            print!("\r\x1b[2K");  // Clear line
+            print!("\x1b[1A");     // Move up
+        }
+        print!("\r\x1b[2K");  // Clear first line
+        print!("{}", color(RESET));  // Reset all colors
+        io::stdout().flush().unwrap();
     }
-    print!("\r\x1b[2K");  // Clear first line
-    print!("\x1b[0m");     // Reset all colors
-    io::stdout().flush().unwrap();
 
     // Capture end frequency
     let frequency_end = get_cpu_frequency();
@@ -180,7 +339,8 @@ pub fn run_stress_test(config: CpuTestConfig, cpu_model: String, cpu_cores: usiz
     }
 
     // Calculate metrics
-    let ops_per_second = all_ops as f64 / config.duration_secs as f64;
+    let run_secs = run_start.elapsed().as_secs_f64();
+    let ops_per_second = if run_secs > 0.0 { all_ops as f64 / run_secs } else { 0.0 };
     let avg_time = if all_times.is_empty() {
         0.0
     } else {
@@ -189,38 +349,78 @@ pub fn run_stress_test(config: CpuTestConfig, cpu_model: String, cpu_cores: usiz
     let variance = calculate_variance(&all_times, avg_time);
 
     // Calculate frequency drop percentage
-    let freq_drop_pct = if frequency_start.current_mhz > 0 {
-        ((frequency_start.current_mhz.saturating_sub(frequency_end.current_mhz)) as f64
-            / frequency_start.current_mhz as f64) * 100.0
-    } else {
-        0.0
-    };
+    let frequency_max_observed = frequency_max_observed.max(frequency_end.current_mhz);
+    let freq_drop_pct = frequency_drop_pct(frequency_max_observed, frequency_end.current_mhz);
+    if let Some(ref t) = temperature {
+        thermal_samples.push((t.current, frequency_end.current_mhz));
+    }
+    let temperature_peak = thermal_samples.iter().map(|&(temp, _)| temp).reduce(f32::max);
+
+    // Judge temperature against this CPU's own limit when the platform reports it
+    // (an observed throttle point was reached by definition, so it can't be the limit)
+    let throttle_point = detect_throttle_point(&thermal_samples);
+    let limits = config.temp_limits.unwrap_or(match throttle_point {
+        Some(ThrottlePoint { temp_c, source: ThrottleSource::Reported }) => TempLimits::from_tjmax(temp_c),
+        _ => TempLimits::default(),
+    });
 
     // Determine health status
-    let health = evaluate_cpu_health(
+    let mut health = evaluate_cpu_health_with_limits(
         completed,
         variance,
         temperature.as_ref(),
         freq_drop_pct,
+        &thermal_samples,
+        limits,
+        fan_history.stuck_fan(&fans, temperature_idle, temperature_peak),
     );
+    let cache_errors = cache_errors.load(Ordering::Relaxed);
+    if cache_errors > 0 && completed {
+        health = HealthStatus::Failed(Issue::new(IssueCode::CpuCacheError, format!(
+            "{} cache data errors - possible CPU cache or memory controller fault",
+            cache_errors
+        )));
+    }
 
     CpuTestResult {
         cpu_model,
         cpu_cores,
+        threads: thread_count,
+        workload,
+        working_set_kb,
+        cache_errors,
         operations: all_ops,
         ops_per_second,
         avg_op_time_ms: avg_time / 1000.0,
         variance_pct: variance,
         temperature,
+        temperature_idle,
+        temperature_peak,
         frequency_start,
         frequency_end,
+        frequency_max_observed,
         freq_drop_pct,
+        boost_enabled,
+        throttle_point,
+        fans,
         health,
     }
 }
 
+impl CpuTestResult {
+    /// Threads that actually ran in parallel: workers beyond the logical core count share cores
+    pub fn parallel_threads(&self) -> usize {
+        self.threads.min(self.cpu_cores).max(1)
+    }
+}
+
+/// Most sensors listed under the verbose CPU display
+const MAX_LISTED_SENSORS: usize = 8;
+
 /// Print the animated progress box for CPU test
 /// Shows multi-line per-core display with platform-specific formatting
+/// `prev_lines` is what the previous call returned (0 on the first one); returns the lines drawn now
+#[allow(clippy::too_many_arguments)]
 fn print_cpu_progress_box(
     elapsed: u64,
     total: u64,
@@ -228,11 +428,14 @@ fn print_cpu_progress_box(
     temp: &Option<CpuTemp>,
     freq: &CpuFrequency,
     cpu_usage: &HashMap<usize, f32>,
-    is_first: bool,
+    prev_lines: usize,
     verbose: bool,
-) {
+    sensors: &[SensorReading],
+    fans: &[FanReading],
+) -> usize {
     let percent = ((elapsed * 100) / total) as u8;
     let bar = progress_bar(percent, 14);
+    let eta = format_eta(elapsed, total);
 
     // Get temperature values
     let temp_val = temp.as_ref().map(|t| t.current).unwrap_or(0.0);
@@ -241,61 +444,74 @@ fn print_cpu_progress_box(
     } else {
         "N/A".to_string()
     };
-    let temp_color_code = if temp_val > 0.0 { temp_color(temp_val) } else { RESET };
+    let temp_color_code = if temp_val > 0.0 { temp_color(temp_val) } else { color(RESET) };
     let temp_status_text = if temp_val > 0.0 { temp_status(temp_val) } else { "" };
 
     // Format operations
     let ops_str = format_large_number(ops);
 
+    // Piped stdout: one log line per update, no cursor movement
+    if plain_progress() {
+        println!("{}", progress_log_line(elapsed, &format!("CPU {}% {} {} {:.2}GHz {} ops",
+            percent, eta, temp_str, freq.current_ghz, ops_str)));
+        return 0;
+    }
+
     // Build per-core rows based on platform
     let cores = freq.cores;
     let per_core_rows = build_per_core_display(freq, cpu_usage, cores, verbose);
 
-    if verbose {
+    let drawn = if verbose {
         // === VERBOSE MODE ===
-        // Calculate lines to move back (main line + core rows)
-        let line_count = per_core_rows.len() + 1;
-
-        // Move cursor up to overwrite previous output (not on first iteration)
-        if !is_first {
-            for _ in 0..line_count {
-                print!("\x1b[1A"); // Move up one line
-            }
+        // Move cursor up to overwrite previous output (not on first iteration), clearing
+        // everything below so a shorter sensor list leaves nothing behind
+        if prev_lines > 0 {
+            print!("\x1b[{}A\x1b[J", prev_lines);
         }
 
         // Main progress line
-        let temp_display = format!("{}{}{} ({}{})", temp_color_code, temp_str, RESET, temp_color_code, temp_status_text);
-        println!("⏳ CPU: [{}] {}% | {} ops | {} | {:.2} GHz",
-              bar, percent, ops_str, temp_display, freq.current_ghz);
+        let temp_display = format!("{}{}{} ({}{})", temp_color_code, temp_str, color(RESET), temp_color_code, temp_status_text);
+        println!("⏳ CPU: [{}] {}% {} | {} ops | {} | {:.2} GHz",
+              bar, percent, eta, ops_str, temp_display, freq.current_ghz);
 
         // Per-core rows with detailed format
         for row in &per_core_rows {
             println!("{}", row);
         }
 
-        // Sensor list section (update every 5 seconds to avoid flicker)
-        if elapsed.is_multiple_of(5) || elapsed == total {
-            let sensors = get_all_sensors();
-            if !sensors.is_empty() {
+        // Sensor list section (-vv)
+        let mut sensor_lines = 0;
+        if !sensors.is_empty() {
+            println!();
+            println!("🌡️  Sensors:");
+            for sensor in sensors {
+                let s_temp = sensor.temp;
+                let s_color = temp_color(s_temp);
+                println!("   • {}{}{}: {}{:.1}°C{}",
+                    color(CYAN), sensor.label, color(RESET), s_color, s_temp, color(RESET));
+            }
+            sensor_lines = 2 + sensors.len();
+        }
+        if !fans.is_empty() {
+            if sensors.is_empty() {
                 println!();
-                println!("🌡️  Sensors:");
-                for sensor in sensors.iter().take(8) { // Limit to 8 sensors
-                    let s_temp = sensor.temp;
-                    let s_color = temp_color(s_temp);
-                    println!("   • {}{}{}: {}{:.1}°C{}",
-                        CYAN, sensor.label, RESET, s_color, s_temp, RESET);
-                }
+                sensor_lines += 1;
             }
+            println!("🌀 Fans: {}", format_fan_speeds(fans));
+            sensor_lines += 1;
         }
+        1 + per_core_rows.len() + sensor_lines
     } else {
         // === NORMAL MODE ===
         // Use \r to return to start of line, then print (no cursor-up needed)
-        let temp_display = format!("{}{}{} ({}{})", temp_color_code, temp_str, RESET, temp_color_code, temp_status_text);
-        print!("\r⏳ CPU: [{}] {}% | {} ops | {} | {:.2} GHz",
-              bar, percent, ops_str, temp_display, freq.current_ghz);
-    }
+        let temp_display = format!("{}{}{} ({}{})", temp_color_code, temp_str, color(RESET), temp_color_code, temp_status_text);
+        print!("\r⏳ CPU: [{}] {}% {} | {} ops | {} | {:.2} GHz",
+              bar, percent, eta, ops_str, temp_display, freq.current_ghz);
+        1
+    };
 
     io::stdout().flush().unwrap();
+    drawn
 }
 
 /// Build per-core display rows
@@ -303,7 +519,9 @@ fn print_cpu_progress_box(
 /// Uses real-time CPU usage from background monitor
 /// Verbose mode: Shows detailed bar chart with usage + frequency
 fn build_per_core_display(
-    _freq: &CpuFrequency,
+    // Per-core clocks aren't shown on macOS (not exposed without root)
+    #[cfg_attr(target_os = "macos", allow(unused_variables))]
+    freq: &CpuFrequency,
     cpu_usage: &HashMap<usize, f32>,
     cores: usize,
     verbose: bool,
@@ -364,43 +582,73 @@ fn build_per_core_display(
     rows
 }
 
-/// Evaluate CPU health based on test results
-fn evaluate_cpu_health(
+/// Evaluate CPU health based on test results (fixed temperature thresholds)
+pub fn evaluate_cpu_health(
+    completed: bool,
+    variance: f64,
+    temperature: Option<&crate::sensors::CpuTemp>,
+    freq_drop_pct: f64,
+) -> HealthStatus {
+    evaluate_cpu_health_with_limits(completed, variance, temperature, freq_drop_pct, &[], TempLimits::default(), None)
+}
+
+/// Evaluate CPU health with temperature limits for this CPU
+/// `samples` are the (temperature, MHz) readings taken each second, used to tell thermal from power throttling
+/// `stuck_fan` is a fan that stopped under load (`FanHistory::stuck_fan`)
+pub fn evaluate_cpu_health_with_limits(
     completed: bool,
     variance: f64,
     temperature: Option<&crate::sensors::CpuTemp>,
     freq_drop_pct: f64,
+    samples: &[(f32, u64)],
+    limits: TempLimits,
+    stuck_fan: Option<&FanReading>,
 ) -> HealthStatus {
     let mut issues = Vec::new();
 
     // Critical: test crashed = actual hardware fault
     if !completed {
-        return HealthStatus::Failed("CPU crashed during test - FAULTY HARDWARE".to_string());
+        return HealthStatus::Failed(Issue::new(IssueCode::CpuCrash, "CPU crashed during test - FAULTY HARDWARE"));
     }
 
-    // Check temperature - from Check.md: > 95°C = FAIL
+    // Check temperature - from Check.md: > 95°C = FAIL (or this CPU's TjMax)
     if let Some(temp) = temperature {
-        if temp.current > 95.0 {
-            return HealthStatus::Failed(format!(
+        if temp.current > limits.fail_c {
+            return HealthStatus::Failed(Issue::new(IssueCode::CpuOverheat, format!(
                 "CPU overheating ({:.1}°C) - cooling system failure",
                 temp.current
-            ));
-        } else if temp.current > 85.0 {
-            issues.push(format!("CPU running hot ({:.1}°C) - check cooling", temp.current));
+            )));
+        } else if temp.current > limits.warn_c {
+            issues.push(Issue::new(IssueCode::CpuOverheat, format!("CPU running hot ({:.1}°C) - check cooling", temp.current)));
         }
     }
 
-    // Frequency throttling warning (>10% drop)
+    // Frequency throttling warning (>10% drop), classified by the temperature while throttled
     if freq_drop_pct > 10.0 {
-        issues.push(format!("CPU throttled by {:.1}% - possible thermal or power limit", freq_drop_pct));
+        issues.push(Issue::new(IssueCode::CpuThrottle, match classify_throttle(samples) {
+            Some((ThrottleCause::Thermal, temp)) => format!(
+                "CPU thermal throttling ({:.1}% drop at {:.0}°C) - check cooling",
+                freq_drop_pct, temp
+            ),
+            Some((ThrottleCause::Power, temp)) => format!(
+                "CPU power/VRM throttling ({:.1}% drop at only {:.0}°C) - check power limits and VRM cooling",
+                freq_drop_pct, temp
+            ),
+            None => format!("CPU throttled by {:.1}% - possible thermal or power limit", freq_drop_pct),
+        }));
+    }
+
+    if let Some(fan) = stuck_fan {
+        let peak = samples.iter().map(|&(temp, _)| temp).chain(temperature.map(|t| t.current)).reduce(f32::max);
+        issues.push(fan_failure_issue(fan, peak));
     }
 
     // Only extreme variance (>200%) suggests possible CPU fault
     if variance > 200.0 {
-        return HealthStatus::Failed(format!(
+        return HealthStatus::Failed(Issue::new(IssueCode::CpuUnstable, format!(
             "Extreme instability detected (variance: {:.1}%) - possible CPU fault",
             variance
-        ));
+        )));
     }
 
     // Return issues or healthy
@@ -424,6 +672,22 @@ fn calculate_primes(n: usize) -> usize {
     count
 }
 
+/// Multiply two deterministic n x n matrices and return the sum of the product
+fn multiply_matrices(n: usize) -> f64 {
+    let scale = (n * n) as f64;
+    let a: Vec<f64> = (0..n * n).map(|i| i as f64 / scale).collect();
+    let mut c = vec![0.0f64; n * n];
+    for i in 0..n {
+        for k in 0..n {
+            let aik = a[i * n + k];
+            for j in 0..n {
+                c[i * n + j] += aik * a[k * n + j];
+            }
+        }
+    }
+    c.iter().sum()
+}
+
 /// Check if a number is prime
 fn is_prime(n: usize) -> bool {
     if n < 2 { return false; }
@@ -454,17 +718,46 @@ fn calculate_variance(times: &[f64], mean: f64) -> f64 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_frequency_drop_pct() {
+        // Already throttled at start: drop is measured from the peak, not the start
+        assert!((frequency_drop_pct(4000, 3000) - 25.0).abs() < 1e-9);
+        assert_eq!(frequency_drop_pct(3000, 3200), 0.0);
+        assert_eq!(frequency_drop_pct(0, 3000), 0.0);
+    }
+
+    #[test]
+    fn test_multiply_matrices() {
+        // [[0, .25], [.5, .75]] squared sums to 1.375
+        assert_eq!(multiply_matrices(2), 1.375);
+        assert_eq!(CpuWorkload::default(), CpuWorkload::Primes);
+    }
+
+    #[test]
+    fn test_clamp_thread_count() {
+        assert_eq!(clamp_thread_count(1, 8), 1);
+        assert_eq!(clamp_thread_count(0, 8), 1);
+        assert_eq!(clamp_thread_count(12, 8), 12);
+        assert_eq!(clamp_thread_count(10_000, 8), 32);
+        assert_eq!(clamp_thread_count(9, 0), 4);
+    }
+
     #[test]
     fn test_cpu_test_short() {
         let config = CpuTestConfig {
             duration_secs: 1,
             thread_count: Some(2),
             verbose: false,
+            show_sensors: false,
+            workload: CpuWorkload::Primes,
+            temp_limits: None,
+            l2_cache_kb: None,
             on_comment: None,
         };
         let result = run_stress_test(config, "Test CPU".to_string(), 2);
 
         assert!(result.operations > 0);
+        assert_eq!(result.threads, 2);
         assert!(matches!(result.health, HealthStatus::Healthy));
     }
 
@@ -509,4 +802,51 @@ mod tests {
         let overheat_temp = CpuTemp { current: 100.0 };
         assert!(matches!(evaluate_cpu_health(true, 10.0, Some(&overheat_temp), 0.0), HealthStatus::Failed(_)));
     }
+
+    #[test]
+    fn test_throttle_cause_in_issues() {
+        let issue = |samples: &[(f32, u64)]| match evaluate_cpu_health_with_limits(true, 10.0, None, 20.0, samples, TempLimits::default(), None) {
+            HealthStatus::IssuesDetected(issues) => {
+                assert_eq!(issues[0].code, IssueCode::CpuThrottle);
+                issues[0].message.clone()
+            }
+            other => panic!("expected issues, got {:?}", other),
+        };
+        assert!(issue(&[(75.0, 4000), (83.0, 3200)]).starts_with("CPU thermal throttling (20.0% drop at 83°C)"));
+        assert!(issue(&[(50.0, 4000), (55.0, 3200)]).starts_with("CPU power/VRM throttling (20.0% drop at only 55°C)"));
+        assert!(issue(&[]).starts_with("CPU throttled by 20.0%"));
+    }
+
+    #[test]
+    fn test_stopped_fan_flagged() {
+        let fan = FanReading { label: "cpu_fan".to_string(), rpm: 0 };
+        match evaluate_cpu_health_with_limits(true, 10.0, None, 0.0, &[(55.0, 4000), (76.0, 4000)], TempLimits::default(), Some(&fan)) {
+            HealthStatus::IssuesDetected(issues) => {
+                assert_eq!(issues[0].code, IssueCode::FanStopped);
+                assert_eq!(issues[0].message, "Fan not spinning under load (cpu_fan at 0 RPM, 76°C) - possible fan failure");
+            }
+            other => panic!("expected issues, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_temp_limits_from_tjmax() {
+        use crate::sensors::CpuTemp;
+
+        // 97°C fails the fixed limit but only warns on a 105°C TjMax part
+        let temp = CpuTemp { current: 97.0 };
+        let limits = TempLimits::from_tjmax(105.0);
+        assert!(matches!(evaluate_cpu_health(true, 10.0, Some(&temp), 0.0), HealthStatus::Failed(_)));
+        assert!(matches!(
+            evaluate_cpu_health_with_limits(true, 10.0, Some(&temp), 0.0, &[], limits, None),
+            HealthStatus::IssuesDetected(_)
+        ));
+
+        // 88°C is only a warning by default but a failure on an 85°C part
+        let temp = CpuTemp { current: 88.0 };
+        assert!(matches!(
+            evaluate_cpu_health_with_limits(true, 10.0, Some(&temp), 0.0, &[], TempLimits::from_tjmax(85.0), None),
+            HealthStatus::Failed(_)
+        ));
+    }
 }
@@ -1,6 +1,6 @@
 // macOS CPU display formatting
 
-use crate::fmt::{RESET, CYAN, GREEN, DARK_GRAY};
+use crate::fmt::{RESET, CYAN, GREEN, DARK_GRAY, color};
 
 /// Cores per row in verbose mode on macOS
 pub fn cores_per_row_verbose() -> usize {
@@ -21,15 +21,15 @@ pub fn format_core_display_verbose(
 ) -> String {
     let bar_str = format!(
         "{}{}{}{}",
-        GREEN,
+        color(GREEN),
         "█".repeat(bar_filled),
-        DARK_GRAY,
+        color(DARK_GRAY),
         "░".repeat(10 - bar_filled)
     );
 
     format!(
         "{}C{:02}:{} [{}] {}%",
-        CYAN, i, RESET, bar_str, usage_int
+        color(CYAN), i, color(RESET), bar_str, usage_int
     )
 }
 
@@ -40,7 +40,7 @@ pub fn format_core_display_normal(
     display_usage: f32,
 ) -> String {
     use crate::fmt::usage_color;
-    let color = usage_color(display_usage);
+    let usage_code = usage_color(display_usage);
     let usage_str = format!("{}%", display_usage as u32);
-    format!("{}C{}:{}{}{} ", CYAN, i, color, usage_str, RESET)
+    format!("{}C{}:{}{}{} ", color(CYAN), i, usage_code, usage_str, color(RESET))
 }
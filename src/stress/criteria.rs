@@ -0,0 +1,101 @@
+// Verdict criteria module
+// Optional strict requirements for the "hardware is in good condition" verdict
+
+use crate::lang::Text;
+
+/// Strict criteria for the headline verdict (all off by default = lenient)
+#[derive(Debug, Clone, Default)]
+pub struct VerdictCriteria {
+    /// Every tested CPU/GPU must report temperature data
+    pub require_thermal: bool,
+    /// Every tested disk must report a verified SMART status
+    pub require_smart_verified: bool,
+    /// Every tested SSD must report at least this much life left
+    pub min_ssd_life_pct: Option<u8>,
+}
+
+/// Evidence collected during the health check
+#[derive(Debug, Clone)]
+pub enum Evidence {
+    Thermal { component: String, available: bool },
+    Disk { disk: String, is_ssd: bool, smart_verified: bool, life_pct: Option<u8> },
+}
+
+impl VerdictCriteria {
+    /// Preset used by --strict
+    pub fn strict() -> Self {
+        Self {
+            require_thermal: true,
+            require_smart_verified: true,
+            min_ssd_life_pct: Some(80),
+        }
+    }
+
+    /// True if any strict requirement is enabled
+    pub fn is_strict(&self) -> bool {
+        self.require_thermal || self.require_smart_verified || self.min_ssd_life_pct.is_some()
+    }
+
+    /// Check evidence against the criteria, returns the unmet criteria as messages
+    pub fn unmet(&self, evidence: &[Evidence], text: &Text) -> Vec<String> {
+        let mut unmet = Vec::new();
+
+        for item in evidence {
+            match item {
+                Evidence::Thermal { component, available } => {
+                    if self.require_thermal && !available {
+                        unmet.push(text.criteria_no_thermal().replace("{COMPONENT}", component));
+                    }
+                }
+                Evidence::Disk { disk, is_ssd, smart_verified, life_pct } => {
+                    if self.require_smart_verified && !smart_verified {
+                        unmet.push(text.criteria_smart_unverified().replace("{DISK}", disk));
+                    }
+                    if let (true, Some(min)) = (*is_ssd, self.min_ssd_life_pct) {
+                        match life_pct {
+                            Some(life) if *life < min => unmet.push(
+                                text.criteria_ssd_life_low()
+                                    .replace("{DISK}", disk)
+                                    .replace("{PCT}", &life.to_string())
+                                    .replace("{MIN}", &min.to_string()),
+                            ),
+                            Some(_) => {}
+                            None => unmet.push(text.criteria_ssd_life_unknown().replace("{DISK}", disk)),
+                        }
+                    }
+                }
+            }
+        }
+
+        unmet
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::Language;
+
+    #[test]
+    fn test_unmet_criteria() {
+        let text = Text::new(Language::English);
+        let evidence = vec![
+            Evidence::Thermal { component: "CPU".to_string(), available: false },
+            Evidence::Disk { disk: "disk0".to_string(), is_ssd: true, smart_verified: true, life_pct: Some(70) },
+            Evidence::Disk { disk: "disk1".to_string(), is_ssd: false, smart_verified: false, life_pct: None },
+        ];
+
+        // Lenient default: nothing is required
+        assert!(!VerdictCriteria::default().is_strict());
+        assert!(VerdictCriteria::default().unmet(&evidence, &text).is_empty());
+
+        // Strict: missing thermal, low SSD life, unverified SMART (HDD life not checked)
+        let unmet = VerdictCriteria::strict().unmet(&evidence, &text);
+        assert_eq!(unmet.len(), 3);
+        assert!(unmet[1].contains("disk0") && unmet[1].contains("70"));
+
+        // Only SSD life with a lower floor
+        let criteria = VerdictCriteria { min_ssd_life_pct: Some(50), ..Default::default() };
+        assert!(criteria.unmet(&evidence, &text).is_empty());
+    }
+}
@@ -2,26 +2,38 @@
 // Tests disk by sequential/random I/O operations
 
 pub mod smart;
+pub mod trim;
+mod nocache;
 
 use std::fs::{File, OpenOptions};
 use std::io::{Read, Write, Seek, SeekFrom};
-use std::path::PathBuf;
-use std::time::Instant;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use std::io::{self, BufWriter};
+use serde::Serialize;
 
-use super::HealthStatus;
+use super::{HealthStatus, Issue, IssueCode};
 use smart::SmartData;
+use trim::TrimStatus;
 use crate::lang::Text;
+use crate::fmt::{quiet_progress, emit_progress_json};
+use crate::interrupt::{TestGuard, interrupted};
 
 pub struct DiskTestConfig {
+    /// Test file or directory (default: the tested disk's mount point)
     pub test_path: Option<String>,
     pub test_size_mb: u64,
+    /// Random access phase: seek latency and random 4K read IOPS
     pub include_seek_test: bool,
+    /// Re-read mismatched regions; only sectors that mismatch twice count as bad
+    pub verify_mismatches: bool,
+    /// Sustained write mode: file size in MB, written in synced windows to expose SSD cache exhaustion
+    pub sustained_mb: Option<u64>,
     pub verbose: bool,
     // Text for i18n
     pub text: Text,
     // AI commentary callbacks (optional, for real-time comments)
-    pub on_comment: Option<Box<dyn Fn(&str) + Send>>,
+    pub on_comment: Option<super::CommentCallback>,
 }
 
 impl Default for DiskTestConfig {
@@ -30,6 +42,8 @@ impl Default for DiskTestConfig {
             test_path: None,
             test_size_mb: 100,
             include_seek_test: true,
+            verify_mismatches: false,
+            sustained_mb: None,
             verbose: false,
             text: Text::new(crate::lang::Language::Vietnamese),
             on_comment: None,
@@ -37,6 +51,7 @@ impl Default for DiskTestConfig {
     }
 }
 
+#[derive(Serialize)]
 pub struct DiskTestResult {
     // Hardware info
     pub disk_name: String,
@@ -47,15 +62,44 @@ pub struct DiskTestResult {
     pub disk_device: Option<String>,
     // Test metrics
     pub write_speed_mb_s: f64,
+    /// Fastest write window (sustained mode only) - the SSD's cache/burst speed
+    pub write_speed_peak_mb_s: Option<f64>,
+    /// Write speed over the final windows (sustained mode only) - steady state once the cache is full
+    pub write_speed_sustained_mb_s: Option<f64>,
     pub read_speed_mb_s: f64,
     pub seek_time_ms: f64,
+    /// Random 4K reads completed per second (0 when the random access phase is skipped)
+    pub random_read_iops: f64,
     pub bad_sectors: u64,
+    /// Sectors that mismatched once but read back correctly (only counted with verify_mismatches)
+    pub read_retries: u64,
     pub is_ssd: bool,
+    /// TRIM support/status of the tested volume
+    pub trim: TrimStatus,
     // SMART data (verbose mode)
     pub smart: Option<SmartData>,
     pub health: HealthStatus,
 }
 
+/// Sustained write this far below the peak means the drive fell off its write cache
+const CACHE_CLIFF_DROP_PCT: f64 = 30.0;
+
+impl DiskTestResult {
+    /// How much (%) sustained write speed dropped below the peak, when it crosses CACHE_CLIFF_DROP_PCT
+    pub fn cache_cliff_pct(&self) -> Option<f64> {
+        let (peak, sustained) = (self.write_speed_peak_mb_s?, self.write_speed_sustained_mb_s?);
+        let drop_pct = (1.0 - sustained / peak) * 100.0;
+        (peak > 0.0 && drop_pct >= CACHE_CLIFF_DROP_PCT).then_some(drop_pct)
+    }
+}
+
+/// Whether a test file of `size_mb` fits in the free space (unknown free space of 0 is not checked)
+fn fits_in_free_space(size_mb: u64, available_gb: f64) -> bool {
+    available_gb <= 0.0 || size_mb as f64 / 1024.0 <= available_gb
+}
+
+const TEST_FILE_NAME: &str = "pchecker_disk_test.tmp";
+
 /// Run disk health check
 /// Tests sequential write/read, random access, and data integrity
 pub fn run_stress_test(
@@ -67,15 +111,56 @@ pub fn run_stress_test(
     disk_fs: String,
     disk_mount: &str,
 ) -> DiskTestResult {
-    // Determine test file path
+    // Held until the test file has been cleaned up
+    let _guard = TestGuard::new();
+
+    // Determine test file path (the disk's own mount point, falling back to temp/home/current dir)
     let test_path = if let Some(ref path) = config.test_path {
-        PathBuf::from(path)
+        let path = PathBuf::from(path);
+        Some(if path.is_dir() { path.join(TEST_FILE_NAME) } else { path })
     } else {
-        std::env::temp_dir().join("pchecker_disk_test.tmp")
+        find_writable_test_path(&test_dirs_for_mount(disk_mount), TEST_FILE_NAME)
+    };
+
+    // Result for a test that could not start
+    let not_started = |reason: String| DiskTestResult {
+        disk_name: disk_name.clone(),
+        disk_size_gb,
+        disk_used_gb,
+        disk_available_gb,
+        disk_fs: disk_fs.clone(),
+        disk_device: get_disk_device(disk_mount),
+        write_speed_mb_s: 0.0,
+        write_speed_peak_mb_s: None,
+        write_speed_sustained_mb_s: None,
+        read_speed_mb_s: 0.0,
+        seek_time_ms: 0.0,
+        random_read_iops: 0.0,
+        bad_sectors: 0,
+        read_retries: 0,
+        is_ssd: true,
+        trim: TrimStatus::default(),
+        smart: None,
+        health: HealthStatus::Failed(Issue::new(IssueCode::DiskNotTested, reason)),
+    };
+
+    let test_path = match test_path {
+        Some(path) => path,
+        None => return not_started(config.text.disk_no_writable_location().to_string()),
     };
 
+    let requested_mb = config.sustained_mb.unwrap_or(config.test_size_mb);
+    let test_size_mb = if super::test_mode() { requested_mb.min(1) } else { requested_mb };
+
+    // Refuse up front rather than failing partway through the write phase
+    if !fits_in_free_space(test_size_mb, disk_available_gb) {
+        return not_started(config.text.disk_insufficient_space()
+            .replace("{SIZE}", &test_size_mb.to_string())
+            .replace("{FREE}", &format!("{:.1}", disk_available_gb)));
+    }
+
     let test_path_str = test_path.to_string_lossy().to_string();
-    let test_size_bytes = config.test_size_mb * 1024 * 1024;
+    let test_size_bytes = test_size_mb * 1024 * 1024;
     let chunk_size = 1024 * 1024; // 1MB chunks
 
     // Detect disk type (SSD/HDD) based on mount point
@@ -87,20 +172,34 @@ pub fn run_stress_test(
     if config.verbose {
         println!("📁 Test file: {}", test_path_str);
         println!("💿 Disk: {} ({})", disk_name, if is_ssd { "SSD" } else { "HDD" });
-        println!("📏 Test size: {} MB", config.test_size_mb);
+        println!("📏 Test size: {} MB", test_size_mb);
         println!();
     }
 
+    // Progress reporting: ANSI line on stdout, or nothing on stdout in JSON modes
+    let quiet = quiet_progress();
+    let start = Instant::now();
+
     // === PHASE 1: Write Test ===
-    print!("⏳ ");
-    if config.verbose {
-        print!("Writing {} MB... ", config.test_size_mb);
+    if quiet {
+        emit_progress_json("disk", 0, None, 0);
     } else {
-        print!("Disk: Writing... ");
+        print!("⏳ ");
+        if config.verbose {
+            print!("Writing {} MB... ", test_size_mb);
+        } else {
+            print!("Disk: Writing... ");
+        }
+        io::stdout().flush().unwrap();
     }
-    io::stdout().flush().unwrap();
 
-    let (write_speed, write_success) = write_test(&test_path, test_size_bytes, chunk_size, config.verbose);
+    let window_bytes = config.sustained_mb.map(|_| SUSTAINED_WINDOW_MB * 1024 * 1024);
+    let (write_speed, write_windows, write_success) =
+        write_test(&test_path, test_size_bytes, chunk_size, window_bytes, config.verbose);
+    let (write_speed_peak_mb_s, write_speed_sustained_mb_s) = match burst_and_sustained(&write_windows) {
+        Some((peak, sustained)) => (Some(peak), Some(sustained)),
+        None => (None, None),
+    };
 
     if !write_success {
         cleanup_test_file(&test_path);
@@ -112,25 +211,36 @@ pub fn run_stress_test(
             disk_fs: disk_fs.clone(),
             disk_device: get_disk_device(disk_mount),
             write_speed_mb_s: 0.0,
+            write_speed_peak_mb_s: None,
+            write_speed_sustained_mb_s: None,
             read_speed_mb_s: 0.0,
             seek_time_ms: 0.0,
+            random_read_iops: 0.0,
             bad_sectors: 0,
+            read_retries: 0,
             is_ssd,
+            trim: TrimStatus::default(),
             smart: None,
-            health: HealthStatus::Failed("Cannot write to disk - check permissions or disk space".to_string()),
+            health: HealthStatus::Failed(Issue::new(IssueCode::DiskIoError, "Cannot write to disk - check permissions or disk space")),
         };
     }
 
     // === PHASE 2: Read Test ===
-    print!("\r⏳ ");
-    if config.verbose {
-        print!("Reading {} MB... ", config.test_size_mb);
+    if quiet {
+        let percent = if config.include_seek_test { 33 } else { 50 };
+        emit_progress_json("disk", percent, None, start.elapsed().as_secs());
     } else {
-        print!("Disk: Reading... ");
+        print!("\r⏳ ");
+        if config.verbose {
+            print!("Reading {} MB... ", test_size_mb);
+        } else {
+            print!("Disk: Reading... ");
+        }
+        io::stdout().flush().unwrap();
     }
-    io::stdout().flush().unwrap();
 
-    let (read_speed, bad_sectors, read_success) = read_test(&test_path, test_size_bytes, chunk_size, config.verbose);
+    let (read_speed, bad_sectors, read_retries, read_success) =
+        read_test(&test_path, test_size_bytes, chunk_size, config.verify_mismatches, config.verbose);
 
     if !read_success {
         cleanup_test_file(&test_path);
@@ -142,12 +252,17 @@ pub fn run_stress_test(
             disk_fs: disk_fs.clone(),
             disk_device: get_disk_device(disk_mount),
             write_speed_mb_s: write_speed,
+            write_speed_peak_mb_s,
+            write_speed_sustained_mb_s,
             read_speed_mb_s: 0.0,
             seek_time_ms: 0.0,
+            random_read_iops: 0.0,
             bad_sectors: 0,
+            read_retries: 0,
             is_ssd,
+            trim: TrimStatus::default(),
             smart: None,
-            health: HealthStatus::Failed("Read test failed - possible disk failure".to_string()),
+            health: HealthStatus::Failed(Issue::new(IssueCode::DiskIoError, "Read test failed - possible disk failure")),
         };
     }
 
@@ -186,18 +301,38 @@ pub fn run_stress_test(
         }
     }
 
-    // === PHASE 3: Seek Test (optional) ===
+    // === PHASE 3: Seek + IOPS Test (optional) ===
     let mut seek_time = 0.0;
+    let mut random_read_iops = 0.0;
     if config.include_seek_test {
-        print!("\r⏳ ");
-        if config.verbose {
-            print!("Testing seek time... ");
+        if quiet {
+            emit_progress_json("disk", 66, None, start.elapsed().as_secs());
         } else {
-            print!("Disk: Seeking... ");
+            print!("\r⏳ ");
+            if config.verbose {
+                print!("Testing seek time... ");
+            } else {
+                print!("Disk: Seeking... ");
+            }
+            io::stdout().flush().unwrap();
         }
-        io::stdout().flush().unwrap();
 
         seek_time = seek_test(&test_path, test_size_bytes, 1000, config.verbose);
+
+        if quiet {
+            emit_progress_json("disk", 83, None, start.elapsed().as_secs());
+        } else {
+            print!("\r⏳ ");
+            if config.verbose {
+                print!("Testing random 4K read IOPS... ");
+            } else {
+                print!("Disk: Random reads... ");
+            }
+            io::stdout().flush().unwrap();
+        }
+
+        let window = if super::test_mode() { TEST_MODE_IOPS_WINDOW } else { IOPS_WINDOW };
+        random_read_iops = iops_test(&test_path, test_size_bytes, window);
     }
 
     // Cleanup test file
@@ -211,13 +346,18 @@ pub fn run_stress_test(
     };
 
     // Print final progress
-    if config.verbose {
+    if quiet {
+        emit_progress_json("disk", 100, None, start.elapsed().as_secs());
+    } else if config.verbose {
         println!();
         println!("✅ Tests completed");
     } else {
         print!("\r");
     }
 
+    // TRIM only matters for SSDs
+    let trim = if is_ssd { trim::get_trim_status(disk_mount) } else { TrimStatus::default() };
+
     // Evaluate health
     let text = &config.text;
     let health = evaluate_disk_health(write_speed, read_speed, seek_time, bad_sectors, is_ssd, text);
@@ -230,17 +370,37 @@ pub fn run_stress_test(
         disk_fs,
         disk_device: get_disk_device(disk_mount),
         write_speed_mb_s: write_speed,
+        write_speed_peak_mb_s,
+        write_speed_sustained_mb_s,
         read_speed_mb_s: read_speed,
         seek_time_ms: seek_time,
+        random_read_iops,
         bad_sectors,
+        read_retries,
         is_ssd,
+        trim,
         smart,
         health,
     }
 }
 
-/// Sequential write test - returns (speed_mb_s, success)
-fn write_test(path: &PathBuf, size_bytes: u64, chunk_size: usize, verbose: bool) -> (f64, bool) {
+/// Sustained mode write window; each one is synced to the device so the page cache can't hide the drive's speed
+const SUSTAINED_WINDOW_MB: u64 = 64;
+
+/// (peak, sustained) MB/s from per-window write speeds: the fastest window, and the mean of the
+/// final quarter (at least one window) once the SSD's SLC cache has been used up
+fn burst_and_sustained(windows: &[f64]) -> Option<(f64, f64)> {
+    if windows.is_empty() {
+        return None;
+    }
+    let peak = windows.iter().copied().fold(0.0, f64::max);
+    let tail = &windows[windows.len() - (windows.len() / 4).max(1)..];
+    Some((peak, tail.iter().sum::<f64>() / tail.len() as f64))
+}
+
+/// Sequential write test - returns (speed_mb_s, per-window speeds, success)
+/// `window_bytes`: sustained mode, time (and sync) every window; None keeps the single burst measurement
+fn write_test(path: &PathBuf, size_bytes: u64, chunk_size: usize, window_bytes: Option<u64>, verbose: bool) -> (f64, Vec<f64>, bool) {
     let file = match OpenOptions::new()
         .write(true)
         .create(true)
@@ -248,19 +408,40 @@ fn write_test(path: &PathBuf, size_bytes: u64, chunk_size: usize, verbose: bool)
         .open(path)
     {
         Ok(f) => f,
-        Err(_) => return (0.0, false),
+        Err(_) => return (0.0, Vec::new(), false),
     };
+    nocache::disable_caching(&file);
 
     let mut writer = BufWriter::new(file);
-    let pattern_byte: u8 = 0xA5;
-    let buffer = vec![pattern_byte; chunk_size];
+    let mut buffer = vec![0u8; chunk_size];
 
     let chunks = (size_bytes / chunk_size as u64) as usize;
+    let chunks_per_window = window_bytes.map(|bytes| (bytes / chunk_size as u64).max(1) as usize);
+    let mut windows = Vec::new();
     let start = Instant::now();
+    let mut window_start = start;
+    let mut window_chunks = 0;
 
     for i in 0..chunks {
-        if writer.write_all(&buffer).is_err() {
-            return (0.0, false);
+        fill_pattern(&mut buffer, (i * chunk_size) as u64);
+        if interrupted() || writer.write_all(&buffer).is_err() {
+            return (0.0, Vec::new(), false);
+        }
+
+        if let Some(per_window) = chunks_per_window {
+            window_chunks += 1;
+            if window_chunks == per_window || i + 1 == chunks {
+                if writer.flush().is_err() || writer.get_ref().sync_data().is_err() {
+                    return (0.0, Vec::new(), false);
+                }
+                let seconds = window_start.elapsed().as_secs_f64();
+                let mb = (window_chunks * chunk_size) as f64 / (1024.0 * 1024.0);
+                if seconds > 0.0 {
+                    windows.push(mb / seconds);
+                }
+                window_start = Instant::now();
+                window_chunks = 0;
+            }
         }
 
         // Progress update every 10%
@@ -272,7 +453,7 @@ fn write_test(path: &PathBuf, size_bytes: u64, chunk_size: usize, verbose: bool)
     }
 
     if writer.flush().is_err() {
-        return (0.0, false);
+        return (0.0, Vec::new(), false);
     }
 
     let elapsed = start.elapsed();
@@ -280,29 +461,93 @@ fn write_test(path: &PathBuf, size_bytes: u64, chunk_size: usize, verbose: bool)
     let mb_written = size_bytes as f64 / (1024.0 * 1024.0);
     let speed = if seconds > 0.0 { mb_written / seconds } else { 0.0 };
 
-    (speed, true)
+    (speed, windows, true)
 }
 
-/// Sequential read test with verification - returns (speed_mb_s, bad_sectors, success)
-fn read_test(path: &PathBuf, size_bytes: u64, chunk_size: usize, verbose: bool) -> (f64, u64, bool) {
+const SECTOR_SIZE: usize = 4096;
+/// Base test data, XORed with the sector number so every sector's content is unique
+const PATTERN_WORD: u64 = 0xA5A5_A5A5_A5A5_A5A5;
+
+/// Test data for the file region at byte `offset`: each 4K sector is stamped with its own position,
+/// so a drive returning another sector's data (misdirected write, stale block) reads back as a mismatch
+fn fill_pattern(buffer: &mut [u8], offset: u64) {
+    let first_sector = offset / SECTOR_SIZE as u64;
+    for (i, sector) in buffer.chunks_mut(SECTOR_SIZE).enumerate() {
+        let word = (PATTERN_WORD ^ (first_sector + i as u64)).to_le_bytes();
+        let head = sector.len().min(word.len());
+        sector[..head].copy_from_slice(&word[..head]);
+        // Double the filled prefix until the sector is full (a handful of memcpys per sector)
+        let mut filled = head;
+        while filled < sector.len() {
+            let count = filled.min(sector.len() - filled);
+            sector.copy_within(..count, filled);
+            filled += count;
+        }
+    }
+}
+
+/// Indexes of the 4K sectors in `chunk` that differ from `expected`
+fn mismatched_sectors(chunk: &[u8], expected: &[u8]) -> Vec<usize> {
+    chunk.chunks(SECTOR_SIZE)
+        .zip(expected.chunks(SECTOR_SIZE))
+        .enumerate()
+        .filter(|(_, (got, want))| got != want)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Split first-read mismatches into (confirmed, transient) by whether they mismatch again on re-read
+fn classify_mismatches(first: &[usize], reread: &[usize]) -> (u64, u64) {
+    let confirmed = first.iter().filter(|sector| reread.contains(sector)).count() as u64;
+    (confirmed, first.len() as u64 - confirmed)
+}
+
+/// Read the chunk at `offset` again from the drive (the first read left it cached); leaves the file positioned right after it
+fn reread_chunk(path: &Path, file: &mut File, offset: u64, buffer: &mut [u8]) -> io::Result<()> {
+    nocache::evict(path, file);
+    file.seek(SeekFrom::Start(offset))?;
+    file.read_exact(buffer)
+}
+
+/// Sequential read test with verification - returns (speed_mb_s, bad_sectors, read_retries, success)
+/// `verify`: mismatched chunks are re-read and only sectors that mismatch twice count as bad
+fn read_test(path: &PathBuf, size_bytes: u64, chunk_size: usize, verify: bool, verbose: bool) -> (f64, u64, u64, bool) {
     let mut file = match File::open(path) {
         Ok(f) => f,
-        Err(_) => return (0.0, 0, false),
+        Err(_) => return (0.0, 0, 0, false),
     };
+    // The data was just written: read it back from the drive, not the page cache
+    nocache::disable_caching(&file);
+    nocache::evict(path, &file);
 
-    let pattern_byte: u8 = 0xA5;
-    let expected_buffer = vec![pattern_byte; chunk_size];
+    let mut expected_buffer = vec![0u8; chunk_size];
     let mut read_buffer = vec![0u8; chunk_size];
 
     let chunks = (size_bytes / chunk_size as u64) as usize;
     let start = Instant::now();
     let mut bad_sectors = 0u64;
+    let mut confirmed_sectors = 0u64;
+    let mut read_retries = 0u64;
 
     for i in 0..chunks {
+        if interrupted() {
+            break;
+        }
         match file.read(&mut read_buffer) {
             Ok(n) if n == chunk_size => {
-                // Verify pattern
-                if read_buffer != expected_buffer {
+                // Verify against the pattern written at this position
+                fill_pattern(&mut expected_buffer, (i * chunk_size) as u64);
+                if read_buffer != expected_buffer && verify {
+                    // A sector that reads back correctly was a one-off glitch, not media damage
+                    let first = mismatched_sectors(&read_buffer, &expected_buffer);
+                    let reread = match reread_chunk(path, &mut file, (i * chunk_size) as u64, &mut read_buffer) {
+                        Ok(()) => mismatched_sectors(&read_buffer, &expected_buffer),
+                        Err(_) => first.clone(),
+                    };
+                    let (confirmed, transient) = classify_mismatches(&first, &reread);
+                    confirmed_sectors += confirmed;
+                    read_retries += transient;
+                } else if read_buffer != expected_buffer {
                     // Count byte mismatches as potential bad sectors
                     for (a, b) in read_buffer.iter().zip(expected_buffer.iter()) {
                         if a != b {
@@ -311,7 +556,7 @@ fn read_test(path: &PathBuf, size_bytes: u64, chunk_size: usize, verbose: bool)
                     }
                 }
             }
-            Ok(_) | Err(_) => return (0.0, 0, false),
+            Ok(_) | Err(_) => return (0.0, 0, 0, false),
         }
 
         // Progress update
@@ -327,10 +572,10 @@ fn read_test(path: &PathBuf, size_bytes: u64, chunk_size: usize, verbose: bool)
     let mb_read = size_bytes as f64 / (1024.0 * 1024.0);
     let speed = if seconds > 0.0 { mb_read / seconds } else { 0.0 };
 
-    // Convert byte errors to sector errors (4KB sectors)
-    let sector_errors = bad_sectors / 4096;
+    // Convert byte errors to sector errors (4KB sectors); verified sectors are already counted
+    let sector_errors = if verify { confirmed_sectors } else { bad_sectors / SECTOR_SIZE as u64 };
 
-    (speed, sector_errors, true)
+    (speed, sector_errors, read_retries, true)
 }
 
 /// Random access (seek) test - returns average seek time in ms
@@ -345,12 +590,10 @@ fn seek_test(path: &PathBuf, file_size: u64, iterations: u32, verbose: bool) ->
     let mut successful_seeks = 0u32;
 
     for i in 0..iterations {
-        // Random position aligned to 4KB
-        let max_pos = file_size.saturating_sub(4096);
-        if max_pos == 0 {
-            break;
-        }
-        let random_pos = (fastrand::u64(..) % max_pos) & !4095;
+        let random_pos = match random_4k_position(file_size) {
+            Some(pos) if !interrupted() => pos,
+            _ => break,
+        };
 
         let seek_start = Instant::now();
 
@@ -381,9 +624,48 @@ fn seek_test(path: &PathBuf, file_size: u64, iterations: u32, verbose: bool) ->
     (total_seek_time / successful_seeks as f64) / 1_000_000.0
 }
 
+/// Random 4KB-aligned offset for a 4KB read inside the file (None if the file is too small)
+fn random_4k_position(file_size: u64) -> Option<u64> {
+    let max_pos = file_size.saturating_sub(4096);
+    if max_pos == 0 {
+        return None;
+    }
+    Some((fastrand::u64(..) % max_pos) & !4095)
+}
+
+/// How long the random 4K read IOPS phase runs
+const IOPS_WINDOW: Duration = Duration::from_secs(3);
+const TEST_MODE_IOPS_WINDOW: Duration = Duration::from_millis(200);
+
+/// Random 4K read test - returns completed reads per second over `window`
+/// Reads bypass the page cache, which still holds the file from the earlier phases
+fn iops_test(path: &Path, file_size: u64, window: Duration) -> f64 {
+    let mut file = match nocache::open_uncached(path) {
+        Ok(f) => f,
+        Err(_) => return 0.0,
+    };
+
+    let mut block = nocache::AlignedBlock::new();
+    let read_buffer = &mut block.0;
+    let mut completed = 0u64;
+    let start = Instant::now();
+
+    while start.elapsed() < window && !interrupted() {
+        let Some(pos) = random_4k_position(file_size) else {
+            break;
+        };
+        if file.seek(SeekFrom::Start(pos)).is_ok() && file.read_exact(read_buffer).is_ok() {
+            completed += 1;
+        }
+    }
+
+    let secs = start.elapsed().as_secs_f64();
+    if secs > 0.0 { completed as f64 / secs } else { 0.0 }
+}
+
 /// Detect disk type (SSD/HDD)
 /// Platform-specific detection
-fn detect_disk_type(path: &PathBuf) -> bool {
+fn detect_disk_type(path: &Path) -> bool {
     // Try to get the mount point
     let default_mount = PathBuf::from("/");
     let mount_ref = path.ancestors().find(|p| {
@@ -406,7 +688,7 @@ fn detect_disk_type(path: &PathBuf) -> bool {
 
     #[cfg(target_os = "windows")]
     {
-        true // Default to SSD on Windows
+        check_windows_ssd(&mount)
     }
 
     #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
@@ -415,6 +697,51 @@ fn detect_disk_type(path: &PathBuf) -> bool {
     }
 }
 
+#[cfg(target_os = "windows")]
+fn check_windows_ssd(mount: &Path) -> bool {
+    use std::process::Command;
+    use crate::profile::TimedOutput;
+
+    // Drive letter of the test path (C:\Users\... -> C)
+    let letter = match mount.to_string_lossy().chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => c.to_ascii_uppercase(),
+        _ => return true, // Default to SSD
+    };
+
+    // Physical disk behind that drive letter
+    let script = format!(
+        "$n = (Get-Partition -DriveLetter {}).DiskNumber; \
+         Get-PhysicalDisk | Where-Object DeviceId -eq $n | ForEach-Object {{ \"$($_.MediaType) $($_.SpindleSpeed)\" }}",
+        letter
+    );
+    Command::new("powershell")
+        .args(["-NoProfile", "-Command", &script])
+        .timed_output()
+        .ok()
+        .and_then(|output| parse_windows_media_type(&String::from_utf8_lossy(&output.stdout)))
+        .unwrap_or(true)
+}
+
+/// Parse "<MediaType> <SpindleSpeed>" from Get-PhysicalDisk: Some(true) = SSD, Some(false) = HDD
+/// "Unspecified" media (common on RAID/USB bridges) counts as HDD when a spindle speed is reported
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_windows_media_type(output: &str) -> Option<bool> {
+    let mut parts = output.split_whitespace();
+    let media_type = parts.next()?;
+    let spindle_rpm: Option<u32> = parts.next().and_then(|s| s.parse().ok());
+
+    match media_type {
+        "SSD" | "SCM" | "4" | "5" => Some(true),
+        "HDD" | "3" => Some(false),
+        // u32::MAX means the spindle speed is unknown
+        _ => match spindle_rpm {
+            Some(0) => Some(true),
+            Some(rpm) if rpm != u32::MAX => Some(false),
+            _ => None,
+        },
+    }
+}
+
 #[cfg(target_os = "macos")]
 fn check_macos_ssd(mount: &PathBuf) -> bool {
     use std::process::Command;
@@ -456,8 +783,7 @@ fn check_macos_ssd(mount: &PathBuf) -> bool {
 }
 
 #[cfg(target_os = "linux")]
-fn check_linux_ssd(mount: &PathBuf) -> bool {
-    use std::path::Path;
+fn check_linux_ssd(mount: &Path) -> bool {
 
     // Try to find the block device for this mount
     let mount_str = mount.to_string_lossy().to_string();
@@ -472,7 +798,7 @@ fn check_linux_ssd(mount: &PathBuf) -> bool {
                 if let Some(name) = Path::new(device).file_name() {
                     let device_name = name.to_string_lossy();
                     // Trim partition number
-                    let base_name = device_name.trim_end_matches('0'..='9');
+                    let base_name = device_name.trim_end_matches(|c: char| c.is_ascii_digit());
 
                     // Check /sys/block/.../queue/rotational
                     let rotational_path = format!("/sys/block/{}/queue/rotational",
@@ -489,21 +815,72 @@ fn check_linux_ssd(mount: &PathBuf) -> bool {
     true // Default to SSD
 }
 
+/// Candidate directories for the test file, in order of preference
+pub fn candidate_test_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![std::env::temp_dir()];
+    if let Some(home) = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE")) {
+        dirs.push(PathBuf::from(home));
+    }
+    if let Ok(cwd) = std::env::current_dir() {
+        dirs.push(cwd);
+    }
+    dirs
+}
+
+/// Candidate directories for testing `mount`: the mount point itself, then fallbacks on the
+/// same disk, then the rest (a read-only mount still gets tested via temp_dir)
+fn test_dirs_for_mount(mount: &str) -> Vec<PathBuf> {
+    let mount = Path::new(mount);
+    let (same_disk, other): (Vec<_>, Vec<_>) = candidate_test_dirs()
+        .into_iter()
+        .partition(|dir| dir.starts_with(mount));
+    std::iter::once(mount.to_path_buf()).chain(same_disk).chain(other).collect()
+}
+
+/// Index of the disk whose mount point contains `path` (longest matching mount wins)
+pub fn disk_index_for_path(mounts: &[&str], path: &Path) -> Option<usize> {
+    mounts
+        .iter()
+        .enumerate()
+        .filter(|(_, mount)| path.starts_with(mount))
+        .max_by_key(|(_, mount)| Path::new(mount).components().count())
+        .map(|(i, _)| i)
+}
+
+/// Probe-write a tiny file to check that a directory is writable
+fn is_dir_writable(dir: &Path) -> bool {
+    let probe = dir.join(".pchecker_write_probe");
+    let writable = File::create(&probe)
+        .and_then(|mut f| f.write_all(b"ok"))
+        .is_ok();
+    let _ = std::fs::remove_file(&probe);
+    writable
+}
+
+/// Pick the first writable directory and return the test file path inside it
+pub fn find_writable_test_path(candidates: &[PathBuf], file_name: &str) -> Option<PathBuf> {
+    candidates
+        .iter()
+        .find(|dir| is_dir_writable(dir))
+        .map(|dir| dir.join(file_name))
+}
+
 /// Clean up test file
 fn cleanup_test_file(path: &PathBuf) {
     let _ = std::fs::remove_file(path);
 }
 
 /// Evaluate disk health based on test results
-fn evaluate_disk_health(write: f64, read: f64, seek: f64, bad_sectors: u64, is_ssd: bool, text: &Text) -> HealthStatus {
+pub fn evaluate_disk_health(write: f64, read: f64, seek: f64, bad_sectors: u64, is_ssd: bool, text: &Text) -> HealthStatus {
     let mut issues = Vec::new();
 
     // Critical: bad sectors detected
     if bad_sectors > 0 {
-        return HealthStatus::Failed(
+        return HealthStatus::Failed(Issue::new(
+            IssueCode::DiskBadSectors,
             text.disk_bad_sectors()
                 .replace("{}", &bad_sectors.to_string())
-        );
+        ));
     }
 
     // Speed thresholds differ for SSD vs HDD
@@ -515,38 +892,43 @@ fn evaluate_disk_health(write: f64, read: f64, seek: f64, bad_sectors: u64, is_s
 
     // Critical: extremely slow speeds
     if read < min_read {
-        return HealthStatus::Failed(
+        return HealthStatus::Failed(Issue::new(
+            IssueCode::DiskSlow,
             text.disk_slow_read()
                 .replace("{:.1}", &format!("{:.1}", read))
-        );
+        ));
     }
 
     if write < min_write {
-        return HealthStatus::Failed(
+        return HealthStatus::Failed(Issue::new(
+            IssueCode::DiskSlow,
             text.disk_slow_write()
                 .replace("{:.1}", &format!("{:.1}", write))
-        );
+        ));
     }
 
     // Issues: slow seek time (if seek test was run)
     if seek > 0.0 && seek > max_seek {
-        issues.push(
+        issues.push(Issue::new(
+            IssueCode::DiskSlow,
             text.disk_slow_seek()
                 .replace("{:.1}", &format!("{:.1}", seek))
-        );
+        ));
     }
 
     // Issues: speed warning (slower than expected but not critical)
     if is_ssd && read < 100.0 {
-        issues.push(
+        issues.push(Issue::new(
+            IssueCode::DiskSlow,
             text.disk_ssd_slow()
                 .replace("{:.1}", &format!("{:.1}", read))
-        );
+        ));
     } else if !is_ssd && read < 50.0 {
-        issues.push(
+        issues.push(Issue::new(
+            IssueCode::DiskSlow,
             text.disk_hdd_slow()
                 .replace("{:.1}", &format!("{:.1}", read))
-        );
+        ));
     }
 
     if !issues.is_empty() {
@@ -622,12 +1004,63 @@ mod tests {
     use super::*;
     use crate::lang::Language;
 
+    #[test]
+    fn test_parse_windows_media_type() {
+        assert_eq!(parse_windows_media_type("SSD 0\r\n"), Some(true));
+        assert_eq!(parse_windows_media_type("HDD 7200\r\n"), Some(false));
+        assert_eq!(parse_windows_media_type("Unspecified 5400"), Some(false));
+        assert_eq!(parse_windows_media_type("Unspecified 0"), Some(true));
+        assert_eq!(parse_windows_media_type("Unspecified 4294967295"), None);
+        assert_eq!(parse_windows_media_type(""), None);
+    }
+
+    #[test]
+    fn test_random_4k_reads() {
+        assert_eq!(random_4k_position(4096), None);
+        for _ in 0..100 {
+            let pos = random_4k_position(1024 * 1024).unwrap();
+            assert_eq!(pos % 4096, 0);
+            assert!(pos + 4096 <= 1024 * 1024);
+        }
+
+        // Small file whose end isn't 4KB-aligned: every read must stay inside it
+        let path = std::env::temp_dir().join(format!("pchecker_seek_{}.tmp", std::process::id()));
+        let size = 3 * 4096 + 100;
+        std::fs::write(&path, vec![0u8; size as usize]).unwrap();
+        let mut file = File::open(&path).unwrap();
+        let mut buffer = [0u8; 4096];
+        for _ in 0..200 {
+            let pos = random_4k_position(size).unwrap();
+            assert!(pos + 4096 <= size);
+            file.seek(SeekFrom::Start(pos)).unwrap();
+            file.read_exact(&mut buffer).unwrap();
+        }
+        assert!(seek_test(&path, size, 50, false) > 0.0);
+        let _ = std::fs::remove_file(&path);
+
+        let path = std::env::temp_dir().join(format!("pchecker_iops_{}.tmp", std::process::id()));
+        std::fs::write(&path, vec![0u8; 256 * 1024]).unwrap();
+        let iops = iops_test(&path, 256 * 1024, Duration::from_millis(50));
+        let _ = std::fs::remove_file(&path);
+        assert!(iops > 0.0);
+    }
+
+    #[test]
+    fn test_fits_in_free_space() {
+        assert!(fits_in_free_space(100, 50.0));
+        assert!(fits_in_free_space(1024, 1.0));
+        assert!(!fits_in_free_space(2048, 1.5));
+        assert!(fits_in_free_space(2048, 0.0));
+    }
+
     #[test]
     fn test_disk_test_small() {
         let config = DiskTestConfig {
-            test_path: Some("/tmp/pchecker_test.tmp".to_string()),
+            test_path: None,  // Writes onto the mount point below
             test_size_mb: 1,  // Only 1MB for quick test
             include_seek_test: false,
+            verify_mismatches: true,
+            sustained_mb: None,
             verbose: false,
             text: Text::new(Language::Vietnamese),
             on_comment: None,
@@ -647,6 +1080,81 @@ mod tests {
         assert!(matches!(result.health, HealthStatus::Healthy));
     }
 
+    #[test]
+    fn test_dirs_for_mount_order() {
+        let dirs = test_dirs_for_mount("/nonexistent/pchecker/mount");
+        assert_eq!(dirs[0], PathBuf::from("/nonexistent/pchecker/mount"));
+        assert!(dirs.contains(&std::env::temp_dir()));
+
+        // Unwritable mount falls back to temp_dir
+        let path = find_writable_test_path(&dirs, TEST_FILE_NAME).unwrap();
+        assert!(!path.starts_with("/nonexistent"));
+    }
+
+    #[test]
+    fn test_disk_index_for_path() {
+        let mounts = ["/", "/mnt/data", "/mnt/data2"];
+        assert_eq!(disk_index_for_path(&mounts, Path::new("/mnt/data/scratch")), Some(1));
+        assert_eq!(disk_index_for_path(&mounts, Path::new("/mnt/data2")), Some(2));
+        assert_eq!(disk_index_for_path(&mounts, Path::new("/home/user")), Some(0));
+        assert_eq!(disk_index_for_path(&mounts[1..], Path::new("/home/user")), None);
+    }
+
+    #[test]
+    fn test_find_writable_test_path() {
+        let missing = PathBuf::from("/nonexistent/pchecker/dir");
+        let tmp = std::env::temp_dir();
+
+        // Falls back past the unwritable candidate
+        let path = find_writable_test_path(&[missing.clone(), tmp.clone()], "test.tmp");
+        assert_eq!(path, Some(tmp.join("test.tmp")));
+
+        // No writable candidates
+        assert_eq!(find_writable_test_path(&[missing], "test.tmp"), None);
+    }
+
+    #[test]
+    fn test_burst_and_sustained() {
+        assert_eq!(burst_and_sustained(&[]), None);
+        assert_eq!(burst_and_sustained(&[500.0]), Some((500.0, 500.0)));
+
+        // SLC cache runs out after 4 windows: final quarter averages the slow tail
+        let windows = [2000.0, 2100.0, 2050.0, 1900.0, 400.0, 450.0, 420.0, 380.0];
+        assert_eq!(burst_and_sustained(&windows), Some((2100.0, 400.0)));
+    }
+
+    #[test]
+    fn test_fill_pattern_is_position_dependent() {
+        let mut chunk = vec![0u8; SECTOR_SIZE * 4];
+        fill_pattern(&mut chunk, 0);
+        let mut next_chunk = vec![0u8; SECTOR_SIZE * 4];
+        fill_pattern(&mut next_chunk, chunk.len() as u64);
+
+        // Every sector differs from its neighbours and from the same slot one chunk later
+        assert_ne!(chunk[..SECTOR_SIZE], chunk[SECTOR_SIZE..SECTOR_SIZE * 2]);
+        assert_eq!(mismatched_sectors(&next_chunk, &chunk), vec![0, 1, 2, 3]);
+        // Regenerating for the same offset matches exactly
+        let mut again = vec![0u8; SECTOR_SIZE * 4];
+        fill_pattern(&mut again, chunk.len() as u64);
+        assert_eq!(again, next_chunk);
+        assert_eq!(&chunk[..8], &0xA5A5_A5A5_A5A5_A5A5u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_mismatch_verification() {
+        let expected = vec![0xA5u8; SECTOR_SIZE * 4];
+        let mut chunk = expected.clone();
+        chunk[10] = 0;
+        chunk[SECTOR_SIZE * 2 + 1] = 0;
+        chunk[SECTOR_SIZE * 2 + 2] = 0;
+        assert_eq!(mismatched_sectors(&chunk, &expected), vec![0, 2]);
+        assert!(mismatched_sectors(&expected, &expected).is_empty());
+
+        // Sector 2 still bad on re-read, sector 0 was a glitch
+        assert_eq!(classify_mismatches(&[0, 2], &[2]), (1, 1));
+        assert_eq!(classify_mismatches(&[0, 2], &[]), (0, 2));
+    }
+
     #[test]
     fn test_evaluate_disk_health() {
         let text = Text::new(Language::Vietnamese);
@@ -666,13 +1174,13 @@ mod tests {
         // Failed - bad sectors
         assert!(matches!(
             evaluate_disk_health(500.0, 2000.0, 0.5, 1, true, &text),
-            HealthStatus::Failed(_)
+            HealthStatus::Failed(Issue { code: IssueCode::DiskBadSectors, .. })
         ));
 
         // Failed - extremely slow read (SSD)
         assert!(matches!(
             evaluate_disk_health(500.0, 20.0, 0.5, 0, true, &text),
-            HealthStatus::Failed(_)
+            HealthStatus::Failed(Issue { code: IssueCode::DiskSlow, .. })
         ));
 
         // Failed - extremely slow write (HDD)
@@ -0,0 +1,77 @@
+// Page-cache bypass for the disk test
+// The test file was just written, so without this the read-back and re-reads would be served from RAM
+
+use std::fs::File;
+use std::path::Path;
+
+/// Keep this handle's I/O out of the cache where the OS offers that per handle (macOS F_NOCACHE)
+#[cfg_attr(not(target_os = "macos"), allow(unused_variables))]
+pub fn disable_caching(file: &File) {
+    #[cfg(target_os = "macos")]
+    unsafe {
+        use std::os::unix::io::AsRawFd;
+        libc::fcntl(file.as_raw_fd(), libc::F_NOCACHE, 1);
+    }
+}
+
+/// Drop the file's cached pages so the next read goes to the drive
+/// Linux: sync, then POSIX_FADV_DONTNEED; Windows: opening an unbuffered handle purges the file's cache;
+/// macOS: nothing to drop when every handle has `disable_caching`
+// Linux needs the handle, Windows the path
+#[allow(unused_variables)]
+pub fn evict(path: &Path, file: &File) {
+    #[cfg(target_os = "linux")]
+    {
+        use std::os::unix::io::AsRawFd;
+        // Dirty pages can't be dropped, write them out first
+        let _ = file.sync_data();
+        unsafe {
+            libc::posix_fadvise(file.as_raw_fd(), 0, 0, libc::POSIX_FADV_DONTNEED);
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::OpenOptionsExt;
+        let _ = std::fs::OpenOptions::new().read(true).custom_flags(FILE_FLAG_NO_BUFFERING).open(path);
+    }
+}
+
+#[cfg(windows)]
+const FILE_FLAG_NO_BUFFERING: u32 = 0x2000_0000;
+
+/// 4K read buffer aligned for unbuffered I/O (O_DIRECT and FILE_FLAG_NO_BUFFERING need sector alignment)
+#[repr(C, align(4096))]
+pub struct AlignedBlock(pub [u8; 4096]);
+
+impl AlignedBlock {
+    pub fn new() -> Box<Self> {
+        Box::new(Self([0; 4096]))
+    }
+}
+
+/// Open `path` for 4K-aligned reads that bypass the cache: O_DIRECT on Linux, FILE_FLAG_NO_BUFFERING on
+/// Windows, F_NOCACHE on macOS; filesystems that refuse unbuffered I/O (e.g. tmpfs) get a plain handle
+/// with the file evicted first
+pub fn open_uncached(path: &Path) -> std::io::Result<File> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        if let Ok(file) = std::fs::OpenOptions::new().read(true).custom_flags(libc::O_DIRECT).open(path) {
+            return Ok(file);
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::OpenOptionsExt;
+        if let Ok(file) = std::fs::OpenOptions::new().read(true).custom_flags(FILE_FLAG_NO_BUFFERING).open(path) {
+            return Ok(file);
+        }
+    }
+
+    let file = File::open(path)?;
+    disable_caching(&file);
+    evict(path, &file);
+    Ok(file)
+}
@@ -1,10 +1,85 @@
 // SMART data module for disk health diagnostics
 // Platform-specific: macOS diskutil, Linux smartctl, Windows WMI
 
+use serde::Serialize;
 use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::profile::TimedOutput;
+
+/// Run smartctl through `sudo -n` (--privileged, after elevation was granted up front)
+static USE_SUDO: AtomicBool = AtomicBool::new(false);
+
+pub fn set_use_sudo(enabled: bool) {
+    USE_SUDO.store(enabled, Ordering::Relaxed);
+}
+
+/// --privileged: get root rights for smartctl before any test starts, instead of sudo
+/// asking for a password in the middle of the progress display
+/// `ask` = false never prompts (document output); only root or cached sudo credentials count then
+/// Returns false when elevation was declined or failed (SMART then uses unprivileged sources only)
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+pub fn request_privileges(text: &crate::lang::Text, ask: bool) -> bool {
+    // Already root: plain smartctl has full access
+    if crate::hw::deep::common::running_as_root() {
+        return true;
+    }
+
+    let cached = Command::new("sudo").args(["-n", "true"]).timed_output().is_ok_and(|o| o.status.success());
+    if cached {
+        set_use_sudo(true);
+        keep_sudo_alive();
+        return true;
+    }
+    if !ask {
+        return false;
+    }
+
+    // Nobody can type a password without a terminal, so the default is no
+    let confirmed = crate::prompt::read_choice_or_default(
+        &format!("🔐 {} [Y/n]: ", text.sudo_smart_prompt()),
+        "",
+        false,
+        |input| Some(!matches!(input.to_lowercase().as_str(), "n" | "no")),
+    );
+    // `sudo -v` asks for the password once and caches it for the smartctl calls
+    let granted = confirmed && Command::new("sudo").arg("-v").status().is_ok_and(|s| s.success());
+    set_use_sudo(granted);
+    if granted {
+        keep_sudo_alive();
+    }
+    granted
+}
+
+/// How often the sudo timestamp is refreshed (sudo's default timeout is 5 minutes)
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+const SUDO_REFRESH: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// SMART is read after the disk test, often past sudo's credential timeout, so refresh the
+/// cached credentials in the background until exit (`sudo -n -v` never prompts)
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+fn keep_sudo_alive() {
+    std::thread::spawn(|| loop {
+        std::thread::sleep(SUDO_REFRESH);
+        let refreshed = Command::new("sudo").args(["-n", "-v"]).timed_output().is_ok_and(|o| o.status.success());
+        if !refreshed {
+            break;
+        }
+    });
+}
+
+/// `smartctl -a <device>` output; through `sudo -n` only when --privileged was granted (never prompts)
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+fn run_smartctl(device: &str) -> Option<String> {
+    let output = if USE_SUDO.load(Ordering::Relaxed) {
+        Command::new("sudo").args(["-n", "smartctl", "-a", device]).timed_output()
+    } else {
+        Command::new("smartctl").args(["-a", device]).timed_output()
+    };
+    output.ok().map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+}
 
 /// SMART health data collected from disk
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SmartData {
     /// Overall SMART status (Verified/Failing/Unknown)
     pub status: SmartStatus,
@@ -42,7 +117,7 @@ pub struct SmartData {
     pub command_timeout: Option<u64>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum SmartStatus {
     Verified,
     Failing,
@@ -110,7 +185,7 @@ fn get_macos_smart_data(mount_point: &str, verbose: bool) -> SmartData {
     // Get disk info using diskutil (no sudo needed for basic info)
     if let Ok(output) = Command::new("diskutil")
         .args(["info", "-plist", &disk_identifier])
-        .output()
+        .timed_output()
     {
         let plist = String::from_utf8_lossy(&output.stdout);
         parse_diskutil_info(&plist, &mut result);
@@ -121,12 +196,7 @@ fn get_macos_smart_data(mount_point: &str, verbose: bool) -> SmartData {
         // Try smartctl first (best source for detailed SMART data)
         // macOS smartctl provides similar output to Linux version
         let rdisk = disk_identifier.replace("disk", "rdisk");
-        if let Ok(output) = Command::new("sh")
-            .arg("-c")
-            .arg(format!("sudo smartctl -a /dev/{} 2>/dev/null || smartctl -a /dev/{} 2>/dev/null", rdisk, rdisk))
-            .output()
-        {
-            let smartctl = String::from_utf8_lossy(&output.stdout);
+        if let Some(smartctl) = run_smartctl(&format!("/dev/{}", rdisk)) {
             if !smartctl.trim().is_empty() && smartctl.contains("SMART") {
                 parse_smartctl_output(&smartctl, &mut result);
             }
@@ -136,7 +206,7 @@ fn get_macos_smart_data(mount_point: &str, verbose: bool) -> SmartData {
         if result.model.is_none() {
             if let Ok(output) = Command::new("diskutil")
                 .args(["info", &disk_identifier])
-                .output()
+                .timed_output()
             {
                 let info = String::from_utf8_lossy(&output.stdout);
                 parse_diskutil_verbose_info(&info, &mut result);
@@ -147,7 +217,7 @@ fn get_macos_smart_data(mount_point: &str, verbose: bool) -> SmartData {
         if result.temperature_c.is_none() {
             if let Ok(output) = Command::new("ioreg")
                 .args(["-rn", "AppleARMIODevice"])
-                .output()
+                .timed_output()
             {
                 let ioreg = String::from_utf8_lossy(&output.stdout);
                 result.temperature_c = parse_ioreg_temp(&ioreg);
@@ -163,7 +233,7 @@ fn get_disk_identifier_from_mount(mount_point: &str) -> String {
     // Get disk identifier from mount point using df
     if let Ok(output) = Command::new("df")
         .arg(mount_point)
-        .output()
+        .timed_output()
     {
         let stdout = String::from_utf8_lossy(&output.stdout);
         if let Some(line) = stdout.lines().nth(1) {
@@ -176,24 +246,99 @@ fn get_disk_identifier_from_mount(mount_point: &str) -> String {
     "disk0".to_string()
 }
 
-#[cfg(target_os = "macos")]
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
 fn parse_diskutil_info(plist: &str, result: &mut SmartData) {
-    // Parse SMART status from plist
-    let lines: Vec<&str> = plist.lines().collect();
-    for (i, line) in lines.iter().enumerate() {
-        let line = line.trim();
+    let info = parse_plist_dict(plist);
 
-        // Check for SMART status
-        if line.contains("<key>SMARTStatus</key>")
-            && i + 1 < lines.len() {
-                let next_line = lines[i + 1].trim();
-                if next_line.contains("Verified") || next_line.contains("true") {
-                    result.status = SmartStatus::Verified;
-                } else if next_line.contains("Failing") || next_line.contains("false") {
-                    result.status = SmartStatus::Failing;
+    // "Verified", "Failing" or "Not Supported" (external / virtual disks)
+    match info.get("SMARTStatus").map(String::as_str) {
+        Some("Verified") => result.status = SmartStatus::Verified,
+        Some("Failing") => result.status = SmartStatus::Failing,
+        _ => {}
+    }
+
+    if let Some(model) = info.get("MediaName").filter(|m| !m.is_empty()) {
+        result.model = Some(model.clone());
+    }
+    if let Some(firmware) = info.get("FirmwareRevision").filter(|f| !f.is_empty()) {
+        result.firmware = Some(firmware.clone());
+    }
+}
+
+/// Parse the top-level scalar values of an XML plist `<dict>`
+/// Tag-based rather than line-based, so formatting/whitespace doesn't matter;
+/// nested dicts and arrays (e.g. APFSPhysicalStores) are skipped
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_plist_dict(xml: &str) -> std::collections::HashMap<String, String> {
+    let mut values = std::collections::HashMap::new();
+    let mut depth = 0usize; // 1 = top-level dict
+    let mut pending_key: Option<String> = None;
+    let mut rest = xml;
+
+    while let Some(start) = rest.find('<') {
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('>') else { break };
+        let tag = after[..end].trim();
+        rest = &after[end + 1..];
+
+        // <?xml ...?>, <!DOCTYPE ...>
+        if tag.starts_with('?') || tag.starts_with('!') {
+            continue;
+        }
+
+        // <true/>, <false/>, empty <dict/> / <array/>
+        if let Some(name) = tag.strip_suffix('/') {
+            if let (1, Some(key)) = (depth, pending_key.take()) {
+                let name = name.trim();
+                if name == "true" || name == "false" {
+                    values.insert(key, name.to_string());
+                }
+            }
+            continue;
+        }
+
+        if let Some(name) = tag.strip_prefix('/') {
+            if matches!(name.trim(), "dict" | "array") {
+                depth = depth.saturating_sub(1);
+            }
+            continue;
+        }
+
+        match tag.split_whitespace().next().unwrap_or("") {
+            "dict" | "array" => {
+                depth += 1;
+                if depth > 1 {
+                    pending_key = None;
                 }
             }
+            name @ ("key" | "string" | "integer" | "real" | "date" | "data") => {
+                let close = format!("</{}>", name);
+                let content_end = rest.find(&close).unwrap_or(rest.len());
+                let content = unescape_xml(rest[..content_end].trim());
+                rest = &rest[(content_end + close.len()).min(rest.len())..];
+
+                if depth == 1 {
+                    if name == "key" {
+                        pending_key = Some(content);
+                    } else if let Some(key) = pending_key.take() {
+                        values.insert(key, content);
+                    }
+                }
+            }
+            _ => {}
+        }
     }
+
+    values
+}
+
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn unescape_xml(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
 }
 
 #[cfg(target_os = "macos")]
@@ -264,14 +409,9 @@ fn get_linux_smart_data(mount_point: &str, verbose: bool) -> SmartData {
     // Find device from mount point
     let device = find_device_for_mount(mount_point);
 
-    // Verbose mode: try smartctl (requires sudo)
+    // Verbose mode: try smartctl (needs root, see --privileged)
     if verbose {
-        if let Ok(output) = Command::new("sh")
-            .arg("-c")
-            .arg(&format!("sudo smartctl -a {} 2>/dev/null || smartctl -a {} 2>/dev/null", device, device))
-            .output()
-        {
-            let smartctl = String::from_utf8_lossy(&output.stdout);
+        if let Some(smartctl) = run_smartctl(&device) {
             parse_smartctl_output(&smartctl, &mut result);
         }
     }
@@ -303,6 +443,12 @@ fn find_device_for_mount(mount_point: &str) -> String {
 
 // Make this function available on both platforms
 fn parse_smartctl_output(output: &str, result: &mut SmartData) {
+    // NVMe drives report a key-value health log instead of the ATA attribute table
+    if output.contains("NVMe") {
+        parse_nvme_smartctl_output(output, result);
+        return;
+    }
+
     for line in output.lines() {
         let line = line.trim();
 
@@ -459,6 +605,49 @@ fn parse_smartctl_output(output: &str, result: &mut SmartData) {
     }
 }
 
+/// Parse the NVMe "SMART/Health Information" log printed by smartctl
+fn parse_nvme_smartctl_output(output: &str, result: &mut SmartData) {
+    // Counters look like "1,234", "3%" or "12,345,678 [6.32 TB]"
+    let parse_count = |value: &str| -> Option<u64> {
+        value.split_whitespace().next()?.trim_end_matches('%').replace(',', "").parse().ok()
+    };
+
+    for line in output.lines() {
+        let Some((key, value)) = line.split_once(':') else { continue };
+        let value = value.trim();
+        match key.trim() {
+            "SMART overall-health self-assessment test result" => {
+                if value.contains("PASSED") {
+                    result.status = SmartStatus::Verified;
+                } else if value.contains("FAILED") {
+                    result.status = SmartStatus::Failing;
+                }
+            }
+            "Model Number" => result.model = Some(value.to_string()),
+            "Serial Number" => result.serial = Some(value.to_string()),
+            "Firmware Version" => result.firmware = Some(value.to_string()),
+            "Temperature" => {
+                if let Some(temp) = parse_count(value) {
+                    result.temperature_c = Some(temp as f64);
+                }
+            }
+            "Percentage Used" => {
+                // Can exceed 100% on drives past their rated endurance
+                if let Some(used) = parse_count(value) {
+                    result.ssd_life_left = Some(100 - used.min(100) as u8);
+                }
+            }
+            // One data unit is 1000 512-byte blocks
+            "Data Units Written" => result.total_lbas_written = parse_count(value).map(|units| units * 1000),
+            "Data Units Read" => result.total_lbas_read = parse_count(value).map(|units| units * 1000),
+            "Power On Hours" => result.power_on_hours = parse_count(value),
+            "Power Cycles" => result.power_cycle_count = parse_count(value),
+            "Media and Data Integrity Errors" => result.media_errors = parse_count(value),
+            _ => {}
+        }
+    }
+}
+
 // =============================================================================
 // Windows implementation
 // =============================================================================
@@ -472,7 +661,7 @@ fn get_windows_smart_data(verbose: bool) -> SmartData {
         // Get disk status
         if let Ok(output) = Command::new("wmic")
             .args(&["diskdrive", "get", "status", "/format:list"])
-            .output()
+            .timed_output()
         {
             let wmic = String::from_utf8_lossy(&output.stdout);
             for line in wmic.lines() {
@@ -487,7 +676,7 @@ fn get_windows_smart_data(verbose: bool) -> SmartData {
         // Get model
         if let Ok(output) = Command::new("wmic")
             .args(&["diskdrive", "get", "model", "/format:list"])
-            .output()
+            .timed_output()
         {
             let wmic = String::from_utf8_lossy(&output.stdout);
             for line in wmic.lines() {
@@ -500,7 +689,7 @@ fn get_windows_smart_data(verbose: bool) -> SmartData {
         // Get serial
         if let Ok(output) = Command::new("wmic")
             .args(&["diskdrive", "get", "serialnumber", "/format:list"])
-            .output()
+            .timed_output()
         {
             let wmic = String::from_utf8_lossy(&output.stdout);
             for line in wmic.lines() {
@@ -513,7 +702,7 @@ fn get_windows_smart_data(verbose: bool) -> SmartData {
         // Get firmware
         if let Ok(output) = Command::new("wmic")
             .args(&["diskdrive", "get", "firmwarerevision", "/format:list"])
-            .output()
+            .timed_output()
         {
             let wmic = String::from_utf8_lossy(&output.stdout);
             for line in wmic.lines() {
@@ -527,7 +716,7 @@ fn get_windows_smart_data(verbose: bool) -> SmartData {
         // Get-PhysicalDisk cmdlet provides health info on Windows 8+
         if let Ok(output) = Command::new("powershell")
             .args(&["-Command", "Get-PhysicalDisk | Select-Object HealthStatus, MediaType, Size | Format-List"])
-            .output()
+            .timed_output()
         {
             let ps = String::from_utf8_lossy(&output.stdout);
             for line in ps.lines() {
@@ -552,30 +741,48 @@ fn get_windows_smart_data(verbose: bool) -> SmartData {
         // Try to get SMART data via PowerShell and storage module
         if let Ok(output) = Command::new("powershell")
             .args(&["-Command",
-                "Get-StorageReliabilityCounter | Select-Object Temperature, Wear, TotalLbasRead, TotalLbasWritten | Format-List"])
-            .output()
+                "Get-StorageReliabilityCounter | Select-Object Temperature, Wear, PowerOnHours, StartStopCycleCount, TotalLbasRead, TotalLbasWritten | Format-List"])
+            .timed_output()
         {
-            let ps = String::from_utf8_lossy(&output.stdout);
-            for line in ps.lines() {
-                if line.contains("Temperature") {
-                    if let Some(temp_str) = line.split(':').nth(1) {
-                        if let Ok(temp) = temp_str.trim().parse::<f64>() {
-                            result.temperature_c = Some(temp);
-                        }
-                    }
+            parse_storage_reliability_counter(&String::from_utf8_lossy(&output.stdout), &mut result);
+        }
+    }
+
+    result
+}
+
+/// Parse `Get-StorageReliabilityCounter | Format-List` output
+/// Non-admin runs print empty values, which leave the fields as None
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_storage_reliability_counter(ps: &str, result: &mut SmartData) {
+    for line in ps.lines() {
+        let Some((key, value)) = line.split_once(':') else { continue };
+        let value = value.trim();
+        match key.trim() {
+            "Temperature" => {
+                if let Ok(temp) = value.parse::<f64>() {
+                    result.temperature_c = Some(temp);
                 }
-                if line.contains("Wear") {
-                    if let Some(wear_str) = line.split(':').nth(1) {
-                        if let Ok(wear) = wear_str.trim().parse::<u8>() {
-                            result.ssd_life_left = Some(wear);
-                        }
-                    }
+            }
+            // Wear is the percentage of rated life used
+            "Wear" => {
+                if let Ok(wear) = value.parse::<u8>() {
+                    result.ssd_life_left = Some(100 - wear.min(100));
+                }
+            }
+            "PowerOnHours" => {
+                if let Ok(hours) = value.parse::<u64>() {
+                    result.power_on_hours = Some(hours);
                 }
             }
+            "StartStopCycleCount" => {
+                if let Ok(cycles) = value.parse::<u64>() {
+                    result.power_cycle_count = Some(cycles);
+                }
+            }
+            _ => {}
         }
     }
-
-    result
 }
 
 #[cfg(test)]
@@ -589,9 +796,152 @@ mod tests {
         assert!(data.temperature_c.is_none());
     }
 
+    #[test]
+    fn test_parse_storage_reliability_counter() {
+        let ps = "\r\nTemperature         : 38\r\nWear                : 2\r\nPowerOnHours        : 5123\r\nStartStopCycleCount : 871\r\nTotalLbasRead       :\r\n";
+        let mut result = SmartData::default();
+        parse_storage_reliability_counter(ps, &mut result);
+        assert_eq!(result.temperature_c, Some(38.0));
+        assert_eq!(result.ssd_life_left, Some(98));
+        assert_eq!(result.power_on_hours, Some(5123));
+        assert_eq!(result.power_cycle_count, Some(871));
+
+        // Non-admin: the cmdlet prints the keys with empty values
+        let ps = "Temperature         :\r\nPowerOnHours        :\r\nStartStopCycleCount :\r\n";
+        let mut result = SmartData::default();
+        parse_storage_reliability_counter(ps, &mut result);
+        assert!(result.temperature_c.is_none());
+        assert!(result.power_on_hours.is_none());
+        assert!(result.power_cycle_count.is_none());
+        parse_storage_reliability_counter("", &mut result);
+        assert!(result.power_on_hours.is_none());
+    }
+
+    #[test]
+    fn test_parse_smartctl_nvme() {
+        let output = "\
+=== START OF INFORMATION SECTION ===
+Model Number:                       Samsung SSD 970 EVO Plus 1TB
+Serial Number:                      S4EWNX0N123456
+Firmware Version:                   2B2QEXM7
+PCI Vendor/Subsystem ID:            0x144d
+
+=== START OF SMART DATA SECTION ===
+SMART overall-health self-assessment test result: PASSED
+
+SMART/Health Information (NVMe Log 0x02)
+Critical Warning:                   0x00
+Temperature:                        40 Celsius
+Available Spare:                    100%
+Percentage Used:                    3%
+Data Units Read:                    12,345,678 [6.32 TB]
+Data Units Written:                 23,456,789 [12.0 TB]
+Power Cycles:                       1,234
+Power On Hours:                     5,678
+Media and Data Integrity Errors:    0
+";
+        let mut result = SmartData::default();
+        parse_smartctl_output(output, &mut result);
+        assert_eq!(result.status, SmartStatus::Verified);
+        assert_eq!(result.model.as_deref(), Some("Samsung SSD 970 EVO Plus 1TB"));
+        assert_eq!(result.serial.as_deref(), Some("S4EWNX0N123456"));
+        assert_eq!(result.temperature_c, Some(40.0));
+        assert_eq!(result.ssd_life_left, Some(97));
+        assert_eq!(result.total_lbas_written, Some(23_456_789_000));
+        assert_eq!(result.total_lbas_read, Some(12_345_678_000));
+        assert_eq!(result.power_on_hours, Some(5678));
+        assert_eq!(result.power_cycle_count, Some(1234));
+        assert_eq!(result.media_errors, Some(0));
+
+        // Worn past rated endurance
+        let mut result = SmartData::default();
+        parse_smartctl_output("NVMe Log\nPercentage Used: 112%\n", &mut result);
+        assert_eq!(result.ssd_life_left, Some(0));
+    }
+
     #[test]
     fn test_smart_status_equality() {
         assert_eq!(SmartStatus::Verified, SmartStatus::Verified);
         assert_ne!(SmartStatus::Verified, SmartStatus::Failing);
     }
+
+    #[test]
+    fn test_parse_diskutil_info_ssd() {
+        let plist = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<key>BusProtocol</key>
+	<string>Apple Fabric</string>
+	<key>DeviceIdentifier</key>
+	<string>disk0</string>
+	<key>Internal</key>
+	<true/>
+	<key>MediaName</key>
+	<string>APPLE SSD AP0512Q</string>
+	<key>SMARTStatus</key>
+	<string>Verified</string>
+	<key>SolidState</key>
+	<true/>
+	<key>TotalSize</key>
+	<integer>500277792768</integer>
+</dict>
+</plist>
+"#;
+        let mut result = SmartData::default();
+        parse_diskutil_info(plist, &mut result);
+        assert_eq!(result.status, SmartStatus::Verified);
+        assert_eq!(result.model.as_deref(), Some("APPLE SSD AP0512Q"));
+
+        // Same data with key and value on one line and odd whitespace
+        let compact = "<plist><dict><key>SMARTStatus</key>  <string>\n  Failing </string><key>MediaName</key><string>WDC &amp; Co</string></dict></plist>";
+        let mut result = SmartData::default();
+        parse_diskutil_info(compact, &mut result);
+        assert_eq!(result.status, SmartStatus::Failing);
+        assert_eq!(result.model.as_deref(), Some("WDC & Co"));
+    }
+
+    #[test]
+    fn test_parse_diskutil_info_fusion_apfs() {
+        let plist = r#"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0">
+<dict>
+	<key>APFSContainerReference</key>
+	<string>disk2</string>
+	<key>APFSPhysicalStores</key>
+	<array>
+		<dict>
+			<key>APFSPhysicalStore</key>
+			<string>disk0s2</string>
+			<key>SMARTStatus</key>
+			<string>Failing</string>
+		</dict>
+		<dict>
+			<key>APFSPhysicalStore</key>
+			<string>disk1s2</string>
+		</dict>
+	</array>
+	<key>Fusion</key>
+	<true/>
+	<key>MediaName</key>
+	<string></string>
+	<key>SMARTStatus</key>
+	<string>Verified</string>
+</dict>
+</plist>
+"#;
+        let info = parse_plist_dict(plist);
+        assert_eq!(info.get("Fusion").map(String::as_str), Some("true"));
+        assert!(!info.contains_key("APFSPhysicalStore"));
+
+        let mut result = SmartData::default();
+        parse_diskutil_info(plist, &mut result);
+        assert_eq!(result.status, SmartStatus::Verified);
+        assert!(result.model.is_none());
+
+        // Not Supported (external enclosure) leaves status unknown
+        let mut result = SmartData::default();
+        parse_diskutil_info("<dict><key>SMARTStatus</key><string>Not Supported</string></dict>", &mut result);
+        assert_eq!(result.status, SmartStatus::Unknown);
+    }
 }
@@ -0,0 +1,274 @@
+// TRIM detection module for SSD health diagnostics
+// Platform-specific: macOS diskutil/system_profiler, Linux sysfs/mount options, Windows fsutil
+
+#[cfg(any(target_os = "macos", target_os = "windows", target_os = "linux"))]
+use std::process::Command;
+#[cfg(any(target_os = "macos", target_os = "windows", target_os = "linux"))]
+use crate::profile::TimedOutput;
+use serde::Serialize;
+
+/// TRIM support and status for a volume
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TrimStatus {
+    /// Device accepts discard/TRIM commands (None if unknown)
+    pub supported: Option<bool>,
+    /// TRIM is actually issued by the OS (mount option, timer or OS setting)
+    pub enabled: Option<bool>,
+}
+
+impl TrimStatus {
+    /// Supported by the device but not enabled - degrades SSD performance over time
+    pub fn needs_attention(&self) -> bool {
+        self.supported == Some(true) && self.enabled == Some(false)
+    }
+}
+
+/// Get TRIM status for the volume at the given mount point
+pub fn get_trim_status(mount_point: &str) -> TrimStatus {
+    #[cfg(target_os = "macos")]
+    {
+        get_macos_trim_status(mount_point)
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        get_linux_trim_status(mount_point)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        get_windows_trim_status(mount_point)
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+    {
+        let _ = mount_point;
+        TrimStatus::default()
+    }
+}
+
+// =============================================================================
+// macOS implementation
+// =============================================================================
+
+#[cfg(target_os = "macos")]
+fn get_macos_trim_status(mount_point: &str) -> TrimStatus {
+    // The volume's physical disk, e.g. disk0 for an APFS volume on disk0s2
+    let disk = match Command::new("diskutil").args(["info", mount_point]).timed_output() {
+        Ok(output) => whole_disk_for_volume(&String::from_utf8_lossy(&output.stdout)),
+        Err(_) => None,
+    };
+    let Some(disk) = disk else {
+        return TrimStatus::default();
+    };
+
+    // NVMe and SATA SSDs both report "TRIM Support: Yes/No"
+    match Command::new("system_profiler")
+        .args(["SPNVMeDataType", "SPSerialATADataType"])
+        .timed_output()
+    {
+        Ok(output) => parse_system_profiler_trim(&String::from_utf8_lossy(&output.stdout), &disk),
+        Err(_) => TrimStatus::default(),
+    }
+}
+
+/// Whole physical disk behind a volume, from `diskutil info <mount point>`
+/// APFS volumes live on a synthesized container disk, so their physical store is used instead
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn whole_disk_for_volume(info: &str) -> Option<String> {
+    let field = |name: &str| {
+        info.lines()
+            .find_map(|l| l.trim().strip_prefix(name))
+            .and_then(|v| v.strip_prefix(':'))
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    let device = field("APFS Physical Store").or_else(|| field("Part of Whole"))?;
+
+    // disk0s2 -> disk0
+    let number_end = device.strip_prefix("disk")?.find('s').map_or(device.len(), |i| i + "disk".len());
+    Some(device[..number_end].to_string())
+}
+
+/// Parse "TRIM Support: Yes" for the device with BSD name `disk` from system_profiler output
+/// Each device block lists "TRIM Support" before its "BSD Name"
+/// macOS issues TRIM automatically when supported (trimforce for 3rd-party SATA)
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_system_profiler_trim(output: &str, disk: &str) -> TrimStatus {
+    let mut trim = None;
+    for line in output.lines().map(str::trim) {
+        if let Some(value) = line.strip_prefix("TRIM Support:") {
+            trim = Some(value.trim().eq_ignore_ascii_case("yes"));
+        } else if line.strip_prefix("BSD Name:").is_some_and(|name| name.trim() == disk) {
+            return TrimStatus { supported: trim, enabled: trim };
+        }
+    }
+    TrimStatus::default()
+}
+
+// =============================================================================
+// Linux implementation
+// =============================================================================
+
+#[cfg(target_os = "linux")]
+fn get_linux_trim_status(mount_point: &str) -> TrimStatus {
+    let mounts = std::fs::read_to_string("/proc/mounts").unwrap_or_default();
+    let entry = mounts.lines().find_map(|line| {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        (parts.len() >= 4 && parts[1] == mount_point).then(|| (parts[0].to_string(), parts[3].to_string()))
+    });
+
+    let (device, options) = match entry {
+        Some(e) => e,
+        None => return TrimStatus::default(),
+    };
+
+    // Device support: non-zero discard_max_bytes on the whole disk
+    let supported = linux_block_device(&device)
+        .and_then(|dev| std::fs::read_to_string(format!("/sys/block/{}/queue/discard_max_bytes", dev)).ok())
+        .and_then(|s| s.trim().parse::<u64>().ok())
+        .map(|max| max > 0);
+
+    // Enabled: continuous discard mount option or periodic fstrim timer
+    let enabled = if mount_has_discard(&options) {
+        Some(true)
+    } else {
+        Command::new("systemctl")
+            .args(["is-enabled", "fstrim.timer"])
+            .timed_output()
+            .ok()
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim() == "enabled")
+    };
+
+    TrimStatus { supported, enabled }
+}
+
+/// Resolve a partition device (/dev/nvme0n1p2) to its whole-disk name (nvme0n1)
+#[cfg(target_os = "linux")]
+fn linux_block_device(device: &str) -> Option<String> {
+    let name = std::path::Path::new(device).file_name()?.to_string_lossy().to_string();
+    let sys_path = std::fs::canonicalize(format!("/sys/class/block/{}", name)).ok()?;
+
+    if sys_path.join("partition").exists() {
+        sys_path.parent()?.file_name().map(|n| n.to_string_lossy().to_string())
+    } else {
+        Some(name)
+    }
+}
+
+/// Check mount options for continuous discard ("discard" or "discard=async")
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn mount_has_discard(options: &str) -> bool {
+    options.split(',').any(|o| o == "discard" || o.starts_with("discard="))
+}
+
+// =============================================================================
+// Windows implementation
+// =============================================================================
+
+#[cfg(target_os = "windows")]
+fn get_windows_trim_status(mount_point: &str) -> TrimStatus {
+    // fsutil wants "C:", not "C:\"
+    let volume = mount_point.trim_end_matches('\\');
+    let fsutil = |args: &[&str]| {
+        Command::new("fsutil")
+            .args(args)
+            .timed_output()
+            .ok()
+            .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+    };
+
+    // Device support for this volume: "Trim Supported" / "Trim Not Supported"
+    let supported = fsutil(&["fsinfo", "sectorinfo", volume]).and_then(|out| parse_sectorinfo_trim(&out));
+
+    // The OS setting is per file system, so read the line for the volume's own (NTFS or ReFS)
+    let file_system = fsutil(&["fsinfo", "volumeinfo", volume])
+        .and_then(|out| parse_volume_file_system(&out))
+        .unwrap_or_else(|| "NTFS".to_string());
+    let enabled = fsutil(&["behavior", "query", "DisableDeleteNotify"])
+        .and_then(|out| parse_disable_delete_notify(&out, &file_system));
+
+    TrimStatus { supported, enabled }
+}
+
+/// Parse the "Trim Supported" / "Trim Not Supported" line of `fsutil fsinfo sectorinfo`
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_sectorinfo_trim(output: &str) -> Option<bool> {
+    output.lines().map(str::trim).find_map(|l| match l {
+        "Trim Supported" => Some(true),
+        "Trim Not Supported" => Some(false),
+        _ => None,
+    })
+}
+
+/// Parse "File System Name : NTFS" from `fsutil fsinfo volumeinfo`
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_volume_file_system(output: &str) -> Option<String> {
+    let line = output.lines().find(|l| l.trim_start().starts_with("File System Name"))?;
+    Some(line.split(':').nth(1)?.trim().to_string()).filter(|fs| !fs.is_empty())
+}
+
+/// Parse "NTFS DisableDeleteNotify = 0  (Disabled)" for `file_system` - 0 means TRIM is enabled
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_disable_delete_notify(output: &str, file_system: &str) -> Option<bool> {
+    let prefix = format!("{} DisableDeleteNotify", file_system);
+    let line = output.lines().find(|l| l.trim_start().starts_with(&prefix))?;
+    let value = line.split('=').nth(1)?.split_whitespace().next()?;
+    match value {
+        "0" => Some(true),
+        "1" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_system_profiler_trim() {
+        let output = "    Apple SSD Controller:\n\n      TRIM Support: Yes\n      BSD Name: disk0\n        Volumes:\n          BSD Name: disk0s1\n\n    \
+            Samsung SSD 870:\n      TRIM Support: No\n      BSD Name: disk4\n";
+        assert_eq!(parse_system_profiler_trim(output, "disk0"), TrimStatus { supported: Some(true), enabled: Some(true) });
+        assert_eq!(parse_system_profiler_trim(output, "disk4").enabled, Some(false));
+        assert_eq!(parse_system_profiler_trim(output, "disk2"), TrimStatus::default());
+    }
+
+    #[test]
+    fn test_whole_disk_for_volume() {
+        let apfs = "   Device Identifier:         disk3s1\n   Part of Whole:             disk3\n   APFS Physical Store:       disk0s2\n";
+        assert_eq!(whole_disk_for_volume(apfs).as_deref(), Some("disk0"));
+        assert_eq!(whole_disk_for_volume("   Part of Whole:             disk12\n").as_deref(), Some("disk12"));
+        assert_eq!(whole_disk_for_volume("Could not find disk"), None);
+    }
+
+    #[test]
+    fn test_mount_has_discard() {
+        assert!(mount_has_discard("rw,relatime,discard"));
+        assert!(mount_has_discard("rw,noatime,discard=async,ssd"));
+        assert!(!mount_has_discard("rw,relatime,nodiscard"));
+    }
+
+    #[test]
+    fn test_parse_disable_delete_notify() {
+        let output = "NTFS DisableDeleteNotify = 0  (Disabled)\nReFS DisableDeleteNotify = 1  (Enabled)";
+        assert_eq!(parse_disable_delete_notify(output, "NTFS"), Some(true));
+        assert_eq!(parse_disable_delete_notify(output, "ReFS"), Some(false));
+        assert_eq!(parse_disable_delete_notify("garbage", "NTFS"), None);
+    }
+
+    #[test]
+    fn test_parse_windows_volume_info() {
+        let sectorinfo = "LogicalBytesPerSector :                                 512\r\nTrim Supported\r\nNot DAX capable\r\n";
+        assert_eq!(parse_sectorinfo_trim(sectorinfo), Some(true));
+        assert_eq!(parse_sectorinfo_trim("Trim Not Supported"), Some(false));
+        assert_eq!(parse_sectorinfo_trim(""), None);
+        assert_eq!(parse_volume_file_system("Volume Name : \r\nFile System Name : ReFS\r\n").as_deref(), Some("ReFS"));
+    }
+
+    #[test]
+    fn test_needs_attention() {
+        assert!(TrimStatus { supported: Some(true), enabled: Some(false) }.needs_attention());
+        assert!(!TrimStatus { supported: None, enabled: Some(false) }.needs_attention());
+    }
+}
@@ -0,0 +1,162 @@
+// Expected performance module
+// Built-in baselines to compare test results against the detected hardware (--compare-to-model)
+
+use serde::Serialize;
+
+/// Below this percentage of the expected value a component is flagged as underperforming
+pub const UNDERPERFORM_THRESHOLD_PCT: f64 = 80.0;
+
+/// Measured vs expected performance for one component
+#[derive(Debug, Clone, Serialize)]
+pub struct PerfCheck {
+    pub component: &'static str,
+    /// Hardware the baseline was chosen for (e.g. "AMD Ryzen 7 5800X", "SSD")
+    pub reference: String,
+    pub expected: f64,
+    pub measured: f64,
+    pub unit: &'static str,
+}
+
+impl PerfCheck {
+    /// Performance index: measured as a percentage of expected (100 = as expected)
+    pub fn index_pct(&self) -> f64 {
+        if self.expected > 0.0 {
+            self.measured / self.expected * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    pub fn is_underperforming(&self) -> bool {
+        self.index_pct() < UNDERPERFORM_THRESHOLD_PCT
+    }
+
+    /// How far below expected, in percent (0 if at or above expected)
+    pub fn shortfall_pct(&self) -> f64 {
+        (100.0 - self.index_pct()).max(0.0)
+    }
+}
+
+/// CPU baselines: (model pattern, ops/sec per logical thread with all threads loaded)
+/// Measured with the prime workload in release builds; patterns are matched in order
+const CPU_BASELINES: &[(&str, f64)] = &[
+    ("apple m4", 350.0),
+    ("apple m3", 310.0),
+    ("apple m2", 280.0),
+    ("apple m1", 260.0),
+    ("ryzen 9 9", 330.0),
+    ("ryzen 7 9", 330.0),
+    ("ryzen 5 9", 330.0),
+    ("ryzen 9 7", 300.0),
+    ("ryzen 7 7", 300.0),
+    ("ryzen 5 7", 300.0),
+    ("ryzen 9 5", 260.0),
+    ("ryzen 7 5", 260.0),
+    ("ryzen 5 5", 260.0),
+    ("ryzen 9 3", 210.0),
+    ("ryzen 7 3", 210.0),
+    ("ryzen 5 3", 210.0),
+    ("i9-14", 290.0),
+    ("i7-14", 290.0),
+    ("i5-14", 290.0),
+    ("i9-13", 280.0),
+    ("i7-13", 280.0),
+    ("i5-13", 280.0),
+    ("i9-12", 260.0),
+    ("i7-12", 260.0),
+    ("i5-12", 260.0),
+    ("i7-11", 220.0),
+    ("i5-11", 220.0),
+    ("i7-10", 200.0),
+    ("i5-10", 200.0),
+    ("xeon", 150.0),
+    ("epyc", 170.0),
+];
+
+/// Expected CPU throughput for a model, None if the model isn't in the database
+pub fn expected_cpu_ops(cpu_model: &str, threads: usize) -> Option<f64> {
+    let model = cpu_model.to_lowercase();
+    CPU_BASELINES
+        .iter()
+        .find(|(pattern, _)| model.contains(pattern))
+        .map(|(_, per_thread)| per_thread * threads.max(1) as f64)
+}
+
+/// Expected single-threaded RAM write speed in GB/s by memory type
+pub fn expected_ram_write_gb_s(ram_type: Option<&str>) -> f64 {
+    let ram_type = ram_type.unwrap_or("").to_uppercase();
+    if ram_type.contains("LPDDR5") {
+        15.0
+    } else if ram_type.contains("DDR5") {
+        12.0
+    } else if ram_type.contains("LPDDR4") || ram_type.contains("DDR4") {
+        8.0
+    } else if ram_type.contains("DDR3") {
+        5.0
+    } else {
+        6.0
+    }
+}
+
+/// Expected sequential (read, write) speeds in MB/s by disk type
+pub fn expected_disk_mb_s(is_ssd: bool) -> (f64, f64) {
+    if is_ssd {
+        (400.0, 300.0)
+    } else {
+        (120.0, 100.0)
+    }
+}
+
+pub fn cpu_check(cpu_model: &str, threads: usize, ops_per_second: f64) -> Option<PerfCheck> {
+    Some(PerfCheck {
+        component: "CPU",
+        reference: cpu_model.to_string(),
+        expected: expected_cpu_ops(cpu_model, threads)?,
+        measured: ops_per_second,
+        unit: "ops/s",
+    })
+}
+
+pub fn ram_check(ram_type: Option<&str>, write_gb_s: f64) -> PerfCheck {
+    PerfCheck {
+        component: "RAM",
+        reference: ram_type.unwrap_or("RAM").to_string(),
+        expected: expected_ram_write_gb_s(ram_type),
+        measured: write_gb_s,
+        unit: "GB/s",
+    }
+}
+
+pub fn disk_check(disk_name: &str, is_ssd: bool, read_mb_s: f64) -> PerfCheck {
+    let kind = if is_ssd { "SSD" } else { "HDD" };
+    PerfCheck {
+        component: "Disk",
+        reference: format!("{} ({})", disk_name, kind),
+        expected: expected_disk_mb_s(is_ssd).0,
+        measured: read_mb_s,
+        unit: "MB/s",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected_cpu_ops() {
+        assert_eq!(expected_cpu_ops("AMD Ryzen 7 5800X 8-Core Processor", 16), Some(260.0 * 16.0));
+        assert_eq!(expected_cpu_ops("Apple M2 Pro", 10), Some(2800.0));
+        assert_eq!(expected_cpu_ops("Unknown CPU Model XYZ", 8), None);
+    }
+
+    #[test]
+    fn test_perf_check_verdict() {
+        let check = cpu_check("AMD Ryzen 7 5800X", 16, 260.0 * 16.0 * 0.7).unwrap();
+        assert!(check.is_underperforming());
+        assert!((check.shortfall_pct() - 30.0).abs() < 0.01);
+
+        let check = disk_check("disk0", true, 2000.0);
+        assert!(!check.is_underperforming());
+        assert_eq!(check.shortfall_pct(), 0.0);
+    }
+}
@@ -2,22 +2,29 @@
 // Tests GPU by checking temperature and running thermal-based evaluation
 
 mod platform;
+pub mod soak;
 
 use std::time::Duration;
 use std::thread;
-use sysinfo::Components;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use serde::Serialize;
 
-use super::HealthStatus;
+use super::{HealthStatus, Issue, IssueCode, TempLimits, fan_failure_issue};
+use crate::sensors::{FanReading, FanHistory, get_fan_speeds};
+use crate::hw::gpu::GpuVendor;
 use super::gpu_compute::run_gpu_compute_stress_sync;
+use crate::fmt::{RESET, color, format_eta, progress_bar, quiet_progress, plain_progress, progress_log_line, emit_progress_json};
 
 /// GPU temperature reading
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GpuTemp {
     pub current: f32,
 }
 
 /// Thermal pressure level from powermetrics
-#[derive(Debug, Clone, PartialEq)]
+#[allow(dead_code)]  // Only constructed by the macOS powermetrics parser
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum ThermalPressure {
     Nominal,
     Moderate,
@@ -28,7 +35,7 @@ pub enum ThermalPressure {
 }
 
 /// Apple Silicon GPU metrics from powermetrics and SMC
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct AppleGpuMetrics {
     // From powermetrics
     pub frequency_mhz: Option<u32>,
@@ -43,55 +50,119 @@ pub struct AppleGpuMetrics {
     pub metal_version: Option<String>,
 }
 
-/// Get GPU temperature from sysinfo Components
-/// Looks for GPU-related temperature sensors
-/// Returns None if temperature not available
-pub fn get_gpu_temp() -> Option<GpuTemp> {
-    let components = Components::new_with_refreshed_list();
+/// NVIDIA GPU metrics from nvidia-smi (peak values over a test run)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct NvidiaGpuMetrics {
+    pub temperature_c: Option<f32>,
+    pub utilization_pct: Option<f32>,
+    pub power_w: Option<f32>,
+    pub memory_used_mb: Option<u32>,
+}
 
-    // Try to find GPU temperature from components
-    let mut gpu_temps: Vec<f32> = Vec::new();
+impl NvidiaGpuMetrics {
+    /// Keep the highest value of each metric
+    fn fold_peak(&mut self, sample: &NvidiaGpuMetrics) {
+        self.temperature_c = max_reading(self.temperature_c, sample.temperature_c);
+        self.utilization_pct = max_reading(self.utilization_pct, sample.utilization_pct);
+        self.power_w = max_reading(self.power_w, sample.power_w);
+        self.memory_used_mb = max_reading(self.memory_used_mb, sample.memory_used_mb);
+    }
+}
 
-    for comp in components.iter() {
-        let label = comp.label();
-        let temp = comp.temperature()?;
+/// AMD GPU metrics from the amdgpu sysfs/hwmon files (peak values over a test run)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AmdGpuMetrics {
+    pub temperature_c: Option<f32>,
+    pub utilization_pct: Option<f32>,
+    pub power_w: Option<f32>,
+    pub frequency_mhz: Option<u32>,
+}
 
-        // Filter out invalid readings
-        if !(-1000.0..=150.0).contains(&temp) {
-            continue;
-        }
+impl AmdGpuMetrics {
+    /// Keep the highest value of each metric
+    fn fold_peak(&mut self, sample: &AmdGpuMetrics) {
+        self.temperature_c = max_reading(self.temperature_c, sample.temperature_c);
+        self.utilization_pct = max_reading(self.utilization_pct, sample.utilization_pct);
+        self.power_w = max_reading(self.power_w, sample.power_w);
+        self.frequency_mhz = max_reading(self.frequency_mhz, sample.frequency_mhz);
+    }
+}
 
-        // Look for GPU-related temperature sensors
-        let label_lower = label.to_lowercase();
-        if label_lower.contains("gpu")
-            || label_lower.contains("graphic")
-            || label_lower.contains("nvidia")
-            || label_lower.contains("amd")
-            || label_lower.contains("radeon")
-            || label_lower.contains("intel")
-            || label_lower.contains("gt")
-            || label_lower.contains("pch")  // Platform Controller Hub (can include GPU)
-        {
-            gpu_temps.push(temp);
-        }
+/// Higher of two optional readings (a missing reading never wins)
+fn max_reading<T: PartialOrd + Copy>(a: Option<T>, b: Option<T>) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(if b > a { b } else { a }),
+        (a, b) => a.or(b),
     }
+}
 
-    if gpu_temps.is_empty() {
-        return None;
+/// Background thread folding a metrics source into peak values once per second
+struct PeakSampler<T> {
+    stop: Arc<AtomicBool>,
+    handle: thread::JoinHandle<T>,
+}
+
+impl<T: Send + 'static> PeakSampler<T> {
+    /// Start sampling if the source has a first reading
+    fn start(sample: fn() -> Option<T>, fold: fn(&mut T, &T)) -> Option<Self> {
+        let mut peak = sample()?;
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = Arc::clone(&stop);
+        let handle = thread::spawn(move || {
+            let mut ticks = 0u32;
+            while !stop_flag.load(Ordering::Relaxed) {
+                thread::sleep(Duration::from_millis(250));
+                ticks += 1;
+                if ticks.is_multiple_of(4) {
+                    if let Some(reading) = sample() {
+                        fold(&mut peak, &reading);
+                    }
+                }
+            }
+            peak
+        });
+        Some(Self { stop, handle })
     }
 
-    // Use the highest temperature from GPU sensors
-    let temp = gpu_temps
-        .into_iter()
-        .reduce(|a, b| a.max(b))
-        .unwrap();
+    fn finish(self) -> Option<T> {
+        self.stop.store(true, Ordering::Relaxed);
+        self.handle.join().ok()
+    }
+}
 
-    Some(GpuTemp { current: temp })
+/// Get GPU temperature: nvidia-smi or amdgpu sysfs when available, else sysinfo Components
+/// Looks for GPU-related temperature sensors
+/// Returns None if temperature not available
+pub fn get_gpu_temp() -> Option<GpuTemp> {
+    get_gpu_temp_for(GpuVendor::Unknown)
+}
+
+/// Temperature of a GPU from `vendor`: only its own tool is asked, so an Intel iGPU test doesn't
+/// report the NVIDIA card's sensor (Unknown asks every tool)
+pub fn get_gpu_temp_for(vendor: GpuVendor) -> Option<GpuTemp> {
+    if uses_vendor_tool(vendor, GpuVendor::Nvidia) {
+        if let Some(temp) = platform::get_nvidia_gpu_metrics().and_then(|m| m.temperature_c) {
+            return Some(GpuTemp { current: temp });
+        }
+    }
+    if uses_vendor_tool(vendor, GpuVendor::Amd) {
+        if let Some(temp) = platform::get_amd_gpu_metrics().and_then(|m| m.temperature_c) {
+            return Some(GpuTemp { current: temp });
+        }
+    }
+
+    crate::sensors::registry::with_fresh(|registry| registry.gpu_temp()).map(|current| GpuTemp { current })
+}
+
+/// Whether `tool`'s probe describes a GPU from `vendor`
+fn uses_vendor_tool(vendor: GpuVendor, tool: GpuVendor) -> bool {
+    vendor == tool || vendor == GpuVendor::Unknown
 }
 
 pub struct GpuTestConfig {
     pub duration_secs: u64,
     pub verbose: bool,
+    pub temp_limits: TempLimits,
 }
 
 impl Default for GpuTestConfig {
@@ -99,22 +170,36 @@ impl Default for GpuTestConfig {
         Self {
             duration_secs: 30,
             verbose: false,
+            temp_limits: TempLimits::default(),
         }
     }
 }
 
+#[derive(Serialize)]
 pub struct GpuTestResult {
     // Hardware info
     pub gpu_model: String,
     pub gpu_type: String,
     pub vram_gb: Option<f64>,
     // Test metrics
-    #[allow(dead_code)]
+    /// Idle baseline, read before the workload starts
     pub temperature_start: Option<GpuTemp>,
     pub temperature_end: Option<GpuTemp>,
     pub temperature_max: Option<f32>,
     pub is_apple_silicon: bool,
     pub apple_gpu_metrics: Option<AppleGpuMetrics>,
+    /// Peak nvidia-smi readings during the test (None without nvidia-smi)
+    pub nvidia_gpu_metrics: Option<NvidiaGpuMetrics>,
+    /// Peak amdgpu sysfs readings during the test (None without an AMD card)
+    pub amd_gpu_metrics: Option<AmdGpuMetrics>,
+    /// Compute dispatches completed (None = compute unavailable, thermal monitoring only)
+    pub frames_dispatched: Option<u32>,
+    /// Approximate compute throughput of the stress shader (None = compute unavailable)
+    pub compute_gflops: Option<f64>,
+    /// Average time per compute frame; the shader workload is tuned toward ~16ms
+    pub compute_frame_ms: Option<f64>,
+    /// wgpu backend that ran the compute load (Metal/Vulkan/Dx12)
+    pub backend: Option<String>,
     pub health: HealthStatus,
 }
 
@@ -132,14 +217,26 @@ pub fn run_stress_test(
         && (gpu_model.contains("M1") || gpu_model.contains("M2")
             || gpu_model.contains("M3") || gpu_model.contains("M4"));
 
+    // Vendor tools only describe their own GPUs, another card's readings must not leak into this test
+    let vendor = GpuVendor::from_model(&gpu_model);
+
     // Get start temperature
-    let temperature_start = get_gpu_temp();
+    let temperature_start = get_gpu_temp_for(vendor);
     let mut temperature_max = temperature_start.as_ref().map(|t| t.current);
 
+    // Sample vendor sensors in the background, the compute load below blocks this thread
+    let nvidia_sampler = uses_vendor_tool(vendor, GpuVendor::Nvidia)
+        .then(|| PeakSampler::start(platform::get_nvidia_gpu_metrics, NvidiaGpuMetrics::fold_peak))
+        .flatten();
+    let amd_sampler = uses_vendor_tool(vendor, GpuVendor::Amd)
+        .then(|| PeakSampler::start(platform::get_amd_gpu_metrics, AmdGpuMetrics::fold_peak))
+        .flatten();
+    let fan_sampler = PeakSampler::start(|| Some(FanHistory::from_readings(&get_fan_speeds())), FanHistory::merge);
+
     // Try to run GPU compute stress test
     let compute_result = run_gpu_compute_stress_sync(config.duration_secs, true);
 
-    if compute_result.is_err() {
+    if compute_result.is_err() && !quiet_progress() {
         // Compute test failed - show error and fall back to thermal
         println!("   ⚠️  GPU compute unavailable");
         println!("   Falling back to thermal monitoring...");
@@ -158,30 +255,52 @@ pub fn run_stress_test(
     if compute_result.is_err() {
         for elapsed in 0..config.duration_secs {
             thread::sleep(Duration::from_secs(1));
+            let eta = format_eta(elapsed + 1, config.duration_secs);
+
+            // JSON modes: nothing drawn on stdout, progress event (if enabled) goes to stderr
+            if quiet_progress() {
+                let temp = get_gpu_temp_for(vendor).map(|t| t.current);
+                if let Some(t) = temp {
+                    if temperature_max.is_none() || t > temperature_max.unwrap() {
+                        temperature_max = Some(t);
+                    }
+                }
+                let percent = ((elapsed + 1) * 100 / config.duration_secs) as u8;
+                emit_progress_json("gpu", percent, temp, elapsed + 1);
+                continue;
+            }
+
+            // Piped stdout: one log line per second instead of redrawing in place
+            if plain_progress() {
+                let temp = get_gpu_temp_for(vendor).map(|t| t.current);
+                if let Some(t) = temp {
+                    if temperature_max.is_none() || t > temperature_max.unwrap() {
+                        temperature_max = Some(t);
+                    }
+                }
+                let percent = ((elapsed + 1) * 100 / config.duration_secs) as u8;
+                let temp_str = temp.map_or("N/A".to_string(), |t| format!("{:.1}°C", t));
+                println!("{}", progress_log_line(elapsed + 1, &format!("GPU {}% {} {}", percent, eta, temp_str)));
+                continue;
+            }
 
             // Get current temperature
-            if let Some(temp) = get_gpu_temp() {
+            if let Some(temp) = get_gpu_temp_for(vendor) {
                 if temperature_max.is_none() || temp.current > temperature_max.unwrap() {
                     temperature_max = Some(temp.current);
                 }
 
                 // Print progress - same format as CPU
                 let percent = ((elapsed + 1) * 100 / config.duration_secs) as u8;
-                let filled = (percent as usize * 14 / 100).min(14);
-                let empty = 14 - filled;
-                let bar = format!("{}{}{}{}",
-                    "\x1b[32m", "█".repeat(filled), "\x1b[90m", "░".repeat(empty));
-                print!("\r⏳ GPU: [{}\x1b[0m] {}% | {:.1}°C",
-                       bar, percent, temp.current);
+                let bar = progress_bar(percent, 14);
+                print!("\r⏳ GPU: [{}] {}% {} | {:.1}°C",
+                       bar, percent, eta, temp.current);
                 use std::io::Write;
                 std::io::stdout().flush().unwrap();
             } else if is_apple_silicon {
                 // Apple Silicon: show platform metrics if available
                 let percent = ((elapsed + 1) * 100 / config.duration_secs) as u8;
-                let filled = (percent as usize * 14 / 100).min(14);
-                let empty = 14 - filled;
-                let bar = format!("{}{}{}{}",
-                    "\x1b[32m", "█".repeat(filled), "\x1b[90m", "░".repeat(empty));
+                let bar = progress_bar(percent, 14);
 
                 // Refresh metrics every 5 seconds
                 if elapsed % 5 == 0 || elapsed == 0 {
@@ -225,44 +344,53 @@ pub fn run_stress_test(
                             parts.push(format!("{} cores", cores));
                         }
 
-                        print!("\r⏳ GPU: [{}\x1b[0m] {}% | {}",
-                               bar, percent, parts.join(" | "));
+                        print!("\r⏳ GPU: [{}] {}% {} | {}",
+                               bar, percent, eta, parts.join(" | "));
                         use std::io::Write;
                         std::io::stdout().flush().unwrap();
                     } else {
-                        print!("\r⏳ GPU: [{}\x1b[0m] {}% | SoC (needs sudo)",
-                               bar, percent);
+                        print!("\r⏳ GPU: [{}] {}% {} | SoC (needs sudo)",
+                               bar, percent, eta);
                         use std::io::Write;
                         std::io::stdout().flush().unwrap();
                     }
                 } else {
                     // Don't re-query powermetrics every second (too slow)
                     // Just update progress bar
-                    print!("\r⏳ GPU: [{}\x1b[0m] {}%", bar, percent);
+                    print!("\r⏳ GPU: [{}] {}% {}", bar, percent, eta);
                     use std::io::Write;
                     std::io::stdout().flush().unwrap();
                 }
             } else {
                 // No temperature sensor available
                 let percent = ((elapsed + 1) * 100 / config.duration_secs) as u8;
-                let filled = (percent as usize * 14 / 100).min(14);
-                let empty = 14 - filled;
-                let bar = format!("{}{}{}{}",
-                    "\x1b[32m", "█".repeat(filled), "\x1b[90m", "░".repeat(empty));
-                print!("\r⏳ GPU: [{}\x1b[0m] {}% | N/A (no sensor)",
-                       bar, percent);
+                let bar = progress_bar(percent, 14);
+                print!("\r⏳ GPU: [{}] {}% {} | N/A (no sensor)",
+                       bar, percent, eta);
                 use std::io::Write;
                 std::io::stdout().flush().unwrap();
             }
         }
         // Clear progress line after thermal monitoring (reset color first)
-        print!("\x1b[0m\r\x1b[2K");  // Reset color, then clear line
-        use std::io::Write;
-        std::io::stdout().flush().unwrap();
+        if !quiet_progress() && !plain_progress() {
+            print!("{}\r\x1b[2K", color(RESET));  // Reset color, then clear line
+            use std::io::Write;
+            std::io::stdout().flush().unwrap();
+        }
     }
 
-    // Get end temperature
-    let temperature_end = get_gpu_temp();
+    let nvidia_gpu_metrics = nvidia_sampler.and_then(PeakSampler::finish);
+    let amd_gpu_metrics = amd_sampler.and_then(PeakSampler::finish);
+    let vendor_peak = max_reading(
+        nvidia_gpu_metrics.as_ref().and_then(|m| m.temperature_c),
+        amd_gpu_metrics.as_ref().and_then(|m| m.temperature_c),
+    );
+    temperature_max = max_reading(temperature_max, vendor_peak);
+
+    // Get end temperature (fans too, while they still reflect the load)
+    let temperature_end = get_gpu_temp_for(vendor);
+    let fans = get_fan_speeds();
+    let fan_history = fan_sampler.and_then(PeakSampler::finish).unwrap_or_default();
 
     // Update max with end temp
     if let Some(ref end) = temperature_end {
@@ -272,7 +400,12 @@ pub fn run_stress_test(
     }
 
     // Determine health status based on temperature
-    let health = evaluate_gpu_health(temperature_max, is_apple_silicon);
+    let temperature_idle = temperature_start.as_ref().map(|t| t.current);
+    let stuck_fan = fan_history.stuck_fan(&fans, temperature_idle, temperature_max);
+    let mut health = evaluate_gpu_health_with_limits(temperature_max, is_apple_silicon, config.temp_limits, stuck_fan);
+    if let Some(issue) = nvidia_gpu_metrics.as_ref().and_then(|m| evaluate_nvidia_load(m, compute_result.is_ok())) {
+        health = health.with_issue(issue);
+    }
 
     GpuTestResult {
         gpu_model,
@@ -283,6 +416,12 @@ pub fn run_stress_test(
         temperature_max,
         is_apple_silicon,
         apple_gpu_metrics,
+        nvidia_gpu_metrics,
+        amd_gpu_metrics,
+        frames_dispatched: compute_result.as_ref().ok().map(|r| r.frames_dispatched),
+        compute_gflops: compute_result.as_ref().ok().map(|r| r.gflops),
+        compute_frame_ms: compute_result.as_ref().ok().map(|r| r.avg_frame_ms),
+        backend: compute_result.ok().map(|r| r.backend),
         health,
     }
 }
@@ -290,17 +429,28 @@ pub fn run_stress_test(
 /// Evaluate GPU health based on temperature
 /// Thresholds: Warning ≥85°C, FAIL >95°C
 /// Apple Silicon integrated GPUs: No separate GPU sensor (SoC thermal)
-fn evaluate_gpu_health(max_temp: Option<f32>, is_apple_silicon: bool) -> HealthStatus {
+pub fn evaluate_gpu_health(max_temp: Option<f32>, is_apple_silicon: bool) -> HealthStatus {
+    evaluate_gpu_health_with_limits(max_temp, is_apple_silicon, TempLimits::default(), None)
+}
+
+/// Evaluate GPU health with custom temperature limits (--temp-warn/--temp-fail)
+/// `stuck_fan` is a fan that stopped under load (`FanHistory::stuck_fan`)
+pub fn evaluate_gpu_health_with_limits(
+    max_temp: Option<f32>,
+    is_apple_silicon: bool,
+    limits: TempLimits,
+    stuck_fan: Option<&FanReading>,
+) -> HealthStatus {
     let mut issues = Vec::new();
 
     if let Some(temp) = max_temp {
-        if temp > 95.0 {
-            return HealthStatus::Failed(format!(
+        if temp > limits.fail_c {
+            return HealthStatus::Failed(Issue::new(IssueCode::GpuOverheat, format!(
                 "GPU overheating ({:.1}°C) - cooling system failure or defective GPU",
                 temp
-            ));
-        } else if temp > 85.0 {
-            issues.push(format!("GPU running hot ({:.1}°C) - check cooling system", temp));
+            )));
+        } else if temp > limits.warn_c {
+            issues.push(Issue::new(IssueCode::GpuOverheat, format!("GPU running hot ({:.1}°C) - check cooling system", temp)));
         }
     } else if is_apple_silicon {
         // Apple Silicon: No separate GPU temp sensor is expected
@@ -308,8 +458,9 @@ fn evaluate_gpu_health(max_temp: Option<f32>, is_apple_silicon: bool) -> HealthS
         return HealthStatus::Healthy;
     } else {
         // Other platforms: No temperature sensor is a problem
-        issues.push("GPU temperature sensor not available - unable to verify thermal status".to_string());
+        issues.push(Issue::new(IssueCode::GpuNoSensor, "GPU temperature sensor not available - unable to verify thermal status"));
     }
+    issues.extend(stuck_fan.map(|fan| fan_failure_issue(fan, max_temp)));
 
     if !issues.is_empty() {
         HealthStatus::IssuesDetected(issues)
@@ -318,10 +469,51 @@ fn evaluate_gpu_health(max_temp: Option<f32>, is_apple_silicon: bool) -> HealthS
     }
 }
 
+/// Below this peak utilization the compute load didn't reach the NVIDIA GPU
+const NVIDIA_MIN_LOAD_PCT: f32 = 50.0;
+
+/// Flag a compute run that left the NVIDIA GPU idle (e.g. wgpu picked the integrated GPU)
+pub fn evaluate_nvidia_load(metrics: &NvidiaGpuMetrics, compute_ran: bool) -> Option<Issue> {
+    let utilization = metrics.utilization_pct?;
+    if compute_ran && utilization < NVIDIA_MIN_LOAD_PCT {
+        Some(Issue::new(IssueCode::GpuLowLoad, format!(
+            "NVIDIA GPU peaked at {:.0}% utilization - compute load may have run on another GPU",
+            utilization
+        )))
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_nvidia_metrics() {
+        let mut peak = NvidiaGpuMetrics { temperature_c: Some(60.0), power_w: None, ..Default::default() };
+        peak.fold_peak(&NvidiaGpuMetrics {
+            temperature_c: Some(72.0),
+            utilization_pct: Some(99.0),
+            power_w: Some(180.0),
+            memory_used_mb: None,
+        });
+        peak.fold_peak(&NvidiaGpuMetrics { temperature_c: Some(70.0), ..Default::default() });
+        assert_eq!(peak.temperature_c, Some(72.0));
+        assert_eq!(peak.utilization_pct, Some(99.0));
+        assert_eq!(peak.power_w, Some(180.0));
+        assert_eq!(peak.memory_used_mb, None);
+
+        assert!(evaluate_nvidia_load(&peak, true).is_none());
+        let idle = NvidiaGpuMetrics { utilization_pct: Some(4.0), ..Default::default() };
+        assert!(evaluate_nvidia_load(&idle, true).unwrap().message.contains("4%"));
+        // Thermal-only run never loads the GPU
+        assert!(evaluate_nvidia_load(&idle, false).is_none());
+
+        assert_eq!(max_reading(None, Some(3)), Some(3));
+        assert_eq!(max_reading(Some(5), Some(3)), Some(5));
+    }
+
     #[test]
     fn test_get_gpu_temp() {
         let temp = get_gpu_temp();
@@ -345,7 +537,7 @@ mod tests {
         assert!(matches!(evaluate_gpu_health(Some(100.0), false), HealthStatus::Failed(_)));
 
         // No sensor - issues detected (non-Apple Silicon)
-        assert!(matches!(evaluate_gpu_health(None, false), HealthStatus::IssuesDetected(_)));
+        assert!(matches!(evaluate_gpu_health(None, false), HealthStatus::IssuesDetected(ref i) if i[0].code == IssueCode::GpuNoSensor));
 
         // No sensor - healthy on Apple Silicon (expected behavior)
         assert!(matches!(evaluate_gpu_health(None, true), HealthStatus::Healthy));
@@ -356,6 +548,7 @@ mod tests {
         let config = GpuTestConfig {
             duration_secs: 1,
             verbose: false,
+            ..Default::default()
         };
         let result = run_stress_test(
             config,
@@ -0,0 +1,100 @@
+// AMD GPU metrics via /sys/class/drm (Linux amdgpu driver)
+// Temperature, power and clock come from the card's hwmon, load from gpu_busy_percent
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::super::AmdGpuMetrics;
+
+const DRM_ROOT: &str = "/sys/class/drm";
+const AMD_VENDOR_ID: &str = "0x1002";
+
+/// Current metrics of the first AMD GPU, None without an amdgpu card
+pub fn get_amd_gpu_metrics() -> Option<AmdGpuMetrics> {
+    read_amd_gpu_metrics(Path::new(DRM_ROOT))
+}
+
+/// Read metrics of the first AMD card under a drm root (cardN/device/...)
+pub fn read_amd_gpu_metrics(drm_root: &Path) -> Option<AmdGpuMetrics> {
+    let device = find_amd_device(drm_root)?;
+    let hwmon = find_hwmon(&device);
+    let read_hwmon = |name: &str| hwmon.as_ref().and_then(|dir| read_number(&dir.join(name)));
+
+    let metrics = AmdGpuMetrics {
+        temperature_c: read_hwmon("temp1_input").map(|milli| (milli / 1000.0) as f32),
+        utilization_pct: read_number(&device.join("gpu_busy_percent")).map(|pct| pct as f32),
+        // Older kernels report power1_average, newer ones power1_input (both µW)
+        power_w: read_hwmon("power1_average")
+            .or_else(|| read_hwmon("power1_input"))
+            .map(|micro| (micro / 1_000_000.0) as f32),
+        frequency_mhz: read_hwmon("freq1_input").map(|hz| (hz / 1_000_000.0) as u32),
+    };
+    (metrics != AmdGpuMetrics::default()).then_some(metrics)
+}
+
+/// cardN/device of the first card with the AMD PCI vendor id (connectors like card0-DP-1 skipped)
+fn find_amd_device(drm_root: &Path) -> Option<PathBuf> {
+    let mut cards: Vec<PathBuf> = fs::read_dir(drm_root)
+        .ok()?
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .and_then(|n| n.strip_prefix("card"))
+                .is_some_and(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))
+        })
+        .collect();
+    cards.sort();
+
+    cards.into_iter().map(|card| card.join("device")).find(|device| {
+        fs::read_to_string(device.join("vendor")).is_ok_and(|v| v.trim() == AMD_VENDOR_ID)
+    })
+}
+
+fn find_hwmon(device: &Path) -> Option<PathBuf> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(device.join("hwmon")).ok()?.flatten().map(|e| e.path()).collect();
+    dirs.sort();
+    dirs.into_iter().next()
+}
+
+fn read_number(path: &Path) -> Option<f64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_amd_gpu_metrics() {
+        let root = std::env::temp_dir().join(format!("pchecker_drm_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        // card0 is an Intel iGPU, card1 the AMD card
+        let intel = root.join("card0/device");
+        fs::create_dir_all(&intel).unwrap();
+        fs::write(intel.join("vendor"), "0x8086\n").unwrap();
+        fs::create_dir_all(root.join("card1-DP-1")).unwrap();
+        let amd = root.join("card1/device");
+        let hwmon = amd.join("hwmon/hwmon3");
+        fs::create_dir_all(&hwmon).unwrap();
+        fs::write(amd.join("vendor"), "0x1002\n").unwrap();
+        fs::write(amd.join("gpu_busy_percent"), "97\n").unwrap();
+        fs::write(hwmon.join("temp1_input"), "68000\n").unwrap();
+        fs::write(hwmon.join("power1_average"), "186000000\n").unwrap();
+        fs::write(hwmon.join("freq1_input"), "2450000000\n").unwrap();
+
+        let m = read_amd_gpu_metrics(&root).unwrap();
+        assert_eq!(m.temperature_c, Some(68.0));
+        assert_eq!(m.utilization_pct, Some(97.0));
+        assert_eq!(m.power_w, Some(186.0));
+        assert_eq!(m.frequency_mhz, Some(2450));
+
+        // No AMD card at all
+        fs::remove_dir_all(root.join("card1")).unwrap();
+        assert!(read_amd_gpu_metrics(&root).is_none());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
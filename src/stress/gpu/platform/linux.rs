@@ -5,6 +5,7 @@ use super::super::AppleGpuMetrics;
 
 /// Get GPU metrics on Linux
 /// Currently not implemented - returns None
+#[allow(dead_code)]
 pub fn get_apple_gpu_metrics() -> Option<AppleGpuMetrics> {
     // Linux doesn't have powermetrics/system_profiler
     // Could be extended with sysfs thermal queries in the future
@@ -2,6 +2,7 @@
 // Uses powermetrics, system_profiler, and SMC
 
 use std::process::Command;
+use crate::profile::TimedOutput;
 
 use super::super::{ThermalPressure, AppleGpuMetrics};
 
@@ -18,7 +19,7 @@ struct AppleGpuInfo {
 pub fn get_apple_gpu_metrics() -> Option<AppleGpuMetrics> {
     let output = Command::new("powermetrics")
         .args(["--samplers", "gpu_power,thermal", "-i", "1000", "-n", "1"])
-        .output();
+        .timed_output();
 
     let content = match output {
         Ok(result) if result.status.success() => String::from_utf8_lossy(&result.stdout).to_string(),
@@ -114,7 +115,7 @@ pub fn get_apple_gpu_metrics() -> Option<AppleGpuMetrics> {
 fn get_apple_gpu_info() -> Result<AppleGpuInfo, String> {
     let output = Command::new("system_profiler")
         .args(["SPDisplaysDataType"])
-        .output();
+        .timed_output();
 
     let content = match output {
         Ok(result) if result.status.success() => String::from_utf8_lossy(&result.stdout).to_string(),
@@ -9,6 +9,12 @@ pub mod windows;
 #[cfg(target_os = "linux")]
 pub mod linux;
 
+#[cfg(not(target_os = "macos"))]
+pub mod nvidia;
+
+#[cfg(target_os = "linux")]
+pub mod amd;
+
 // Re-export platform functions for use in parent module
 #[cfg(target_os = "macos")]
 pub use macos::get_apple_gpu_metrics;
@@ -17,12 +23,36 @@ pub use macos::get_apple_gpu_metrics;
 #[cfg(not(target_os = "macos"))]
 pub use self::stub::get_apple_gpu_metrics;
 
+#[cfg(not(target_os = "macos"))]
+pub use nvidia::get_nvidia_gpu_metrics;
+
+// Macs haven't shipped NVIDIA GPUs with a supported driver in years
+#[cfg(target_os = "macos")]
+pub use self::stub::get_nvidia_gpu_metrics;
+
+#[cfg(target_os = "linux")]
+pub use amd::get_amd_gpu_metrics;
+
+// AMD sensors are only read from Linux sysfs so far
+#[cfg(not(target_os = "linux"))]
+pub use self::stub::get_amd_gpu_metrics;
+
 // Stub implementations for non-macOS platforms
 mod stub {
-    use crate::stress::gpu::AppleGpuMetrics;
+    use crate::stress::gpu::{AmdGpuMetrics, AppleGpuMetrics, NvidiaGpuMetrics};
 
     #[allow(dead_code)]
     pub fn get_apple_gpu_metrics() -> Option<AppleGpuMetrics> {
         None
     }
+
+    #[allow(dead_code)]
+    pub fn get_nvidia_gpu_metrics() -> Option<NvidiaGpuMetrics> {
+        None
+    }
+
+    #[allow(dead_code)]
+    pub fn get_amd_gpu_metrics() -> Option<AmdGpuMetrics> {
+        None
+    }
 }
@@ -0,0 +1,77 @@
+// NVIDIA GPU metrics via nvidia-smi (Linux/Windows)
+// Discrete NVIDIA temperatures rarely show up in sysinfo Components, nvidia-smi always has them
+
+use std::process::Command;
+use std::sync::OnceLock;
+
+use crate::profile::TimedOutput;
+
+use super::super::NvidiaGpuMetrics;
+
+const QUERY: &str = "--query-gpu=temperature.gpu,utilization.gpu,power.draw,memory.used";
+
+/// Whether nvidia-smi runs on this machine (checked once, it's spawned every second during tests)
+fn nvidia_smi_available() -> bool {
+    static AVAILABLE: OnceLock<bool> = OnceLock::new();
+    *AVAILABLE.get_or_init(|| {
+        Command::new("nvidia-smi")
+            .arg("-L")
+            .timed_output()
+            .is_ok_and(|o| o.status.success())
+    })
+}
+
+/// Current metrics of the first NVIDIA GPU, None without nvidia-smi
+pub fn get_nvidia_gpu_metrics() -> Option<NvidiaGpuMetrics> {
+    if !nvidia_smi_available() {
+        return None;
+    }
+    let output = Command::new("nvidia-smi")
+        .args([QUERY, "--format=csv,noheader,nounits"])
+        .timed_output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_nvidia_smi_csv(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse `nvidia-smi --query-gpu=... --format=csv,noheader,nounits` output
+/// e.g. "67, 98, 215.43, 7321" (first line = first GPU; "[N/A]" fields become None)
+pub fn parse_nvidia_smi_csv(output: &str) -> Option<NvidiaGpuMetrics> {
+    let line = output.lines().map(str::trim).find(|l| !l.is_empty())?;
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    if fields.len() < 4 {
+        return None;
+    }
+    let number = |s: &str| s.parse::<f32>().ok();
+
+    Some(NvidiaGpuMetrics {
+        temperature_c: number(fields[0]),
+        utilization_pct: number(fields[1]),
+        power_w: number(fields[2]),
+        memory_used_mb: number(fields[3]).map(|mb| mb as u32),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_nvidia_smi_csv() {
+        let m = parse_nvidia_smi_csv("67, 98, 215.43, 7321\n45, 0, 20.1, 5\n").unwrap();
+        assert_eq!(m.temperature_c, Some(67.0));
+        assert_eq!(m.utilization_pct, Some(98.0));
+        assert_eq!(m.power_w, Some(215.43));
+        assert_eq!(m.memory_used_mb, Some(7321));
+
+        // Laptop GPUs often don't report power
+        let m = parse_nvidia_smi_csv("52, 3, [N/A], 410").unwrap();
+        assert_eq!(m.power_w, None);
+        assert_eq!(m.memory_used_mb, Some(410));
+
+        assert!(parse_nvidia_smi_csv("").is_none());
+        assert!(parse_nvidia_smi_csv("NVIDIA-SMI has failed").is_none());
+    }
+}
@@ -0,0 +1,231 @@
+// GPU sustained thermal-soak test
+// Runs compute load until the GPU temperature reaches steady state instead of a fixed timer
+
+use std::collections::VecDeque;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use super::{evaluate_gpu_health_with_limits, get_gpu_temp};
+use crate::fmt::{RESET, color, progress_bar, quiet_progress, plain_progress, progress_log_line, emit_progress_json};
+use crate::stress::{HealthStatus, Issue, IssueCode, TempLimits};
+use crate::stress::gpu_compute::run_gpu_compute_stress_until;
+
+pub struct GpuSoakConfig {
+    /// Give up waiting for steady state after this many seconds
+    pub max_secs: u64,
+    /// Temperature must stay within `delta_c` over this window
+    pub window_secs: u64,
+    pub delta_c: f32,
+    pub temp_limits: TempLimits,
+}
+
+impl Default for GpuSoakConfig {
+    fn default() -> Self {
+        Self {
+            max_secs: 600,
+            window_secs: 30,
+            delta_c: 1.0,
+            temp_limits: TempLimits::default(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct GpuSoakResult {
+    pub gpu_model: String,
+    pub elapsed_secs: u64,
+    pub steady_temp_c: Option<f32>,
+    pub time_to_stable_secs: Option<u64>,
+    pub max_temp_c: Option<f32>,
+    /// False if the compute load could not run (thermal monitoring only)
+    pub compute_active: bool,
+    pub health: HealthStatus,
+}
+
+/// Tracks temperature samples and detects thermal steady state
+pub struct StabilityTracker {
+    window_secs: u64,
+    delta_c: f32,
+    samples: VecDeque<(u64, f32)>,
+}
+
+impl StabilityTracker {
+    pub fn new(window_secs: u64, delta_c: f32) -> Self {
+        Self {
+            window_secs,
+            delta_c,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Add a sample, returns true once the last full window varied by less than delta
+    pub fn push(&mut self, elapsed_secs: u64, temp_c: f32) -> bool {
+        self.samples.push_back((elapsed_secs, temp_c));
+        while let Some(&(t, _)) = self.samples.front() {
+            if elapsed_secs - t > self.window_secs {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        let covered = self.samples.front().map_or(0, |&(t, _)| elapsed_secs - t);
+        covered >= self.window_secs && self.spread() < self.delta_c
+    }
+
+    /// Max - min temperature over the current window
+    pub fn spread(&self) -> f32 {
+        let max = self.samples.iter().map(|&(_, t)| t).fold(f32::MIN, f32::max);
+        let min = self.samples.iter().map(|&(_, t)| t).fold(f32::MAX, f32::min);
+        if self.samples.is_empty() { 0.0 } else { max - min }
+    }
+
+    /// Average temperature over the current window
+    pub fn average(&self) -> Option<f32> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        Some(self.samples.iter().map(|&(_, t)| t).sum::<f32>() / self.samples.len() as f32)
+    }
+}
+
+/// Run GPU compute load until temperature stabilizes or max time elapses
+pub fn run_sustained_test(config: GpuSoakConfig, gpu_model: String) -> GpuSoakResult {
+    let start = Instant::now();
+
+    // Requires a temperature sensor - nothing to stabilize without one
+    if get_gpu_temp().is_none() {
+        return GpuSoakResult {
+            gpu_model,
+            elapsed_secs: 0,
+            steady_temp_c: None,
+            time_to_stable_secs: None,
+            max_temp_c: None,
+            compute_active: false,
+            health: HealthStatus::IssuesDetected(vec![Issue::new(
+                IssueCode::GpuNoSensor,
+                "GPU temperature sampling not available - sustained test needs a GPU sensor",
+            )]),
+        };
+    }
+
+    // Compute load runs in the background until we signal stop
+    let stop = Arc::new(AtomicBool::new(false));
+    let worker = {
+        let stop = Arc::clone(&stop);
+        let max_secs = config.max_secs;
+        thread::spawn(move || run_gpu_compute_stress_until(max_secs, &stop).is_ok())
+    };
+
+    let mut tracker = StabilityTracker::new(config.window_secs, config.delta_c);
+    let mut max_temp: Option<f32> = None;
+    let mut time_to_stable = None;
+    let mut elapsed = 0;
+
+    while elapsed < config.max_secs {
+        thread::sleep(Duration::from_secs(1));
+        elapsed = start.elapsed().as_secs();
+
+        let temp = match get_gpu_temp() {
+            Some(t) => t.current,
+            None => continue,
+        };
+        max_temp = Some(max_temp.map_or(temp, |m: f32| m.max(temp)));
+        let stable = tracker.push(elapsed, temp);
+
+        let percent = (elapsed * 100 / config.max_secs) as u8;
+        if quiet_progress() {
+            emit_progress_json("gpu", percent, Some(temp), elapsed);
+        } else if plain_progress() {
+            println!("{}", progress_log_line(elapsed, &format!("GPU {:.1}°C Δ {:.1}°C/{}s",
+                temp, tracker.spread(), config.window_secs)));
+        } else {
+            print!("\r⏳ GPU: [{}] {}s | {:.1}°C | Δ {:.1}°C/{}s   ",
+                progress_bar(percent, 14), elapsed, temp, tracker.spread(), config.window_secs);
+            std::io::stdout().flush().unwrap();
+        }
+
+        if stable {
+            time_to_stable = Some(elapsed);
+            break;
+        }
+    }
+
+    stop.store(true, Ordering::Relaxed);
+    let compute_active = worker.join().unwrap_or(false);
+
+    if !quiet_progress() && !plain_progress() {
+        print!("{}\r\x1b[2K", color(RESET));
+        std::io::stdout().flush().unwrap();
+    }
+
+    let steady_temp = time_to_stable.and_then(|_| tracker.average());
+    let health = evaluate_soak_health(max_temp, time_to_stable, config.max_secs, config.temp_limits);
+
+    GpuSoakResult {
+        gpu_model,
+        elapsed_secs: start.elapsed().as_secs(),
+        steady_temp_c: steady_temp,
+        time_to_stable_secs: time_to_stable,
+        max_temp_c: max_temp,
+        compute_active,
+        health,
+    }
+}
+
+/// Evaluate sustained test: usual GPU thresholds plus failure to reach steady state
+fn evaluate_soak_health(max_temp: Option<f32>, time_to_stable: Option<u64>, max_secs: u64, limits: TempLimits) -> HealthStatus {
+    let health = evaluate_gpu_health_with_limits(max_temp, false, limits, None);
+    if time_to_stable.is_some() || matches!(health, HealthStatus::Failed(_)) {
+        return health;
+    }
+
+    let mut issues = match health {
+        HealthStatus::IssuesDetected(issues) => issues,
+        _ => Vec::new(),
+    };
+    issues.push(Issue::new(IssueCode::GpuNotStable, format!(
+        "GPU temperature did not stabilize within {}s - cooling may not sustain full load",
+        max_secs
+    )));
+    HealthStatus::IssuesDetected(issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stability_tracker() {
+        let mut tracker = StabilityTracker::new(10, 1.0);
+
+        // Heating up: never stable while rising 1°C/s
+        for s in 0..20 {
+            assert!(!tracker.push(s, 50.0 + s as f32));
+        }
+
+        // Plateau: stable once a full 10s window stays within 1°C
+        let mut stable_at = None;
+        for s in 20..40 {
+            if tracker.push(s, 70.0 + (s % 2) as f32 * 0.5) {
+                stable_at = Some(s);
+                break;
+            }
+        }
+        assert_eq!(stable_at, Some(30));
+        assert!((tracker.average().unwrap() - 70.25).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_evaluate_soak_health() {
+        let limits = TempLimits::default();
+        assert!(matches!(evaluate_soak_health(Some(70.0), Some(120), 600, limits), HealthStatus::Healthy));
+        assert!(matches!(evaluate_soak_health(Some(70.0), None, 600, limits), HealthStatus::IssuesDetected(_)));
+        assert!(matches!(evaluate_soak_health(Some(100.0), None, 600, limits), HealthStatus::Failed(_)));
+    }
+}
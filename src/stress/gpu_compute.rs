@@ -4,6 +4,10 @@
 #[cfg(feature = "gpu-compute")]
 use std::time::Instant;
 
+use std::sync::atomic::AtomicBool;
+#[cfg(feature = "gpu-compute")]
+use std::sync::atomic::Ordering;
+
 #[cfg(feature = "gpu-compute")]
 use wgpu::util::DeviceExt;
 
@@ -14,14 +18,55 @@ pub struct GpuComputeResult {
     pub gpu_name: String,
     pub backend: String,
     pub frames_dispatched: u32,
+    /// Approximate shader arithmetic throughput over the whole run
+    pub gflops: f64,
+    /// Average wall time per submitted frame (ms)
+    pub avg_frame_ms: f64,
+}
+
+/// Arithmetic per element per shader loop iteration in gpu_stress.wgsl:
+/// 5 FMA (2 ops each), 5 sin*cos terms (sin, cos, multiply, add) with 8 argument adds, 5 sqrt with 4 adds
+/// (transcendentals count as one op, so the result is approximate)
+const OPS_PER_ITERATION: f64 = 5.0 * 2.0 + 5.0 * 4.0 + 8.0 + 5.0 + 4.0;
+
+/// Shader loop count for the first frame
+#[cfg(feature = "gpu-compute")]
+const START_ITERATIONS: u32 = 100;
+const MIN_ITERATIONS: u32 = 1;
+const MAX_ITERATIONS: u32 = 100_000;
+/// Frame time the iteration count is tuned for (keeps CPU-side submission overhead low)
+const TARGET_FRAME_SECS: f64 = 0.016;
+/// Frames whose timing re-tunes the iteration count (the first one includes pipeline warm-up)
+#[cfg(feature = "gpu-compute")]
+const CALIBRATION_FRAMES: u32 = 3;
+
+/// Iteration count that makes a frame of `frame_secs` at `iterations` take ~TARGET_FRAME_SECS
+#[cfg_attr(not(feature = "gpu-compute"), allow(dead_code))]
+fn scale_iterations(iterations: u32, frame_secs: f64) -> u32 {
+    if frame_secs <= 0.0 {
+        return MAX_ITERATIONS;
+    }
+    let scaled = iterations as f64 * TARGET_FRAME_SECS / frame_secs;
+    scaled.clamp(MIN_ITERATIONS as f64, MAX_ITERATIONS as f64).round() as u32
+}
+
+/// GFLOPS from the total shader loop iterations run over all elements; 0 when nothing ran
+#[cfg_attr(not(feature = "gpu-compute"), allow(dead_code))]
+fn compute_gflops(element_iterations: f64, elapsed_secs: f64) -> f64 {
+    if elapsed_secs <= 0.0 {
+        return 0.0;
+    }
+    element_iterations * OPS_PER_ITERATION / elapsed_secs / 1e9
 }
 
 /// Run GPU compute stress test using wgpu
 /// Returns Ok with result if successful, Err with message if GPU not available
+/// Stops early when `stop` is set (used by the sustained thermal-soak test)
 #[cfg(feature = "gpu-compute")]
 pub async fn run_gpu_compute_stress(
     duration_secs: u64,
     show_progress: bool,
+    stop: Option<&AtomicBool>,
 ) -> Result<GpuComputeResult, String> {
     // 1. Initialize wgpu instance
     let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
@@ -35,6 +80,7 @@ pub async fn run_gpu_compute_stress(
 
     let info = adapter.get_info();
     let gpu_name = info.name.to_string();
+    let vendor = crate::hw::gpu::GpuVendor::from_model(&gpu_name);
     let backend = format!("{:?}", info.backend);
 
     // 3. Create device and queue
@@ -78,21 +124,39 @@ pub async fn run_gpu_compute_stress(
         compilation_options: Default::default(),
     });
 
+    // Shader loop count (padded to 16 bytes for uniform layout)
+    let mut iterations = START_ITERATIONS;
+    let params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("GPU Stress Params"),
+        contents: bytemuck::cast_slice(&[iterations, 0, 0, 0]),
+        usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+    });
+
     let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
         label: None,
         layout: &compute_pipeline.get_bind_group_layout(0),
-        entries: &[wgpu::BindGroupEntry {
-            binding: 0,
-            resource: storage_buffer.as_entire_binding(),
-        }],
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: storage_buffer.as_entire_binding(),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: params_buffer.as_entire_binding(),
+            },
+        ],
     });
 
     // 7. Main stress loop
     let start_time = Instant::now();
     let mut frames_dispatched = 0u32;
+    let mut element_iterations = 0.0f64;
     let mut last_update_time = 0.0f32;
+    let mut last_logged_second = 0u64;
 
-    while start_time.elapsed().as_secs() < duration_secs {
+    while start_time.elapsed().as_secs() < duration_secs
+        && !stop.is_some_and(|s| s.load(Ordering::Relaxed))
+    {
         let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
             label: Some("GPU Stress Encoder"),
         });
@@ -115,12 +179,21 @@ pub async fn run_gpu_compute_stress(
             cpass.dispatch_workgroups(x_size, y_size, 1);
         }
 
+        let frame_start = Instant::now();
         queue.submit(Some(encoder.finish()));
 
         // Force GPU to execute
         device.poll(wgpu::Maintain::Wait);
 
         frames_dispatched += 1;
+        element_iterations += data_size as f64 * iterations as f64;
+
+        // Adaptive workload: size the next frames to ~16ms so fast GPUs aren't fed
+        // microsecond frames (CPU spinning on submits) and slow ones don't stall for seconds
+        if frames_dispatched <= CALIBRATION_FRAMES {
+            iterations = scale_iterations(iterations, frame_start.elapsed().as_secs_f64());
+            queue.write_buffer(&params_buffer, 0, bytemuck::cast_slice(&[iterations, 0, 0, 0]));
+        }
 
         // Progress display - update every 0.5 seconds or every 100 frames
         if show_progress {
@@ -131,13 +204,28 @@ pub async fn run_gpu_compute_stress(
             if should_update {
                 last_update_time = elapsed;
                 let percent = ((elapsed / duration_secs as f32) * 100.0).min(100.0) as u8;
+                if crate::fmt::quiet_progress() {
+                    // Sample once per second like the other components
+                    if elapsed as u64 > last_logged_second {
+                        last_logged_second = elapsed as u64;
+                        let temp = crate::stress::gpu::get_gpu_temp_for(vendor).map(|t| t.current);
+                        crate::fmt::emit_progress_json("gpu", percent, temp, last_logged_second);
+                    }
+                    continue;
+                }
+                if crate::fmt::plain_progress() {
+                    // Piped stdout: one log line per second instead of redrawing in place
+                    if elapsed as u64 > last_logged_second {
+                        last_logged_second = elapsed as u64;
+                        println!("{}", crate::fmt::progress_log_line(last_logged_second,
+                            &format!("GPU {}% {} {} frames", percent, crate::fmt::format_eta(elapsed as u64, duration_secs), frames_dispatched)));
+                    }
+                    continue;
+                }
                 // Use same format as CPU: █ for filled, ░ for empty
-                let filled = (percent as usize * 14 / 100).min(14);
-                let empty = 14 - filled;
-                let bar = format!("{}{}{}{}",
-                    "\x1b[32m", "█".repeat(filled), "\x1b[90m", "░".repeat(empty));
-                print!("\r⏳ GPU: [{}\x1b[0m] {}% | {} frames",
-                       bar, percent, frames_dispatched);
+                let bar = crate::fmt::progress_bar(percent, 14);
+                print!("\r⏳ GPU: [{}] {}% {} | {} frames",
+                       bar, percent, crate::fmt::format_eta(elapsed as u64, duration_secs), frames_dispatched);
                 use std::io::Write;
                 std::io::stdout().flush().unwrap();
             }
@@ -145,16 +233,22 @@ pub async fn run_gpu_compute_stress(
     }
 
     // Clear the progress line when done (reset color first to avoid color bleeding)
-    if show_progress {
-        print!("\x1b[0m\r\x1b[2K");  // Reset color, then clear line
+    if show_progress && !crate::fmt::quiet_progress() && !crate::fmt::plain_progress() {
+        print!("{}\r\x1b[2K", crate::fmt::color(crate::fmt::RESET));  // Reset color, then clear line
         use std::io::Write;
         std::io::stdout().flush().unwrap();
     }
 
+    let elapsed_secs = start_time.elapsed().as_secs_f64();
+    let gflops = compute_gflops(element_iterations, elapsed_secs);
+    let avg_frame_ms = if frames_dispatched > 0 { elapsed_secs * 1000.0 / frames_dispatched as f64 } else { 0.0 };
+
     Ok(GpuComputeResult {
         gpu_name,
         backend,
         frames_dispatched,
+        gflops,
+        avg_frame_ms,
     })
 }
 
@@ -164,7 +258,16 @@ pub fn run_gpu_compute_stress_sync(
     duration_secs: u64,
     show_progress: bool,
 ) -> Result<GpuComputeResult, String> {
-    pollster::block_on(run_gpu_compute_stress(duration_secs, show_progress))
+    pollster::block_on(run_gpu_compute_stress(duration_secs, show_progress, None))
+}
+
+/// Run compute load until `stop` is set or `max_secs` elapses (no progress output)
+#[cfg(feature = "gpu-compute")]
+pub fn run_gpu_compute_stress_until(
+    max_secs: u64,
+    stop: &AtomicBool,
+) -> Result<GpuComputeResult, String> {
+    pollster::block_on(run_gpu_compute_stress(max_secs, false, Some(stop)))
 }
 
 /// Stub implementation when gpu-compute feature is disabled
@@ -176,5 +279,38 @@ pub fn run_gpu_compute_stress_sync(
     Err("GPU compute stress test not enabled. Build with --features gpu-compute".to_string())
 }
 
+/// Stub implementation when gpu-compute feature is disabled
+#[cfg(not(feature = "gpu-compute"))]
+pub fn run_gpu_compute_stress_until(
+    _max_secs: u64,
+    _stop: &AtomicBool,
+) -> Result<GpuComputeResult, String> {
+    Err("GPU compute stress test not enabled. Build with --features gpu-compute".to_string())
+}
+
 // When gpu-compute feature is disabled, use the same struct definition
 // but it's already defined above, so no need to redefine here
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_gflops() {
+        // 47 ops per iteration: 100 frames x 1M elements x 100 iterations in 2s = 235 GFLOPS
+        assert_eq!(OPS_PER_ITERATION, 47.0);
+        assert!((compute_gflops(100.0 * 1_000_000.0 * 100.0, 2.0) - 235.0).abs() < 1e-9);
+        assert_eq!(compute_gflops(0.0, 2.0), 0.0);
+        assert_eq!(compute_gflops(1e9, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_scale_iterations() {
+        // 2ms frame at 100 iterations -> 8x more work to reach 16ms
+        assert_eq!(scale_iterations(100, 0.002), 800);
+        // 160ms frame -> a tenth of the work
+        assert_eq!(scale_iterations(100, 0.16), 10);
+        assert_eq!(scale_iterations(100, 0.0), MAX_ITERATIONS);
+        assert_eq!(scale_iterations(100, 100.0), MIN_ITERATIONS);
+    }
+}
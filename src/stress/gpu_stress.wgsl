@@ -4,6 +4,17 @@
 @group(0) @binding(0)
 var<storage, read_write> data: array<f32>;
 
+// Loop count per dispatch, tuned by the host so each frame takes ~16ms
+struct Params {
+    iterations: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+}
+
+@group(0) @binding(1)
+var<uniform> params: Params;
+
 // Pseudo-random hash to prevent GPU optimization
 fn hash(value: u32) -> f32 {
     var state = value;
@@ -27,10 +38,10 @@ fn gpu_stress_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
 
     var val = data[index];
 
-    // Heavy compute loop - constant iteration count for WGSL compliance
+    // Heavy compute loop - iteration count from the params uniform
     // Using fma, trig, and sqrt to stress ALU units
     // Unrolled loops for maximum GPU load
-    for (var i = 0u; i < 100u; i++) {
+    for (var i = 0u; i < params.iterations; i++) {
         // Fused Multiply-Add (FMA) - heavy on compute units
         val = fma(val, 1.00001, 0.00002);
         val = fma(val, 1.00002, 0.00003);
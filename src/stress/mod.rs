@@ -6,17 +6,226 @@ pub mod ram;
 pub mod disk;
 pub mod gpu;
 pub mod gpu_compute;
+pub mod expected;
+pub mod criteria;
 pub mod torture;
 
+use serde::Serialize;
+
+/// Stable machine-readable cause of an issue, so scripts don't have to match localized messages
+/// Severity comes from the HealthStatus variant (e.g. CpuOverheat is a warning or a failure)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum IssueCode {
+    CpuCrash,
+    CpuOverheat,
+    CpuThrottle,
+    CpuUnstable,
+    CpuCacheError,
+    FanStopped,
+    RamError,
+    RamAllocFailed,
+    RamSlow,
+    RamMissingCapacity,
+    DiskNotTested,
+    DiskIoError,
+    DiskBadSectors,
+    DiskSlow,
+    GpuOverheat,
+    GpuNoSensor,
+    GpuLowLoad,
+    GpuNotStable,
+    BatteryWear,
+    Interrupted,
+}
+
+/// One detected problem: code for programs, message for people
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Issue {
+    pub code: IssueCode,
+    pub message: String,
+}
+
+impl Issue {
+    pub fn new(code: IssueCode, message: impl Into<String>) -> Self {
+        Self { code, message: message.into() }
+    }
+}
+
+impl std::fmt::Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Issue for a fan `FanHistory::stuck_fan` found stopped while the component reached `peak_c`
+fn fan_failure_issue(fan: &crate::sensors::FanReading, peak_c: Option<f32>) -> Issue {
+    Issue::new(IssueCode::FanStopped, format!(
+        "Fan not spinning under load ({} at 0 RPM, {:.0}°C) - possible fan failure",
+        fan.label,
+        peak_c.unwrap_or_default()
+    ))
+}
+
 /// Health status after hardware test
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum HealthStatus {
     Healthy,
-    IssuesDetected(Vec<String>),
-    Failed(String),
+    IssuesDetected(Vec<Issue>),
+    Failed(Issue),
+}
+
+impl HealthStatus {
+    /// Add a non-fatal issue (Failed stays Failed)
+    pub fn with_issue(self, issue: Issue) -> Self {
+        match self {
+            HealthStatus::Healthy => HealthStatus::IssuesDetected(vec![issue]),
+            HealthStatus::IssuesDetected(mut issues) => {
+                issues.push(issue);
+                HealthStatus::IssuesDetected(issues)
+            }
+            failed => failed,
+        }
+    }
+}
+
+/// Worst status across a run, ordered by severity (mapped to the process exit code)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum RunOutcome {
+    #[default]
+    Healthy,
+    IssuesDetected,
+    Failed,
+}
+
+impl RunOutcome {
+    /// Outcome of one component; `healthy` is false when the report flagged extra issues
+    pub fn from_result(status: &HealthStatus, healthy: bool) -> Self {
+        match status {
+            HealthStatus::Failed(_) => RunOutcome::Failed,
+            HealthStatus::IssuesDetected(_) => RunOutcome::IssuesDetected,
+            HealthStatus::Healthy if !healthy => RunOutcome::IssuesDetected,
+            HealthStatus::Healthy => RunOutcome::Healthy,
+        }
+    }
+
+    /// 0 = healthy, 1 = issues detected, 2 = failed
+    pub fn exit_code(self) -> i32 {
+        match self {
+            RunOutcome::Healthy => 0,
+            RunOutcome::IssuesDetected => 1,
+            RunOutcome::Failed => 2,
+        }
+    }
 }
 
-pub use cpu::{CpuTestConfig, CpuTestResult, run_stress_test as run_cpu_test};
-pub use ram::{RamTestConfig, RamTestResult, run_stress_test as run_ram_test};
+/// Temperature thresholds for the CPU/GPU health verdict
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TempLimits {
+    pub warn_c: f32,
+    pub fail_c: f32,
+}
+
+impl Default for TempLimits {
+    /// Fixed thresholds from Check.md, used when TjMax is unknown
+    fn default() -> Self {
+        Self { warn_c: 85.0, fail_c: 95.0 }
+    }
+}
+
+impl TempLimits {
+    /// Plausible range for user-supplied limits (°C)
+    pub const RANGE: std::ops::RangeInclusive<f32> = 40.0..=110.0;
+
+    /// Fail at the throttle point, warn within 10°C of it
+    pub fn from_tjmax(tjmax_c: f32) -> Self {
+        Self { warn_c: tjmax_c - 10.0, fail_c: tjmax_c }
+    }
+
+    /// Limits from --temp-warn/--temp-fail; None when neither is given
+    /// A fail limit alone warns 10°C below it, a warn limit alone keeps the default fail limit
+    pub fn from_overrides(warn_c: Option<f32>, fail_c: Option<f32>) -> Result<Option<Self>, String> {
+        let limits = match (warn_c, fail_c) {
+            (None, None) => return Ok(None),
+            (Some(warn_c), Some(fail_c)) => Self { warn_c, fail_c },
+            (None, Some(fail_c)) => Self::from_tjmax(fail_c),
+            (Some(warn_c), None) => Self { warn_c, ..Self::default() },
+        };
+        for temp in [limits.warn_c, limits.fail_c] {
+            if !Self::RANGE.contains(&temp) {
+                return Err(format!("temperature limit {}°C is outside 40-110°C", temp));
+            }
+        }
+        if limits.warn_c >= limits.fail_c {
+            return Err(format!(
+                "warning limit ({}°C) must be below the failure limit ({}°C)",
+                limits.warn_c, limits.fail_c
+            ));
+        }
+        Ok(Some(limits))
+    }
+}
+
+/// Minimal-work mode for `cargo test` and constrained CI sandboxes (PCHECKER_TEST_MODE=1, debug builds only)
+/// Stress tests keep their code paths but shrink workload, buffer sizes and tick length;
+/// release builds ignore the variable so a shortened run can't pass for a real health check
+pub fn test_mode() -> bool {
+    cfg!(test) || (cfg!(debug_assertions) && std::env::var_os("PCHECKER_TEST_MODE").is_some_and(|v| v != "0"))
+}
+
+/// Callback used by stress tests to surface real-time commentary
+pub type CommentCallback = Box<dyn Fn(&str) + Send>;
+
+/// Callback receiving the overall progress percentage (0-100) of a running test
+pub type ProgressCallback = Box<dyn Fn(u8) + Send>;
+
+pub use cpu::{CpuTestConfig, CpuTestResult, CpuWorkload, run_stress_test as run_cpu_test};
+pub use ram::{RamAccess, RamTestConfig, RamTestResult, run_stress_test as run_ram_test};
 pub use disk::{DiskTestConfig, DiskTestResult, run_stress_test as run_disk_test};
 pub use gpu::{GpuTestConfig, GpuTestResult, run_stress_test as run_gpu_test};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_outcome() {
+        let issues = HealthStatus::IssuesDetected(vec![Issue::new(IssueCode::RamSlow, "slow")]);
+        assert_eq!(RunOutcome::from_result(&HealthStatus::Healthy, true), RunOutcome::Healthy);
+        assert_eq!(RunOutcome::from_result(&HealthStatus::Healthy, false), RunOutcome::IssuesDetected);
+        assert_eq!(RunOutcome::from_result(&issues, false), RunOutcome::IssuesDetected);
+        assert_eq!(RunOutcome::from_result(&HealthStatus::Failed(Issue::new(IssueCode::RamError, "dead")), false), RunOutcome::Failed);
+
+        // Worst status wins
+        let worst = [RunOutcome::IssuesDetected, RunOutcome::Failed, RunOutcome::Healthy].into_iter().max().unwrap();
+        assert_eq!(worst.exit_code(), 2);
+        assert_eq!(RunOutcome::default().exit_code(), 0);
+    }
+
+    #[test]
+    fn test_temp_limit_overrides() {
+        assert_eq!(TempLimits::from_overrides(None, None), Ok(None));
+        assert_eq!(TempLimits::from_overrides(Some(75.0), Some(90.0)), Ok(Some(TempLimits { warn_c: 75.0, fail_c: 90.0 })));
+        assert_eq!(TempLimits::from_overrides(None, Some(100.0)), Ok(Some(TempLimits { warn_c: 90.0, fail_c: 100.0 })));
+        assert_eq!(TempLimits::from_overrides(Some(90.0), None), Ok(Some(TempLimits { warn_c: 90.0, fail_c: 95.0 })));
+
+        assert!(TempLimits::from_overrides(Some(90.0), Some(90.0)).is_err());
+        assert!(TempLimits::from_overrides(Some(100.0), None).is_err());
+        assert!(TempLimits::from_overrides(None, Some(45.0)).is_err());
+        assert!(TempLimits::from_overrides(Some(30.0), Some(80.0)).is_err());
+        assert!(TempLimits::from_overrides(Some(80.0), Some(120.0)).is_err());
+    }
+
+    #[test]
+    fn test_torture_outcome() {
+        let cancelled = torture::TortureResult {
+            _duration_actual_secs: 0,
+            cpu_result: None,
+            ram_result: None,
+            disk_result: None,
+            gpu_result: None,
+            survived: false,
+            stuck_fan: None,
+        };
+        assert_eq!(cancelled.outcome(), RunOutcome::Failed);
+    }
+}
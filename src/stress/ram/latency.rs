@@ -0,0 +1,108 @@
+// RAM latency microbenchmark (--ram-latency)
+// Pointer chasing through a random cycle of cache lines: each load needs the previous one's result,
+// so neither out-of-order execution nor the prefetcher can hide the trip to DRAM
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use crate::interrupt::interrupted;
+
+/// Words per 64-byte cache line; each node of the chain owns one line
+const LINE_WORDS: usize = 64 / std::mem::size_of::<u64>();
+/// Buffer is this many times the L3 size so nearly every hop misses the cache
+const L3_MULTIPLIER: u64 = 8;
+const MIN_BUFFER_BYTES: u64 = 64 * 1024 * 1024;
+const MAX_BUFFER_BYTES: u64 = 1024 * 1024 * 1024;
+/// Used when the L3 size is unknown
+const DEFAULT_BUFFER_BYTES: u64 = 256 * 1024 * 1024;
+/// Dependent loads timed (~1s at 100ns each)
+const LOADS: usize = 10_000_000;
+/// Loads between interrupt checks
+const LOADS_PER_CHECK: usize = 1 << 20;
+
+/// Buffer size for the given L3 cache size
+fn buffer_bytes(l3_kb: Option<u32>) -> u64 {
+    match l3_kb {
+        Some(kb) => (kb as u64 * 1024 * L3_MULTIPLIER).clamp(MIN_BUFFER_BYTES, MAX_BUFFER_BYTES),
+        None => DEFAULT_BUFFER_BYTES,
+    }
+}
+
+/// Successor of every node in one random cycle through all of them (Sattolo's algorithm)
+fn random_cycle(nodes: usize) -> Vec<usize> {
+    let mut next: Vec<usize> = (0..nodes).collect();
+    for i in (1..nodes).rev() {
+        next.swap(i, fastrand::usize(0..i));
+    }
+    next
+}
+
+/// Average nanoseconds per dependent random load, None if interrupted or the buffer can't be allocated
+/// `l3_kb` is the detected L3 size (CacheInfo); the buffer is sized well beyond it
+pub fn measure_latency_ns(l3_kb: Option<u32>) -> Option<f64> {
+    let (bytes, loads) = if crate::stress::test_mode() {
+        (MIN_BUFFER_BYTES / 16, LOADS / 50)
+    } else {
+        (buffer_bytes(l3_kb), LOADS)
+    };
+    chase(bytes, loads)
+}
+
+/// Build the chain in a `bytes` buffer and time `loads` hops through it
+fn chase(bytes: u64, loads: usize) -> Option<f64> {
+    let nodes = (bytes as usize / 64).max(2);
+    let mut buffer = super::try_alloc_words(nodes * LINE_WORDS)?;
+    for (node, next) in random_cycle(nodes).into_iter().enumerate() {
+        buffer[node * LINE_WORDS] = (next * LINE_WORDS) as u64;
+    }
+
+    let mut pos = 0;
+    let mut done = 0;
+    let start = Instant::now();
+    while done < loads {
+        if interrupted() {
+            return None;
+        }
+        let batch = LOADS_PER_CHECK.min(loads - done);
+        for _ in 0..batch {
+            pos = buffer[pos] as usize;
+        }
+        done += batch;
+    }
+    let elapsed = start.elapsed();
+    black_box(pos);
+
+    Some(elapsed.as_nanos() as f64 / loads as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_cycle_visits_every_node() {
+        for nodes in [2, 3, 10, 1000] {
+            let next = random_cycle(nodes);
+            let mut seen = vec![false; nodes];
+            let mut pos = 0;
+            for _ in 0..nodes {
+                assert!(!seen[pos], "node {} visited twice ({} nodes)", pos, nodes);
+                seen[pos] = true;
+                pos = next[pos];
+            }
+            assert_eq!(pos, 0);
+        }
+    }
+
+    #[test]
+    fn test_buffer_bytes() {
+        // 32 MB L3 -> 256 MB buffer; small caches get the floor, huge ones the cap
+        assert_eq!(buffer_bytes(Some(32 * 1024)), 256 * 1024 * 1024);
+        assert_eq!(buffer_bytes(Some(2 * 1024)), MIN_BUFFER_BYTES);
+        assert_eq!(buffer_bytes(Some(256 * 1024)), MAX_BUFFER_BYTES);
+        assert_eq!(buffer_bytes(None), DEFAULT_BUFFER_BYTES);
+
+        let latency = chase(1024 * 1024, 100_000).unwrap();
+        assert!(latency > 0.0 && latency < 10_000.0, "{} ns", latency);
+    }
+}
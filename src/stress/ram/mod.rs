@@ -1,20 +1,89 @@
 // RAM health check module
 // Tests RAM by allocating memory and verifying write/read patterns
 
+pub mod latency;
+
 use std::time::Instant;
 use std::io::{self, Write};
+use serde::Serialize;
 use sysinfo::System;
 
-use super::HealthStatus;
+use super::{HealthStatus, Issue, IssueCode};
+use crate::lang::Text;
+use crate::fmt::{quiet_progress, emit_progress_json};
+use crate::interrupt::{TestGuard, interrupted};
+
+/// Default test size cap, to stay clear of OOM on machines with lots of RAM
+pub const DEFAULT_MAX_GB: f64 = 16.0;
+/// Only very low streaming speed indicates an actual fault
+const MIN_SEQUENTIAL_SPEED_GB_S: f64 = 0.3;
+/// Below this share of total RAM the result carries a partial-coverage note
+pub const PARTIAL_COVERAGE_PCT: f64 = 50.0;
+/// Coverage points a larger --ram-gb has to add before the partial-coverage note suggests it
+const MIN_COVERAGE_GAIN_PCT: f64 = 5.0;
+
+/// Order in which the write/verify loops walk the buffer
+#[derive(clap::ValueEnum, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RamAccess {
+    /// Front to back (prefetcher-friendly streaming)
+    #[default]
+    Sequential,
+    /// Chunks in shuffled order, words at a random stride (defeats prefetching)
+    Random,
+}
+
+impl RamAccess {
+    pub fn name(self) -> &'static str {
+        match self {
+            RamAccess::Sequential => "sequential",
+            RamAccess::Random => "random",
+        }
+    }
+
+    /// Speed below which the RAM is failed; random access is latency-bound, so no floor applies
+    fn min_speed_gb_s(self) -> Option<f64> {
+        match self {
+            RamAccess::Sequential => Some(MIN_SEQUENTIAL_SPEED_GB_S),
+            RamAccess::Random => None,
+        }
+    }
+}
 
-#[derive(Default)]
 pub struct RamTestConfig {
+    /// Amount to test (--ram-gb), None = 80% of available capped at DEFAULT_MAX_GB
     pub max_gb: Option<f64>,
+    /// Write/verify passes, each with the next pattern from `RAM_PATTERNS`
+    pub passes: u32,
+    pub access: RamAccess,
+    /// Measure random-access latency after the passes (--ram-latency)
+    pub latency: bool,
+    /// Detected L3 size, the latency buffer is sized beyond it
+    pub l3_cache_kb: Option<u32>,
+    // Text for i18n
+    pub text: Text,
     // AI commentary callbacks (optional, for real-time comments)
-    pub on_comment: Option<Box<dyn Fn(&str) + Send>>,
+    pub on_comment: Option<super::CommentCallback>,
+    /// Overall progress: write phase reports 0-50%, verify phase 50-100%
+    pub on_progress: Option<super::ProgressCallback>,
 }
 
+impl Default for RamTestConfig {
+    fn default() -> Self {
+        Self {
+            max_gb: None,
+            passes: 1,
+            access: RamAccess::default(),
+            latency: false,
+            l3_cache_kb: None,
+            text: Text::new(crate::lang::Language::Vietnamese),
+            on_comment: None,
+            on_progress: None,
+        }
+    }
+}
 
+#[derive(Serialize)]
 pub struct RamTestResult {
     // Hardware info
     pub ram_total_gb: f64,
@@ -23,12 +92,157 @@ pub struct RamTestResult {
     pub write_speed_gb_s: f64,
     pub read_speed_gb_s: f64,
     pub errors: u64,
+    pub passes: u32,
+    pub access: RamAccess,
+    /// Names of the patterns that read back wrong
+    pub failed_patterns: Vec<String>,
+    /// Nanoseconds per dependent random load (None unless --ram-latency)
+    pub latency_ns: Option<f64>,
+    /// Largest size a --ram-gb request would have been granted (90% of available RAM at test time)
+    #[serde(skip)]
+    pub max_request_gb: f64,
     pub health: HealthStatus,
 }
 
+/// Data written to every u64 of the test buffer in one pass
+#[derive(Debug, Clone, Copy)]
+enum RamPattern {
+    Constant(&'static str, u64),
+    /// A single set bit moving one position per word (catches coupled bits)
+    WalkingOnes,
+    /// Each word holds its own index (catches address line faults)
+    AddressAsData,
+}
+
+/// Patterns cycled through by the passes; pass 1 is the original checkerboard
+const RAM_PATTERNS: [RamPattern; 7] = [
+    RamPattern::Constant("checkerboard", 0xAA55_AA55_AA55_AA55),
+    RamPattern::Constant("all-zeros", 0),
+    RamPattern::Constant("all-ones", u64::MAX),
+    RamPattern::Constant("0x55", 0x5555_5555_5555_5555),
+    RamPattern::Constant("0xAA", 0xAAAA_AAAA_AAAA_AAAA),
+    RamPattern::WalkingOnes,
+    RamPattern::AddressAsData,
+];
+
+impl RamPattern {
+    fn for_pass(pass: u32) -> Self {
+        RAM_PATTERNS[pass as usize % RAM_PATTERNS.len()]
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            RamPattern::Constant(name, _) => name,
+            RamPattern::WalkingOnes => "walking-ones",
+            RamPattern::AddressAsData => "address-as-data",
+        }
+    }
+
+    /// Expected value of the word at `index` in the whole buffer
+    fn value(self, index: usize) -> u64 {
+        match self {
+            RamPattern::Constant(_, value) => value,
+            RamPattern::WalkingOnes => 1u64 << (index % 64),
+            RamPattern::AddressAsData => index as u64,
+        }
+    }
+
+    /// Write the pattern into a chunk starting at buffer index `base`
+    /// `stride` = None walks front to back, Some(s) visits every word once in steps of s
+    fn fill(self, chunk: &mut [u64], base: usize, stride: Option<usize>) {
+        match (self, stride) {
+            (RamPattern::Constant(_, value), None) => chunk.fill(value),
+            (_, None) => {
+                for (i, val) in chunk.iter_mut().enumerate() {
+                    *val = self.value(base + i);
+                }
+            }
+            (_, Some(stride)) => {
+                for i in StridedIndices::new(chunk.len(), stride) {
+                    chunk[i] = self.value(base + i);
+                }
+            }
+        }
+    }
+
+    /// Count words in a chunk that don't hold the pattern
+    fn count_mismatches(self, chunk: &[u64], base: usize, stride: Option<usize>) -> u64 {
+        match (self, stride) {
+            (RamPattern::Constant(_, value), None) => chunk.iter().filter(|&&v| v != value).count() as u64,
+            (_, None) => chunk
+                .iter()
+                .enumerate()
+                .filter(|&(i, &v)| v != self.value(base + i))
+                .count() as u64,
+            (_, Some(stride)) => StridedIndices::new(chunk.len(), stride)
+                .filter(|&i| chunk[i] != self.value(base + i))
+                .count() as u64,
+        }
+    }
+}
+
+/// Every index in 0..len exactly once, `stride` apart (mod len); stride must be coprime to len
+struct StridedIndices {
+    len: usize,
+    stride: usize,
+    next: usize,
+    remaining: usize,
+}
+
+impl StridedIndices {
+    fn new(len: usize, stride: usize) -> Self {
+        Self { len, stride: if len > 0 { stride % len } else { 0 }, next: 0, remaining: len }
+    }
+}
+
+impl Iterator for StridedIndices {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let index = self.next;
+        self.remaining -= 1;
+        self.next += self.stride;
+        if self.next >= self.len {
+            self.next -= self.len;
+        }
+        Some(index)
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Random stride in the upper half of 0..len that is coprime to len (so the walk covers every word)
+fn random_stride(len: usize) -> usize {
+    if len < 3 {
+        return 1;
+    }
+    loop {
+        let stride = fastrand::usize(len / 2..len);
+        if gcd(stride, len) == 1 {
+            return stride;
+        }
+    }
+}
+
+/// Chunk visiting order for one pass: in order, or shuffled for random access
+fn chunk_order(total_chunks: usize, access: RamAccess) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..total_chunks).collect();
+    if access == RamAccess::Random {
+        fastrand::shuffle(&mut order);
+    }
+    order
+}
+
 /// Run RAM health check
 /// Allocates memory, writes patterns, reads back to verify
 pub fn run_stress_test(config: RamTestConfig, ram_total_gb: f64) -> RamTestResult {
+    let _guard = TestGuard::new();
+
     // Get available memory
     let mut sys = System::new_all();
     sys.refresh_memory();
@@ -36,97 +250,206 @@ pub fn run_stress_test(config: RamTestConfig, ram_total_gb: f64) -> RamTestResul
     let total_gb = sys.total_memory() as f64 / 1024.0 / 1024.0 / 1024.0;
     let available_gb = (sys.total_memory() - sys.used_memory()) as f64 / 1024.0 / 1024.0 / 1024.0;
 
-    // Use 80% of available RAM, or specified amount
-    let test_gb = config.max_gb.unwrap_or_else(|| {
-        (available_gb * 0.8).min(total_gb * 0.8)
-    });
-
-    // Limit to max 16GB to prevent OOM on systems with lots of RAM
-    let test_gb = test_gb.min(16.0);
+    // 100MB in test mode
+    let test_gb = if super::test_mode() {
+        config.max_gb.unwrap_or(0.1).min(0.1)
+    } else {
+        select_test_gb(config.max_gb, available_gb, total_gb)
+    };
 
-    print!("⏳ Checking RAM... Allocating {:.1} GB...", test_gb);
-    io::stdout().flush().unwrap();
+    let text = &config.text;
+    let quiet = quiet_progress();
+    if !quiet {
+        print!("⏳ {}", text.ram_allocating().replace("{SIZE}", &format!("{:.1}", test_gb)));
+        io::stdout().flush().unwrap();
+    }
 
-    // Allocate buffer
+    // Allocate buffer (a refused allocation fails the test instead of aborting the process)
     let element_count = (test_gb * 1024.0 * 1024.0 * 1024.0 / 8.0) as usize;
-    let mut buffer: Vec<u64> = vec![0; element_count];
+    let Some(mut buffer) = try_alloc_words(element_count) else {
+        if !quiet {
+            println!();
+        }
+        return RamTestResult {
+            ram_total_gb,
+            tested_gb: 0.0,
+            write_speed_gb_s: 0.0,
+            read_speed_gb_s: 0.0,
+            errors: 0,
+            passes: 0,
+            access: config.access,
+            failed_patterns: Vec::new(),
+            latency_ns: None,
+            max_request_gb: max_request_gb(available_gb),
+            health: HealthStatus::Failed(Issue::new(
+                IssueCode::RamAllocFailed,
+                format!("Could not allocate {:.1} GB", test_gb),
+            )),
+        };
+    };
 
-    let _start = Instant::now();
+    let start = Instant::now();
 
     // Clone callback for use in loop
     let comment_callback = config.on_comment;
+    let progress_callback = config.on_progress;
 
-    // Write test: fill buffer with pattern
-    print!("\r⏳ Checking RAM... Writing data...");
-    io::stdout().flush().unwrap();
-
-    let write_start = Instant::now();
-    let pattern = 0xAA55_AA55_AA55_AA55_u64;
+    let passes = config.passes.max(1);
     let chunk_size = 1024 * 1024;
     let total_chunks = element_count.div_ceil(chunk_size);
+    // Each pass covers an equal share of the overall percent: write first half, verify second
+    let overall = |pass: u32, pass_percent: u8| ((pass * 100 + pass_percent as u32) / passes) as u8;
 
-    for (i, chunk) in buffer.chunks_mut(chunk_size).enumerate() {
-        for val in chunk.iter_mut() {
-            *val = pattern;
-        }
-        // Show progress every 100 chunks
-        if (i + 1) % 100 == 0 || i + 1 == total_chunks {
-            let progress = ((i + 1) * 100 / total_chunks) as u8;
-            print!("\r⏳ Checking RAM... Writing data... {}%", progress);
+    let mut errors = 0u64;
+    let mut failed_patterns: Vec<String> = Vec::new();
+    let mut write_secs = 0.0;
+    let mut read_secs = 0.0;
+    let mut passes_done = 0u32;
+
+    for pass in 0..passes {
+        let pattern = RamPattern::for_pass(pass);
+        let (writing, verifying) = if passes > 1 {
+            let tag = format!("[{}/{} {}]", pass + 1, passes, pattern.name());
+            (format!("{} {}", text.ram_writing(), tag), format!("{} {}", text.ram_verifying(), tag))
+        } else {
+            (text.ram_writing().to_string(), text.ram_verifying().to_string())
+        };
+
+        // Write test: fill buffer with pattern
+        if !quiet {
+            print!("\r⏳ {}", writing);
             io::stdout().flush().unwrap();
         }
-    }
 
-    let write_time = write_start.elapsed();
-    let write_speed = if write_time.as_secs_f64() > 0.0 {
-        test_gb / write_time.as_secs_f64()
-    } else {
-        0.0
-    };
-
-    // AI commentary on write speed
-    if let Some(ref callback) = comment_callback {
-        if write_speed > 10.0 {
-            callback(&format!("RAM write speed: {:.1} GB/s - excellent", write_speed));
-        } else if write_speed > 5.0 {
-            callback(&format!("RAM write speed: {:.1} GB/s - good", write_speed));
-        } else {
-            callback(&format!("RAM write speed: {:.1} GB/s", write_speed));
+        let write_start = Instant::now();
+        for (i, &c) in chunk_order(total_chunks, config.access).iter().enumerate() {
+            if interrupted() {
+                break;
+            }
+            let base = c * chunk_size;
+            let chunk = &mut buffer[base..(base + chunk_size).min(element_count)];
+            let stride = (config.access == RamAccess::Random).then(|| random_stride(chunk.len()));
+            pattern.fill(chunk, base, stride);
+            // Show progress every 100 chunks
+            if (i + 1) % 100 == 0 || i + 1 == total_chunks {
+                let progress = ((i + 1) * 100 / total_chunks) as u8;
+                let percent = overall(pass, progress / 2);
+                if let Some(ref callback) = progress_callback {
+                    callback(percent);
+                }
+                if quiet {
+                    emit_progress_json("ram", percent, None, start.elapsed().as_secs());
+                } else {
+                    print!("\r⏳ {} {}%", writing, progress);
+                    io::stdout().flush().unwrap();
+                }
+            }
+        }
+        write_secs += write_start.elapsed().as_secs_f64();
+
+        // AI commentary on write speed (first pass only)
+        if pass == 0 {
+            if let Some(ref callback) = comment_callback {
+                let write_speed = if write_secs > 0.0 { test_gb / write_secs } else { 0.0 };
+                if write_speed > 10.0 {
+                    callback(&format!("RAM write speed: {:.1} GB/s - excellent", write_speed));
+                } else if write_speed > 5.0 {
+                    callback(&format!("RAM write speed: {:.1} GB/s - good", write_speed));
+                } else {
+                    callback(&format!("RAM write speed: {:.1} GB/s", write_speed));
+                }
+            }
         }
-    }
 
-    // Read + verify test
-    print!("\r⏳ Checking RAM... Verifying data...");
-    io::stdout().flush().unwrap();
+        // Unwritten memory would read back as errors, so stop before verifying
+        if interrupted() {
+            return RamTestResult {
+                ram_total_gb,
+                tested_gb: test_gb,
+                write_speed_gb_s: if write_secs > 0.0 { test_gb * (pass + 1) as f64 / write_secs } else { 0.0 },
+                read_speed_gb_s: 0.0,
+                errors,
+                passes: passes_done,
+                access: config.access,
+                failed_patterns,
+                latency_ns: None,
+                max_request_gb: max_request_gb(available_gb),
+                health: HealthStatus::Failed(Issue::new(IssueCode::Interrupted, "Interrupted")),
+            };
+        }
 
-    let mut errors = 0u64;
-    let read_start = Instant::now();
+        // Read + verify test
+        if !quiet {
+            print!("\r⏳ {}", verifying);
+            io::stdout().flush().unwrap();
+        }
 
-    for (i, chunk) in buffer.chunks(chunk_size).enumerate() {
-        for &val in chunk.iter() {
-            if val != pattern {
-                errors += 1;
+        let mut pass_errors = 0u64;
+        let read_start = Instant::now();
+        for (i, &c) in chunk_order(total_chunks, config.access).iter().enumerate() {
+            if interrupted() {
+                break;
+            }
+            let base = c * chunk_size;
+            let chunk = &buffer[base..(base + chunk_size).min(element_count)];
+            let stride = (config.access == RamAccess::Random).then(|| random_stride(chunk.len()));
+            pass_errors += pattern.count_mismatches(chunk, base, stride);
+            // Show progress every 100 chunks
+            if (i + 1) % 100 == 0 || i + 1 == total_chunks {
+                let progress = ((i + 1) * 100 / total_chunks) as u8;
+                let percent = overall(pass, 50 + progress / 2);
+                if let Some(ref callback) = progress_callback {
+                    callback(percent);
+                }
+                if quiet {
+                    emit_progress_json("ram", percent, None, start.elapsed().as_secs());
+                } else {
+                    print!("\r⏳ {} {}%", verifying, progress);
+                    io::stdout().flush().unwrap();
+                }
             }
         }
-        // Show progress every 100 chunks
-        if (i + 1) % 100 == 0 || i + 1 == total_chunks {
-            let progress = ((i + 1) * 100 / total_chunks) as u8;
-            print!("\r⏳ Checking RAM... Verifying data... {}%", progress);
-            io::stdout().flush().unwrap();
+        read_secs += read_start.elapsed().as_secs_f64();
+
+        if pass_errors > 0 {
+            errors += pass_errors;
+            failed_patterns.push(pattern.name().to_string());
+        }
+        passes_done += 1;
+        if interrupted() {
+            break;
         }
     }
 
-    let read_time = read_start.elapsed();
-    let read_speed = if read_time.as_secs_f64() > 0.0 {
-        test_gb / read_time.as_secs_f64()
+    let tested_total_gb = test_gb * passes_done as f64;
+    let write_speed = if write_secs > 0.0 { tested_total_gb / write_secs } else { 0.0 };
+    let read_speed = if read_secs > 0.0 { tested_total_gb / read_secs } else { 0.0 };
+
+    // Latency runs on its own buffer, sized from the L3 cache rather than free memory
+    drop(buffer);
+    let latency_ns = if config.latency && !interrupted() {
+        if !quiet {
+            print!("\r\x1b[2K⏳ {}", text.ram_measuring_latency());
+            io::stdout().flush().unwrap();
+        }
+        latency::measure_latency_ns(config.l3_cache_kb)
     } else {
-        0.0
+        None
     };
 
-    println!(); // New line after progress
+    if !quiet {
+        println!(); // New line after progress
+    }
 
     // Evaluate health
-    let health = evaluate_ram_health(test_gb, write_speed, read_speed, errors);
+    let health = evaluate_ram_health_with_patterns(
+        test_gb,
+        write_speed,
+        read_speed,
+        errors,
+        &failed_patterns,
+        config.access.min_speed_gb_s(),
+    );
 
     RamTestResult {
         ram_total_gb,
@@ -134,45 +457,135 @@ pub fn run_stress_test(config: RamTestConfig, ram_total_gb: f64) -> RamTestResul
         write_speed_gb_s: write_speed,
         read_speed_gb_s: read_speed,
         errors,
+        passes: passes_done,
+        access: config.access,
+        failed_patterns,
+        latency_ns,
+        max_request_gb: max_request_gb(available_gb),
         health,
     }
 }
 
+/// Zeroed buffer of `count` words, None when the allocator refuses instead of aborting the process
+/// Under Linux overcommit the reservation can succeed for memory that isn't there, and the zero fill then gets
+/// the process OOM-killed rather than returning None; that's why callers size the buffer from available RAM
+pub fn try_alloc_words(count: usize) -> Option<Vec<u64>> {
+    let mut buffer = Vec::new();
+    buffer.try_reserve_exact(count).ok()?;
+    buffer.resize(count, 0);
+    Some(buffer)
+}
+
+/// Cap on a requested test size: 90% of available RAM
+fn max_request_gb(available_gb: f64) -> f64 {
+    available_gb * 0.9
+}
+
+/// Test size: the requested amount (never more than 90% of available, to avoid OOM),
+/// otherwise 80% of available RAM capped at DEFAULT_MAX_GB
+fn select_test_gb(requested_gb: Option<f64>, available_gb: f64, total_gb: f64) -> f64 {
+    match requested_gb {
+        Some(gb) => gb.min(max_request_gb(available_gb)),
+        None => (available_gb * 0.8).min(total_gb * 0.8).min(DEFAULT_MAX_GB),
+    }
+}
+
+impl RamTestResult {
+    /// Share of total RAM that was exercised (%)
+    pub fn coverage_pct(&self) -> f64 {
+        if self.ram_total_gb > 0.0 {
+            (self.tested_gb / self.ram_total_gb * 100.0).min(100.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// True when most of the RAM was never written (informational, not an issue)
+    pub fn is_partial_coverage(&self) -> bool {
+        self.coverage_pct() < PARTIAL_COVERAGE_PCT
+    }
+
+    /// Whether a larger --ram-gb would have raised coverage noticeably (false when the rest of the RAM was in use)
+    pub fn can_test_more(&self) -> bool {
+        self.ram_total_gb > 0.0
+            && (self.max_request_gb - self.tested_gb) / self.ram_total_gb * 100.0 >= MIN_COVERAGE_GAIN_PCT
+    }
+}
+
 /// Evaluate RAM health based on test results
-fn evaluate_ram_health(test_gb: f64, write: f64, read: f64, errors: u64) -> HealthStatus {
+pub fn evaluate_ram_health(test_gb: f64, write: f64, read: f64, errors: u64) -> HealthStatus {
+    evaluate_ram_health_with_patterns(test_gb, write, read, errors, &[], Some(MIN_SEQUENTIAL_SPEED_GB_S))
+}
+
+/// Evaluate RAM health, naming the patterns that failed so the fault type can be told apart
+pub fn evaluate_ram_health_with_patterns(
+    test_gb: f64,
+    write: f64,
+    read: f64,
+    errors: u64,
+    failed_patterns: &[String],
+    min_speed_gb_s: Option<f64>,
+) -> HealthStatus {
     // Critical: any memory errors = BAD RAM
     if errors > 0 {
-        return HealthStatus::Failed(format!(
-            "Memory errors detected ({} errors) - BAD RAM",
-            errors
-        ));
+        let patterns = if failed_patterns.is_empty() {
+            String::new()
+        } else {
+            format!(", patterns: {}", failed_patterns.join(", "))
+        };
+        return HealthStatus::Failed(Issue::new(IssueCode::RamError, format!(
+            "Memory errors detected ({} errors{}) - BAD RAM",
+            errors, patterns
+        )));
     }
 
     // Check if allocation worked at all
     if test_gb < 0.1 {
-        return HealthStatus::Failed("Memory allocation failed".to_string());
+        return HealthStatus::Failed(Issue::new(IssueCode::RamAllocFailed, "Memory allocation failed"));
     }
 
-    // Only very low speed indicates actual fault (< 0.3 GB/s)
+    // Only very low speed indicates actual fault
     // Speed variations are normal depending on RAM type, generation, system load
-    if write < 0.3 {
-        return HealthStatus::Failed(format!(
-            "Extremely low write speed ({:.1} GB/s) - faulty RAM or wrong slot",
-            write
-        ));
-    }
+    if let Some(min_speed) = min_speed_gb_s {
+        if write < min_speed {
+            return HealthStatus::Failed(Issue::new(IssueCode::RamSlow, format!(
+                "Extremely low write speed ({:.1} GB/s) - faulty RAM or wrong slot",
+                write
+            )));
+        }
 
-    if read < 0.3 {
-        return HealthStatus::Failed(format!(
-            "Extremely low read speed ({:.1} GB/s) - faulty RAM or wrong slot",
-            read
-        ));
+        if read < min_speed {
+            return HealthStatus::Failed(Issue::new(IssueCode::RamSlow, format!(
+                "Extremely low read speed ({:.1} GB/s) - faulty RAM or wrong slot",
+                read
+            )));
+        }
     }
 
     // Otherwise healthy
     HealthStatus::Healthy
 }
 
+/// Cross-check installed DIMM sizes against the OS-visible total
+/// The OS always sees a bit less (firmware / iGPU reservation), but a gap of most of
+/// a stick means a module failed to train or sits in a dead slot
+pub fn check_dimm_total(dimm_sizes_gb: &[f64], os_total_gb: f64) -> Option<Issue> {
+    let smallest = dimm_sizes_gb.iter().copied().filter(|&gb| gb > 0.0).fold(f64::MAX, f64::min);
+    if smallest == f64::MAX {
+        return None;
+    }
+
+    let dimm_total: f64 = dimm_sizes_gb.iter().sum();
+    if dimm_total - os_total_gb >= smallest * 0.75 {
+        Some(Issue::new(IssueCode::RamMissingCapacity, format!(
+            "DIMMs total {:.0}GB but OS sees {:.0}GB - a memory module may not be detected",
+            dimm_total, os_total_gb
+        )))
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -181,7 +594,7 @@ mod tests {
     fn test_ram_test_small() {
         let config = RamTestConfig {
             max_gb: Some(0.1), // Only test 100MB
-            on_comment: None,
+            ..Default::default()
         };
         let result = run_stress_test(config, 16.0);
 
@@ -190,6 +603,69 @@ mod tests {
         assert_eq!(result.errors, 0); // Should have no errors on healthy RAM
     }
 
+    #[test]
+    fn test_ram_latency_opt_in() {
+        let config = RamTestConfig { max_gb: Some(0.05), latency: true, ..Default::default() };
+        let result = run_stress_test(config, 16.0);
+        assert!(result.latency_ns.is_some_and(|ns| ns > 0.0));
+        assert!(run_stress_test(RamTestConfig { max_gb: Some(0.05), ..Default::default() }, 16.0).latency_ns.is_none());
+    }
+
+    #[test]
+    fn test_ram_progress_callback() {
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let config = RamTestConfig {
+            max_gb: Some(0.1),
+            on_progress: Some(Box::new(move |percent| sink.lock().unwrap().push(percent))),
+            ..Default::default()
+        };
+        run_stress_test(config, 16.0);
+
+        let seen = seen.lock().unwrap();
+        assert!(seen.windows(2).all(|w| w[0] <= w[1]), "progress went backwards: {:?}", seen);
+        assert!(seen.contains(&50));
+        assert_eq!(seen.last(), Some(&100));
+    }
+
+    #[test]
+    fn test_ram_multi_pass() {
+        let config = RamTestConfig {
+            max_gb: Some(0.1),
+            passes: 3,
+            ..Default::default()
+        };
+        let result = run_stress_test(config, 16.0);
+
+        assert_eq!(result.passes, 3);
+        assert_eq!(result.errors, 0);
+        assert!(result.failed_patterns.is_empty());
+        assert!(matches!(result.health, HealthStatus::Healthy));
+    }
+
+    #[test]
+    fn test_ram_patterns() {
+        let mut chunk = vec![0u64; 130];
+        for pattern in RAM_PATTERNS {
+            pattern.fill(&mut chunk, 64, None);
+            assert_eq!(pattern.count_mismatches(&chunk, 64, None), 0, "{}", pattern.name());
+        }
+        assert_eq!(RamPattern::WalkingOnes.value(65), 2);
+        assert_eq!(RamPattern::AddressAsData.value(1234), 1234);
+        assert_eq!(RamPattern::for_pass(7).name(), "checkerboard");
+
+        // A stuck bit shows up against the pattern that exercises it
+        chunk[3] ^= 1 << 5;
+        assert_eq!(RamPattern::AddressAsData.count_mismatches(&chunk, 64, None), 1);
+
+        let msg = match evaluate_ram_health_with_patterns(8.0, 15.0, 20.0, 2, &["all-ones".to_string()], None) {
+            HealthStatus::Failed(issue) => issue,
+            other => panic!("expected Failed, got {:?}", other),
+        };
+        assert_eq!(msg.code, IssueCode::RamError);
+        assert!(msg.message.contains("all-ones"));
+    }
+
     #[test]
     fn test_evaluate_ram_health() {
         // Healthy RAM - normal speeds
@@ -216,4 +692,79 @@ mod tests {
             HealthStatus::Failed(_)
         ));
     }
+
+    #[test]
+    fn test_check_dimm_total() {
+        // Normal reservation: 2x8GB, OS sees 15.4GB
+        assert!(check_dimm_total(&[8.0, 8.0], 15.4).is_none());
+
+        // One 8GB stick missing from 32GB
+        let issue = check_dimm_total(&[8.0, 8.0, 8.0, 8.0], 24.0).unwrap();
+        assert_eq!(issue.code, IssueCode::RamMissingCapacity);
+        assert!(issue.message.contains("32GB") && issue.message.contains("24GB"));
+
+        // No DIMM data
+        assert!(check_dimm_total(&[], 16.0).is_none());
+    }
+
+    #[test]
+    fn test_ram_random_access() {
+        // Strided walk visits every word exactly once
+        for len in [1, 2, 7, 1000, 1024] {
+            let stride = random_stride(len);
+            let mut seen: Vec<usize> = StridedIndices::new(len, stride).collect();
+            seen.sort_unstable();
+            assert_eq!(seen, (0..len).collect::<Vec<_>>(), "len {} stride {}", len, stride);
+        }
+
+        let config = RamTestConfig {
+            max_gb: Some(0.1),
+            passes: 2,
+            access: RamAccess::Random,
+            ..Default::default()
+        };
+        let result = run_stress_test(config, 16.0);
+        assert_eq!(result.access, RamAccess::Random);
+        assert_eq!(result.errors, 0);
+        assert!(result.failed_patterns.is_empty());
+        assert_eq!(result.health, HealthStatus::Healthy);
+    }
+
+    #[test]
+    fn test_try_alloc_words() {
+        assert_eq!(try_alloc_words(1024).map(|b| b.len()), Some(1024));
+        // Far beyond any address space: refused, not aborted
+        assert!(try_alloc_words(usize::MAX / 2).is_none());
+    }
+
+    #[test]
+    fn test_select_test_gb() {
+        // Default: 80% of available, capped at 16GB
+        assert_eq!(select_test_gb(None, 10.0, 16.0), 8.0);
+        assert_eq!(select_test_gb(None, 120.0, 128.0), DEFAULT_MAX_GB);
+        // --ram-gb lifts the cap but never exceeds 90% of available
+        assert_eq!(select_test_gb(Some(64.0), 120.0, 128.0), 64.0);
+        assert_eq!(select_test_gb(Some(64.0), 40.0, 128.0), 36.0);
+
+        let result = RamTestResult {
+            ram_total_gb: 128.0,
+            tested_gb: 16.0,
+            write_speed_gb_s: 10.0,
+            read_speed_gb_s: 10.0,
+            errors: 0,
+            passes: 1,
+            access: RamAccess::Sequential,
+            failed_patterns: vec![],
+            latency_ns: None,
+            max_request_gb: 108.0,
+            health: HealthStatus::Healthy,
+        };
+        assert_eq!(result.coverage_pct(), 12.5);
+        assert!(result.is_partial_coverage());
+        assert!(result.can_test_more());
+        // Default size already took everything a request could get
+        let result = RamTestResult { max_request_gb: 16.5, ..result };
+        assert!(!result.can_test_more());
+        assert!(!RamTestResult { tested_gb: 100.0, ..result }.is_partial_coverage());
+    }
 }
@@ -1,22 +1,23 @@
 // Dashboard renderer for torture test
-// Shows real-time progress on 5 lines (in-place updates)
+// Shows real-time progress on up to 5 lines (in-place updates), or one timestamped log line per refresh when stdout is piped
 
 use std::io::Write;
 use std::time::Duration;
 use crate::lang::Text;
-use crate::fmt::{RESET, temp_color};
-
-/// ANSI escape sequence to move cursor up 5 lines (to overwrite 5-line dashboard)
-const MOVE_UP_LINES: &str = "\x1b[5A";
+use crate::fmt::{RESET, color, temp_color, plain_progress, format_eta};
+use crate::sensors::{FanReading, format_fan_speeds};
+use crate::stress::torture::tests::{cpu, disk, gpu, ram};
 
 /// Render the torture test progress dashboard.
 ///
-/// Displays 5 lines of output that update in-place:
-/// - Line 1: Progress header [XX% | elapsed/total]
-/// - Line 2: CPU metrics (load, temp, frequency)
-/// - Line 3: GPU metrics (load, temp)
-/// - Line 4: RAM metrics (load, errors)
-/// - Line 5: Disk metrics (load, write speed, read speed)
+/// Displays a header plus one line per active component, updated in-place:
+/// - Line 1: Progress header [XX% | elapsed/total | ETA mm:ss | fan RPM]
+/// - CPU metrics (load, temp, frequency)
+/// - GPU metrics (load, temp)
+/// - RAM metrics (load, errors)
+/// - Disk metrics (load, write speed, read speed)
+///
+/// Components left out with --torture-components are passed as None and not shown.
 ///
 /// # Terminal Requirements
 /// - Supports ANSI escape sequences (most modern terminals)
@@ -24,13 +25,15 @@ const MOVE_UP_LINES: &str = "\x1b[5A";
 ///
 /// # Arguments
 /// * `first_render` - if true, print without moving cursor; if false, move up first
+#[allow(clippy::too_many_arguments)]
 pub fn render_torture_dashboard(
     elapsed: Duration,
     total: Duration,
-    cpu: &crate::stress::torture::tests::cpu::TestMetrics,
-    ram: &crate::stress::torture::tests::ram::TestMetrics,
-    disk: &crate::stress::torture::tests::disk::TestMetrics,
-    gpu: &crate::stress::torture::tests::gpu::TestMetrics,
+    cpu: Option<&cpu::TestMetrics>,
+    ram: Option<&ram::TestMetrics>,
+    disk: Option<&disk::TestMetrics>,
+    gpu: Option<&gpu::TestMetrics>,
+    fans: &[FanReading],
     text: &Text,
     first_render: bool,
 ) {
@@ -38,44 +41,66 @@ pub fn render_torture_dashboard(
     let total_secs = total.as_secs();
     let progress_pct = ((elapsed_secs as f32 / total_secs as f32) * 100.0).min(100.0);
 
-    // Build temperature strings with color
-    let cpu_temp_str = if let Some(temp) = cpu.temp_c {
-        format!("{}{}°C{}", temp_color(temp), temp as i32, RESET)
-    } else {
-        text.torture_na().to_string()
-    };
+    // Piped stdout: one summary line per update instead of the in-place redraw
+    if plain_progress() {
+        let temp = |t: Option<f32>| t.map_or(text.torture_na().to_string(), |t| format!("{}°C", t as i32));
+        let mut parts = vec![format!("{}% {}", progress_pct as i32, format_eta(elapsed_secs, total_secs))];
+        parts.extend(cpu.map(|cpu| format!(
+            "{} {}% {} {:.2}GHz", text.torture_cpu(), cpu.load_pct as i32, temp(cpu.temp_c), cpu.freq_ghz
+        )));
+        parts.extend(gpu.map(|gpu| format!("{} {}% {}", text.torture_gpu(), gpu.load_pct as i32, temp(gpu.temp_c))));
+        parts.extend(ram.map(|ram| format!("{} {} {}", text.torture_ram(), ram.errors, text.torture_errors())));
+        parts.extend(disk.map(|disk| format!(
+            "{} {:.3}/{:.3} {}", text.torture_disk(), disk.write_speed_mb_s, disk.read_speed_mb_s, text.torture_mb_s()
+        )));
+        if !fans.is_empty() {
+            parts.push(format!("{} {}", text.fans(), format_fan_speeds(fans)));
+        }
+        // Tenths of a second so refreshes above 1Hz stay distinguishable
+        println!("[{:.1}s] {}", elapsed.as_secs_f64(), parts.join(" | "));
+        return;
+    }
 
-    let gpu_temp_str = if let Some(temp) = gpu.temp_c {
-        format!("{}{}°C{}", temp_color(temp), temp as i32, RESET)
-    } else {
-        text.torture_na().to_string()
+    let temp_str = |temp: Option<f32>| match temp {
+        Some(temp) => format!("{}{}°C{}", temp_color(temp), temp as i32, color(RESET)),
+        None => text.torture_na().to_string(),
     };
 
-    // Build component strings
-    let cpu_line = format!("{}:  {}% {} | {} | {:.2}GHz",
+    // Build component strings (fans go on the header so the line count never changes)
+    let fan_str = if fans.is_empty() { String::new() } else { format!(" | 🌀 {}", format_fan_speeds(fans)) };
+    let mut lines = vec![format!(
+        "[{}% | {}/{}s | {}{}]",
+        progress_pct as i32,
+        elapsed_secs,
+        total_secs,
+        format_eta(elapsed_secs, total_secs),
+        fan_str
+    )];
+
+    lines.extend(cpu.map(|cpu| format!("{}:  {}% {} | {} | {:.2}GHz",
         text.torture_cpu(),
         cpu.load_pct as i32,
         text.torture_load(),
-        cpu_temp_str,
+        temp_str(cpu.temp_c),
         cpu.freq_ghz,
-    );
+    )));
 
-    let gpu_line = format!("{}:  {}% {}  | {}",
+    lines.extend(gpu.map(|gpu| format!("{}:  {}% {}  | {}",
         text.torture_gpu(),
         gpu.load_pct as i32,
         text.torture_load(),
-        gpu_temp_str,
-    );
+        temp_str(gpu.temp_c),
+    )));
 
-    let ram_line = format!("{}:  {}% {}  | {} {}",
+    lines.extend(ram.map(|ram| format!("{}:  {}% {}  | {} {}",
         text.torture_ram(),
         ram.load_pct as i32,
         text.torture_load(),
         ram.errors,
         text.torture_errors(),
-    );
+    )));
 
-    let disk_line = format!("{}: {}% {}  | {:.3} {}/{} | {:.3} {}/{}",
+    lines.extend(disk.map(|disk| format!("{}: {}% {}  | {:.3} {}/{} | {:.3} {}/{}",
         text.torture_disk(),
         disk.load_pct as i32,
         text.torture_load(),
@@ -85,19 +110,16 @@ pub fn render_torture_dashboard(
         disk.read_speed_mb_s,
         text.torture_mb_s(),
         text.torture_read(),
-    );
+    )));
 
-    // Move cursor up to overwrite previous output (5 lines)
+    // Move cursor up to overwrite previous output (same line count every render)
     if !first_render {
-        print!("{}", MOVE_UP_LINES);
+        print!("\x1b[{}A", lines.len());
     }
 
-    // Print all 5 lines
-    println!("[{}% | {}/{}s]", progress_pct as i32, elapsed_secs, total_secs);
-    println!("{}", cpu_line);
-    println!("{}", gpu_line);
-    println!("{}", ram_line);
-    println!("{}", disk_line);
+    for line in &lines {
+        println!("{}", line);
+    }
 
     std::io::stdout().flush().unwrap();
 }
@@ -1,11 +1,13 @@
 // Torture test - Full system stress test (Boss Fight)
 // Runs CPU, GPU, RAM, Disk simultaneously to detect PSU/thermal issues
 
-use std::io::{self, Write};
 use std::time::{Duration, Instant};
 use std::thread;
 
 use crate::lang::{Language, Text};
+use super::RunOutcome;
+use crate::interrupt::{TestGuard, interrupted};
+use crate::sensors::{FanReading, FanHistory, get_fan_speeds};
 
 pub mod tests;
 pub mod dashboard;
@@ -17,20 +19,104 @@ use tests::disk::DiskPartialResult;
 use tests::gpu::GpuPartialResult;
 use dashboard::render_torture_dashboard;
 
+/// Temperature that triggers the emergency stop
+const CRITICAL_TEMP_C: f32 = 100.0;
+/// Consecutive samples (100ms cycles) above `CRITICAL_TEMP_C` before stopping
+const OVERHEAT_SAMPLES: u32 = 20;
+
+/// Counts consecutive cycles where the CPU or GPU ran above the critical temperature
+#[derive(Default)]
+struct OverheatWatch {
+    consecutive: u32,
+}
+
+impl OverheatWatch {
+    /// Record one cycle's temperatures; true once the limit was held for `OVERHEAT_SAMPLES`
+    fn update(&mut self, cpu_temp: Option<f32>, gpu_temp: Option<f32>) -> bool {
+        let hot = [cpu_temp, gpu_temp].into_iter().flatten().any(|t| t > CRITICAL_TEMP_C);
+        self.consecutive = if hot { self.consecutive + 1 } else { 0 };
+        self.consecutive >= OVERHEAT_SAMPLES
+    }
+}
+
+/// One workload of the torture test (--torture-components)
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TortureComponent {
+    Cpu,
+    Ram,
+    Disk,
+    Gpu,
+}
+
+impl TortureComponent {
+    pub const ALL: [TortureComponent; 4] =
+        [TortureComponent::Cpu, TortureComponent::Ram, TortureComponent::Disk, TortureComponent::Gpu];
+}
+
 pub struct TortureConfig {
     pub duration_secs: u64,
     pub _verbose: bool,
     pub language: Language,
     pub skip_confirm: bool,
+    /// Workloads to run together (default: all four)
+    pub components: Vec<TortureComponent>,
+    /// Dashboard refreshes per second (1-10)
+    pub dashboard_hz: u32,
+}
+
+impl TortureConfig {
+    fn runs(&self, component: TortureComponent) -> bool {
+        self.components.contains(&component)
+    }
+}
+
+/// Time between dashboard refreshes
+fn refresh_interval(dashboard_hz: u32) -> Duration {
+    Duration::from_secs(1) / dashboard_hz.clamp(1, 10)
+}
+
+/// Whether the dashboard is due at `elapsed`; moves `next_refresh` past it on the fixed schedule,
+/// so rates that don't divide the 100ms cycle (3, 4, 7 Hz...) still average out exactly
+fn refresh_due(elapsed: Duration, next_refresh: &mut Duration, interval: Duration) -> bool {
+    if elapsed < *next_refresh {
+        return false;
+    }
+    while *next_refresh <= elapsed {
+        *next_refresh += interval;
+    }
+    true
+}
+
+/// Time slice per active test in each 100ms cycle
+fn chunk_ms(active_tests: usize) -> u64 {
+    100 / active_tests.max(1) as u64
 }
 
 pub struct TortureResult {
     pub _duration_actual_secs: u64,
-    pub _cpu_result: Option<CpuPartialResult>,
-    pub _ram_result: Option<RamPartialResult>,
-    pub _disk_result: Option<DiskPartialResult>,
-    pub _gpu_result: Option<GpuPartialResult>,
-    pub _survived: bool,
+    pub cpu_result: Option<CpuPartialResult>,
+    pub ram_result: Option<RamPartialResult>,
+    pub disk_result: Option<DiskPartialResult>,
+    pub gpu_result: Option<GpuPartialResult>,
+    pub survived: bool,
+    /// Fan that stopped under load, with the temperature it was found at
+    pub stuck_fan: Option<(FanReading, f32)>,
+}
+
+impl TortureResult {
+    /// A run that did not complete, or any unhealthy component, counts as failed
+    pub fn outcome(&self) -> RunOutcome {
+        let all_healthy = [
+            self.cpu_result.as_ref().map(|r| r.healthy),
+            self.ram_result.as_ref().map(|r| r.healthy),
+            self.disk_result.as_ref().map(|r| r.healthy),
+            self.gpu_result.as_ref().map(|r| r.healthy),
+        ]
+        .into_iter()
+        .all(|healthy| healthy != Some(false));
+
+        if self.survived && all_healthy && self.stuck_fan.is_none() { RunOutcome::Healthy } else { RunOutcome::Failed }
+    }
 }
 
 /// Run full system torture test
@@ -38,39 +124,42 @@ pub struct TortureResult {
 pub fn run_torture_test(config: TortureConfig) -> TortureResult {
     let text = Text::new(config.language);
 
-    // Warning message
-    println!();
-    println!("============================================================");
-    println!("🔥 {} - v0.3.0", text.torture_test());
-    println!("============================================================");
-    println!();
-    println!("⚠️  {}", text.torture_warning());
-    println!();
-    println!("• {}", text.torture_warning_psu());
-    println!("• {}", text.torture_warning_thermal());
-    println!("• {}", text.torture_warning_fans());
-    println!();
-    println!("{} {} {} {}", text.torture_duration(), config.duration_secs, text.seconds(), text.torture_cancel_info());
-    println!();
+    // Warning message (--summary-only keeps it only when a confirmation is asked for)
+    if !(crate::fmt::summary_only_enabled() && config.skip_confirm) {
+        println!();
+        println!("============================================================");
+        println!("🔥 {} - v0.3.0", text.torture_test());
+        println!("============================================================");
+        println!();
+        println!("⚠️  {}", text.torture_warning());
+        println!();
+        println!("• {}", text.torture_warning_psu());
+        println!("• {}", text.torture_warning_thermal());
+        println!("• {}", text.torture_warning_fans());
+        println!();
+        println!("{} {} {} {}", text.torture_duration(), config.duration_secs, text.seconds(), text.torture_cancel_info());
+        println!();
+    }
 
     // Confirm prompt (skip if configured)
     if !config.skip_confirm {
-        print!("{} [Y/n]: ", text.torture_confirm());
-        io::stdout().flush().unwrap();
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input).unwrap();
-
-        let input = input.trim().to_lowercase();
-        if input == "n" || input == "no" {
+        // Auto-confirm when nobody can answer (CI, piped stdin)
+        let confirmed = crate::prompt::read_choice_or_default(
+            &format!("{} [Y/n]: ", text.torture_confirm()),
+            "",
+            true,
+            |input| Some(!matches!(input.to_lowercase().as_str(), "n" | "no")),
+        );
+        if !confirmed {
             println!("❌ {}", text.torture_cancelled());
             return TortureResult {
                 _duration_actual_secs: 0,
-                _cpu_result: None,
-                _ram_result: None,
-                _disk_result: None,
-                _gpu_result: None,
-                _survived: false,
+                cpu_result: None,
+                ram_result: None,
+                disk_result: None,
+                gpu_result: None,
+                survived: false,
+                stuck_fan: None,
             };
         }
 
@@ -90,58 +179,114 @@ pub fn run_torture_test(config: TortureConfig) -> TortureResult {
 
 /// Internal torture test implementation
 fn run_torture_test_internal(config: TortureConfig) -> TortureResult {
+    let _guard = TestGuard::new();
+    // Idle baseline for the stuck-fan check, read before any load starts
+    let idle_temp = [
+        config.runs(TortureComponent::Cpu).then(crate::sensors::get_cpu_temp).flatten().map(|t| t.current),
+        config.runs(TortureComponent::Gpu).then(tests::gpu::get_gpu_temp).flatten().map(|t| t.current),
+    ]
+    .into_iter()
+    .flatten()
+    .reduce(f32::max);
+
     let start = Instant::now();
     let duration = Duration::from_secs(config.duration_secs);
     let cycle_duration = Duration::from_millis(100); // 100ms per cycle
 
-    // Create tests
-    let mut cpu_test = CpuTortureTest::new(config.duration_secs);
-    let mut ram_test = RamTortureTest::new();
-    let mut disk_test = DiskTortureTest::new();
-    let mut gpu_test = GpuTortureTest::new(config.duration_secs);
+    // Create only the selected tests
+    let mut cpu_test = config.runs(TortureComponent::Cpu).then(|| CpuTortureTest::new(config.duration_secs));
+    let mut ram_test = config.runs(TortureComponent::Ram).then(RamTortureTest::new);
+    let mut disk_test = config.runs(TortureComponent::Disk).then(DiskTortureTest::new);
+    let mut gpu_test = config.runs(TortureComponent::Gpu).then(|| GpuTortureTest::new(config.duration_secs));
 
     let mut max_cpu_temp: Option<f32> = None;
     let mut max_gpu_temp: Option<f32> = None;
-
-    // Main loop: round-robin through all tests
-    let cycle_count = 4; // CPU, RAM, Disk, GPU
-    let chunk_ms = 100 / cycle_count; // 25ms per test per cycle
+    let mut overheat = OverheatWatch::default();
+    let mut emergency_stop = false;
+    let mut fans: Vec<FanReading> = get_fan_speeds();
+    let mut fan_history = FanHistory::from_readings(&fans);
+    let mut stuck_fan: Option<(FanReading, f32)> = None;
+
+    // Main loop: round-robin through the active tests (25ms each with all four)
+    let active_tests = [cpu_test.is_some(), ram_test.is_some(), disk_test.is_some(), gpu_test.is_some()]
+        .iter()
+        .filter(|&&active| active)
+        .count();
+    let chunk_ms = chunk_ms(active_tests);
     let mut first_render = true;
     let mut cycle_counter = 0;
+    let refresh_interval = refresh_interval(config.dashboard_hz);
+    let mut next_refresh = refresh_interval;
 
-    while start.elapsed() < duration {
+    while start.elapsed() < duration && !interrupted() {
         let cycle_start = Instant::now();
 
         // Run each test for its chunk
-        cpu_test.run_chunk(chunk_ms);
-        ram_test.run_chunk(chunk_ms);
-        disk_test.run_chunk(chunk_ms);
-        gpu_test.run_chunk(chunk_ms);
+        if let Some(test) = cpu_test.as_mut() {
+            test.run_chunk(chunk_ms);
+        }
+        if let Some(test) = ram_test.as_mut() {
+            test.run_chunk(chunk_ms);
+        }
+        if let Some(test) = disk_test.as_mut() {
+            test.run_chunk(chunk_ms);
+        }
+        if let Some(test) = gpu_test.as_mut() {
+            test.run_chunk(chunk_ms);
+        }
 
         // Collect metrics and update dashboard
-        let cpu_metrics = cpu_test.get_metrics();
-        let ram_metrics = ram_test.get_metrics();
-        let disk_metrics = disk_test.get_metrics();
-        let gpu_metrics = gpu_test.get_metrics();
+        let cpu_metrics = cpu_test.as_ref().map(|t| t.get_metrics());
+        let ram_metrics = ram_test.as_ref().map(|t| t.get_metrics());
+        let disk_metrics = disk_test.as_ref().map(|t| t.get_metrics());
+        let gpu_metrics = gpu_test.as_ref().map(|t| t.get_metrics());
+        let cpu_temp = cpu_metrics.as_ref().and_then(|m| m.temp_c);
+        let gpu_temp = gpu_metrics.as_ref().and_then(|m| m.temp_c);
 
         // Track max temperatures
-        if let Some(temp) = cpu_metrics.temp_c {
+        if let Some(temp) = cpu_temp {
             max_cpu_temp = Some(max_cpu_temp.unwrap_or(0.0).max(temp));
         }
-        if let Some(temp) = gpu_metrics.temp_c {
+        if let Some(temp) = gpu_temp {
             max_gpu_temp = Some(max_gpu_temp.unwrap_or(0.0).max(temp));
         }
+        if overheat.update(cpu_temp, gpu_temp) {
+            emergency_stop = true;
+            break;
+        }
 
-        // Render dashboard every 10 cycles (1Hz) to match CPU test behavior
+        // Telemetry and JSON progress stay at 1Hz, the dashboard follows --dashboard-hz
         cycle_counter += 1;
         if cycle_counter % 10 == 0 {
+            fans = get_fan_speeds();
+            fan_history.record(&fans);
+            if stuck_fan.is_none() {
+                let peak = [max_cpu_temp, max_gpu_temp].into_iter().flatten().reduce(f32::max);
+                if let Some(fan) = fan_history.stuck_fan(&fans, idle_temp, peak) {
+                    stuck_fan = Some((fan.clone(), peak.unwrap_or_default()));
+                }
+            }
+            crate::fmt::telemetry::record(
+                "torture",
+                start.elapsed().as_secs(),
+                cpu_temp,
+                cpu_metrics.as_ref().and_then(|m| m.freq_mhz).unwrap_or(0),
+                cpu_metrics.as_ref().map_or(0, |m| m.operations),
+                &cpu_test.as_ref().map(CpuTortureTest::per_core_usage).unwrap_or_default(),
+            );
+        }
+        if cycle_counter % 10 == 0 && crate::fmt::progress_json_enabled() {
+            let percent = (start.elapsed().as_secs() * 100 / config.duration_secs.max(1)) as u8;
+            crate::fmt::emit_progress_json("torture", percent, cpu_temp, start.elapsed().as_secs());
+        } else if !crate::fmt::progress_json_enabled() && !crate::fmt::summary_only_enabled() && refresh_due(start.elapsed(), &mut next_refresh, refresh_interval) {
             render_torture_dashboard(
                 start.elapsed(),
                 duration,
-                &cpu_metrics,
-                &ram_metrics,
-                &disk_metrics,
-                &gpu_metrics,
+                cpu_metrics.as_ref(),
+                ram_metrics.as_ref(),
+                disk_metrics.as_ref(),
+                gpu_metrics.as_ref(),
+                &fans,
                 &Text::new(config.language),
                 first_render,
             );
@@ -155,53 +300,104 @@ fn run_torture_test_internal(config: TortureConfig) -> TortureResult {
         }
     }
 
-    // Stop all tests
-    cpu_test.stop();
-    ram_test.stop();
-    disk_test.stop();
-    gpu_test.stop();
+    // Stop all tests (also removes the disk test file after Ctrl+C)
+    if let Some(test) = cpu_test.as_mut() {
+        test.stop();
+    }
+    if let Some(test) = ram_test.as_mut() {
+        test.stop();
+    }
+    if let Some(test) = disk_test.as_mut() {
+        test.stop();
+    }
+    if let Some(test) = gpu_test.as_mut() {
+        test.stop();
+    }
+
+    let text = Text::new(config.language);
+    if emergency_stop {
+        println!();
+        println!("🛑 {}", text.torture_emergency_stop().replace("{TEMP}", &format!("{:.0}", CRITICAL_TEMP_C)));
+        println!();
+    }
 
     // Collect results
-    let cpu_result = cpu_test.get_result();
-    let ram_result = ram_test.get_result();
-    let disk_result = disk_test.get_result();
-    let gpu_result = gpu_test.get_result();
+    let cpu_result = cpu_test.map(|t| t.get_result());
+    let ram_result = ram_test.map(|t| t.get_result());
+    let disk_result = disk_test.map(|t| t.get_result());
+    let gpu_result = gpu_test.map(|t| t.get_result());
 
     let actual_duration = start.elapsed().as_secs();
 
     // Print summary
     print_torture_summary(
         actual_duration,
-        &cpu_result,
-        &ram_result,
-        &disk_result,
-        &gpu_result,
+        cpu_result.as_ref(),
+        ram_result.as_ref(),
+        disk_result.as_ref(),
+        gpu_result.as_ref(),
         max_cpu_temp,
         max_gpu_temp,
-        &Text::new(config.language),
+        stuck_fan.as_ref(),
+        &text,
     );
 
     TortureResult {
         _duration_actual_secs: actual_duration,
-        _cpu_result: Some(cpu_result),
-        _ram_result: Some(ram_result),
-        _disk_result: Some(disk_result),
-        _gpu_result: Some(gpu_result),
-        _survived: true,
+        cpu_result,
+        ram_result,
+        disk_result,
+        gpu_result,
+        survived: !interrupted() && !emergency_stop,
+        stuck_fan,
     }
 }
 
-/// Print torture test summary
+/// Print torture test summary (only the components that ran)
+#[allow(clippy::too_many_arguments)]
 fn print_torture_summary(
     duration: u64,
-    cpu: &CpuPartialResult,
-    ram: &RamPartialResult,
-    disk: &DiskPartialResult,
-    gpu: &GpuPartialResult,
+    cpu: Option<&CpuPartialResult>,
+    ram: Option<&RamPartialResult>,
+    disk: Option<&DiskPartialResult>,
+    gpu: Option<&GpuPartialResult>,
     _max_cpu_temp: Option<f32>,
     _max_gpu_temp: Option<f32>,
+    stuck_fan: Option<&(FanReading, f32)>,
     text: &Text,
 ) {
+    let all_healthy = stuck_fan.is_none()
+        && cpu.is_none_or(|r| r.healthy)
+        && ram.is_none_or(|r| r.healthy)
+        && disk.is_none_or(|r| r.healthy)
+        && gpu.is_none_or(|r| r.healthy);
+
+    // --summary-only: just the verdict and what failed
+    if crate::fmt::summary_only_enabled() {
+        let failures = [
+            (text.cpu(), cpu.map(|r| (r.healthy, &r.status))),
+            (text.ram(), ram.map(|r| (r.healthy, &r.status))),
+            (text.disk(), disk.map(|r| (r.healthy, &r.status))),
+            (text.gpu(), gpu.map(|r| (r.healthy, &r.status))),
+        ];
+        println!("============================================================");
+        if all_healthy {
+            println!("✅ {}", text.torture_passed());
+        } else {
+            println!("❌ {}", text.torture_failed());
+        }
+        for (label, result) in failures {
+            if let Some((false, status)) = result {
+                println!("   • {}: {}", label, status.as_deref().unwrap_or("-"));
+            }
+        }
+        if let Some((fan, temp)) = stuck_fan {
+            println!("   • {}", text.fan_not_spinning().replace("{FAN}", &fan.label).replace("{TEMP}", &format!("{:.0}", temp)));
+        }
+        println!("============================================================");
+        return;
+    }
+
     println!("============================================================");
     println!("📊 {} - v0.3.0", text.torture_summary());
     println!("============================================================");
@@ -210,51 +406,63 @@ fn print_torture_summary(
     println!();
 
     // CPU result
-    println!("🧠 {}", text.cpu());
-    println!("   {} {} | {} {:.1}°C | {} {:.2} GHz",
-        text.operations(), cpu.operations,
-        text.temperature(), cpu.temp_c.unwrap_or(0.0),
-        text.frequency(), cpu.freq_ghz
-    );
-    if let Some(ref msg) = cpu.status {
-        println!("   {} {}", if cpu.healthy { "✅" } else { "❌" }, msg);
+    if let Some(cpu) = cpu {
+        println!("🧠 {}", text.cpu());
+        println!("   {} {} | {} {:.1}°C | {} {:.2} GHz",
+            text.operations(), cpu.operations,
+            text.temperature(), cpu.temp_c.unwrap_or(0.0),
+            text.frequency(), cpu.freq_ghz
+        );
+        if let Some(ref msg) = cpu.status {
+            println!("   {} {}", if cpu.healthy { "✅" } else { "❌" }, msg);
+        }
+        println!();
     }
-    println!();
 
     // RAM result
-    println!("💾 {}", text.ram());
-    println!("   {} {:.1} GB | {} {}",
-        text.tested_gb(), ram.tested_gb,
-        text.errors_detected(), ram.errors
-    );
-    if let Some(ref msg) = ram.status {
-        println!("   {} {}", if ram.healthy { "✅" } else { "❌" }, msg);
+    if let Some(ram) = ram {
+        println!("💾 {}", text.ram());
+        println!("   {} {:.1} GB | {} {}",
+            text.tested_gb(), ram.tested_gb,
+            text.errors_detected(), ram.errors
+        );
+        if let Some(ref msg) = ram.status {
+            println!("   {} {}", if ram.healthy { "✅" } else { "❌" }, msg);
+        }
+        println!();
     }
-    println!();
 
     // Disk result
-    println!("💿 {}", text.disk());
-    println!("   {} {:.3} MB/s | {} {:.3} MB/s",
-        text.write_speed(), disk.write_speed_mb_s,
-        text.read_speed(), disk.read_speed_mb_s
-    );
-    if let Some(ref msg) = disk.status {
-        println!("   {} {}", if disk.healthy { "✅" } else { "❌" }, msg);
+    if let Some(disk) = disk {
+        println!("💿 {}", text.disk());
+        println!("   {} {:.3} MB/s | {} {:.3} MB/s",
+            text.write_speed(), disk.write_speed_mb_s,
+            text.read_speed(), disk.read_speed_mb_s
+        );
+        if let Some(ref msg) = disk.status {
+            println!("   {} {}", if disk.healthy { "✅" } else { "❌" }, msg);
+        }
+        println!();
     }
-    println!();
 
     // GPU result
-    println!("🎮 {}", text.gpu());
-    if let Some(temp) = gpu.temp_c {
-        println!("   {} {:.1}°C", text.temperature(), temp);
+    if let Some(gpu) = gpu {
+        println!("🎮 {}", text.gpu());
+        if let Some(temp) = gpu.temp_c {
+            println!("   {} {:.1}°C", text.temperature(), temp);
+        }
+        if let Some(ref msg) = gpu.status {
+            println!("   {} {}", if gpu.healthy { "✅" } else { "❌" }, msg);
+        }
+        println!();
     }
-    if let Some(ref msg) = gpu.status {
-        println!("   {} {}", if gpu.healthy { "✅" } else { "❌" }, msg);
+
+    if let Some((fan, temp)) = stuck_fan {
+        println!("🛑 {}", text.fan_not_spinning().replace("{FAN}", &fan.label).replace("{TEMP}", &format!("{:.0}", temp)));
+        println!();
     }
-    println!();
 
     // Overall verdict
-    let all_healthy = cpu.healthy && ram.healthy && disk.healthy && gpu.healthy;
     println!("============================================================");
     if all_healthy {
         println!("✅ {}", text.torture_passed());
@@ -263,3 +471,50 @@ fn print_torture_summary(
     }
     println!("============================================================");
 }
+
+// `tests` is taken by the torture workloads
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_overheat_watch() {
+        let mut watch = OverheatWatch::default();
+        for _ in 0..OVERHEAT_SAMPLES - 1 {
+            assert!(!watch.update(Some(101.0), None));
+        }
+        // A cool sample resets the streak
+        assert!(!watch.update(Some(90.0), Some(70.0)));
+        for _ in 0..OVERHEAT_SAMPLES - 1 {
+            assert!(!watch.update(None, Some(102.0)));
+        }
+        assert!(watch.update(Some(60.0), Some(102.0)));
+        assert!(!OverheatWatch::default().update(None, None));
+    }
+
+    #[test]
+    fn test_chunk_ms() {
+        assert_eq!(chunk_ms(4), 25);
+        assert_eq!(chunk_ms(2), 50);
+        assert_eq!(chunk_ms(0), 100);
+    }
+
+    #[test]
+    fn test_refresh_interval() {
+        assert_eq!(refresh_interval(1), Duration::from_secs(1));
+        assert_eq!(refresh_interval(4), Duration::from_millis(250));
+        assert_eq!(refresh_interval(10), Duration::from_millis(100));
+        assert_eq!(refresh_interval(0), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_refresh_due_keeps_rate() {
+        // 3 Hz checked every 100ms cycle: 3 refreshes per second, not 10 / 3 = 3.33
+        let interval = refresh_interval(3);
+        let mut next_refresh = interval;
+        let refreshes = (1..=30)
+            .filter(|&cycle| refresh_due(Duration::from_millis(cycle * 100), &mut next_refresh, interval))
+            .count();
+        assert_eq!(refreshes, 9);
+    }
+}
@@ -1,18 +1,21 @@
 // CPU torture test wrapper
 // Runs CPU-intensive calculations in chunks during round-robin execution
 
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::thread;
 use std::time::Instant;
 
-use crate::sensors::{get_cpu_temp, get_cpu_frequency};
+use crate::sensors::{CpuMonitorHandle, get_cpu_temp, get_cpu_frequency};
 
 /// CPU torture test state
 pub struct CpuTortureTest {
     running: Arc<AtomicBool>,
     operations: Arc<AtomicU64>,
     thread_handles: Vec<thread::JoinHandle<()>>,
+    /// Background per-core usage sampling (for --telemetry)
+    monitor: CpuMonitorHandle,
     _start_time: Instant,
     _target_duration_secs: u64,
     _cpu_cores: usize,
@@ -55,6 +58,7 @@ impl CpuTortureTest {
             running,
             operations,
             thread_handles,
+            monitor: CpuMonitorHandle::start(),
             _start_time: Instant::now(),
             _target_duration_secs: duration_secs,
             _cpu_cores: cpu_cores,
@@ -77,14 +81,19 @@ impl CpuTortureTest {
         TestMetrics {
             load_pct: 100.0, // Always 100% when running
             temp_c: temp.as_ref().map(|t| t.current),
-            _freq_mhz: Some(freq.current_mhz),
+            freq_mhz: Some(freq.current_mhz),
             freq_ghz: freq.current_ghz,
-            _operations: ops,
+            operations: ops,
             _errors: 0,
             _status_msg: format!("{} ops", format_large_number(ops)),
         }
     }
 
+    /// Latest usage per core (%)
+    pub fn per_core_usage(&self) -> HashMap<usize, f32> {
+        self.monitor.get_per_core_usage()
+    }
+
     /// Stop the CPU test and collect results
     pub fn stop(&mut self) {
         if self.completed {
@@ -138,9 +147,9 @@ impl CpuTortureTest {
 pub struct TestMetrics {
     pub load_pct: f32,
     pub temp_c: Option<f32>,
-    pub _freq_mhz: Option<u64>,
+    pub freq_mhz: Option<u64>,
     pub freq_ghz: f64,
-    pub _operations: u64,
+    pub operations: u64,
     pub _errors: u64,
     pub _status_msg: String,
 }
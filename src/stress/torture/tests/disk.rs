@@ -53,9 +53,10 @@ pub struct TestMetrics {
 impl DiskTortureTest {
     /// Create new Disk torture test
     pub fn new() -> Self {
-        // Get temp directory
-        let test_path = std::env::temp_dir()
-            .join("pchecker_torture_disk.tmp")
+        // Get a writable directory (temp dir, then home, then current dir)
+        use crate::stress::disk::{candidate_test_dirs, find_writable_test_path};
+        let test_path = find_writable_test_path(&candidate_test_dirs(), "pchecker_torture_disk.tmp")
+            .unwrap_or_else(|| std::env::temp_dir().join("pchecker_torture_disk.tmp"))
             .to_string_lossy()
             .to_string();
 
@@ -214,11 +215,9 @@ impl DiskTortureTest {
             0.0
         };
 
-        let progress = if self.total_chunks > 0 {
-            (self.chunk_index * 100 / self.total_chunks) as f32
-        } else {
-            0.0
-        };
+        let progress = (self.chunk_index * 100)
+            .checked_div(self.total_chunks)
+            .map_or(0.0, |p| p as f32);
 
         let status = match self.phase {
             DiskPhase::CreateFile => "Creating file...".to_string(),
@@ -165,12 +165,7 @@ impl GpuTortureTest {
     }
 }
 
-/// Helper to get GPU temp from sensors
+/// Helper to get GPU temp from sensors (shared registry, refreshed per call)
 pub fn get_gpu_temp() -> Option<GpuTemp> {
-    sensors::get_all_sensors()
-        .into_iter()
-        .find(|s| s.label.to_lowercase().contains("gpu")
-            || s.label.to_lowercase().contains("graphic")
-            || s.label.contains("GT"))
-        .map(|s| GpuTemp { current: s.temp })
+    sensors::registry::with_fresh(|registry| registry.gpu_temp()).map(|current| GpuTemp { current })
 }
@@ -15,6 +15,8 @@ pub struct RamTortureTest {
     errors: Arc<AtomicU64>,
     _start_time: Instant,
     tested_gb: f64,
+    /// Size that the allocator refused (GB)
+    alloc_failed_gb: Option<f64>,
     phase: RamPhase,
     stop_requested: bool,
 }
@@ -55,6 +57,7 @@ impl RamTortureTest {
             errors: Arc::new(AtomicU64::new(0)),
             _start_time: Instant::now(),
             tested_gb: 0.0,
+            alloc_failed_gb: None,
             phase: RamPhase::Alloc,
             stop_requested: false,
         }
@@ -97,15 +100,16 @@ impl RamTortureTest {
 
         let element_count = (test_gb * 1024.0 * 1024.0 * 1024.0 / 8.0) as usize;
 
-        match vec![0u64; element_count] {
-            buffer => {
-                self.buffer = Some(buffer);
-                self.tested_gb = test_gb;
-                self.chunk_index = 0;
-                self.total_chunks = element_count.div_ceil(1024 * 1024);
-                self.phase = RamPhase::Write;
-            }
-        }
+        let Some(buffer) = crate::stress::ram::try_alloc_words(element_count) else {
+            self.alloc_failed_gb = Some(test_gb);
+            self.phase = RamPhase::Done;
+            return;
+        };
+        self.buffer = Some(buffer);
+        self.tested_gb = test_gb;
+        self.chunk_index = 0;
+        self.total_chunks = element_count.div_ceil(1024 * 1024);
+        self.phase = RamPhase::Write;
     }
 
     /// Write a chunk of data
@@ -123,9 +127,7 @@ impl RamTortureTest {
             let start = self.chunk_index * chunk_size;
             let end = (start + chunk_size).min(buffer.len());
 
-            for i in start..end {
-                buffer[i] = pattern;
-            }
+            buffer[start..end].fill(pattern);
 
             self.chunk_index += 1;
 
@@ -152,10 +154,9 @@ impl RamTortureTest {
             let start = self.chunk_index * chunk_size;
             let end = (start + chunk_size).min(buffer.len());
 
-            for i in start..end {
-                if buffer[i] != pattern {
-                    self.errors.fetch_add(1, Ordering::Relaxed);
-                }
+            let bad = buffer[start..end].iter().filter(|&&v| v != pattern).count();
+            if bad > 0 {
+                self.errors.fetch_add(bad as u64, Ordering::Relaxed);
             }
 
             self.chunk_index += 1;
@@ -172,11 +173,9 @@ impl RamTortureTest {
     /// Get current metrics
     pub fn get_metrics(&self) -> TestMetrics {
         let errors = self.errors.load(Ordering::Relaxed);
-        let progress = if self.total_chunks > 0 {
-            (self.chunk_index * 100 / self.total_chunks) as f32
-        } else {
-            0.0
-        };
+        let progress = (self.chunk_index * 100)
+            .checked_div(self.total_chunks)
+            .map_or(0.0, |p| p as f32);
 
         let status = match self.phase {
             RamPhase::Alloc => "Allocating...".to_string(),
@@ -216,6 +215,8 @@ impl RamTortureTest {
 
         let (healthy, status) = if errors > 0 {
             (false, Some(format!("{} errors detected", errors)))
+        } else if let Some(gb) = self.alloc_failed_gb {
+            (false, Some(format!("Could not allocate {:.1} GB", gb)))
         } else if self.tested_gb < 0.1 {
             (false, Some("Allocation failed".to_string()))
         } else {
@@ -0,0 +1,121 @@
+// Run transcript module
+// Appends a timestamped plain-text record of each health check run to a file (--output)
+
+use std::io;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::stress::HealthStatus;
+
+/// Result boxes and status lines collected during one health check run
+pub struct Transcript {
+    started: SystemTime,
+    boxes: String,
+    status_lines: Vec<String>,
+}
+
+impl Transcript {
+    pub fn new() -> Self {
+        Self {
+            started: SystemTime::now(),
+            boxes: String::new(),
+            status_lines: Vec::new(),
+        }
+    }
+
+    /// Record one component's result box and its status
+    pub fn add_result(&mut self, component: &str, result_box: &str, status: &HealthStatus) {
+        self.boxes.push_str(result_box);
+        self.status_lines.push(format!("{}: {}", component, status_summary(status)));
+    }
+
+    pub fn render(&self, duration: Duration) -> String {
+        let mut out = String::new();
+        out.push_str("============================================================\n");
+        out.push_str(&format!("pchecker v0.3.0 health check - {}\n", format_utc(self.started)));
+        out.push_str("============================================================\n");
+        out.push_str(&self.boxes);
+        out.push('\n');
+        for line in &self.status_lines {
+            out.push_str(line);
+            out.push('\n');
+        }
+        out.push_str(&format!("Duration: {:.1}s\n\n", duration.as_secs_f64()));
+        out
+    }
+
+    /// Append this run to `path`, creating the file if needed
+    pub fn append_to(&self, path: &Path, duration: Duration) -> io::Result<()> {
+        crate::fmt::gzip::append_file(path, self.render(duration).as_bytes())
+    }
+}
+
+/// One-line summary of a health status
+pub fn status_summary(status: &HealthStatus) -> String {
+    match status {
+        HealthStatus::Healthy => "Healthy".to_string(),
+        HealthStatus::IssuesDetected(issues) => {
+            let messages: Vec<&str> = issues.iter().map(|i| i.message.as_str()).collect();
+            format!("Issues detected - {}", messages.join("; "))
+        }
+        HealthStatus::Failed(issue) => format!("Failed - {}", issue),
+    }
+}
+
+/// Format a time as "YYYY-MM-DD HH:MM:SS UTC"
+pub fn format_utc(time: SystemTime) -> String {
+    let (year, month, day, h, m, s) = utc_parts(time);
+    format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC", year, month, day, h, m, s)
+}
+
+/// Format a time as RFC 3339 ("YYYY-MM-DDTHH:MM:SSZ"), for machine-readable files
+pub fn format_rfc3339(time: SystemTime) -> String {
+    let (year, month, day, h, m, s) = utc_parts(time);
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, h, m, s)
+}
+
+fn utc_parts(time: SystemTime) -> (i64, u32, u32, u64, u64, u64) {
+    let secs = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let rem = secs % 86_400;
+    (year, month, day, rem / 3600, rem % 3600 / 60, rem % 60)
+}
+
+/// Days since 1970-01-01 to (year, month, day) in the proleptic Gregorian calendar
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stress::{Issue, IssueCode};
+
+    #[test]
+    fn test_format_utc() {
+        assert_eq!(format_utc(UNIX_EPOCH), "1970-01-01 00:00:00 UTC");
+        let leap_day = UNIX_EPOCH + Duration::from_secs(1_709_210_096);
+        assert_eq!(format_utc(leap_day), "2024-02-29 12:34:56 UTC");
+        assert_eq!(format_rfc3339(leap_day), "2024-02-29T12:34:56Z");
+    }
+
+    #[test]
+    fn test_render() {
+        let mut transcript = Transcript::new();
+        transcript.add_result("CPU", "│ box │\n", &HealthStatus::Healthy);
+        transcript.add_result("RAM", "", &HealthStatus::IssuesDetected(vec![Issue::new(IssueCode::RamSlow, "a"), Issue::new(IssueCode::RamError, "b")]));
+        let text = transcript.render(Duration::from_millis(12_340));
+        assert!(text.contains("│ box │\n"));
+        assert!(text.contains("CPU: Healthy\nRAM: Issues detected - a; b\n"));
+        assert!(text.contains("Duration: 12.3s"));
+    }
+}
@@ -0,0 +1,42 @@
+// Ctrl+C ends a --watch run through the normal exit path, so a compressed telemetry file gets its trailer
+
+#![cfg(unix)]
+
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Command, Stdio};
+
+#[test]
+fn test_interrupted_watch_leaves_valid_gzip() {
+    let path = std::env::temp_dir().join(format!("pchecker_watch_{}.jsonl.gz", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_pchecker"))
+        .args(["--watch", "1", "--cpu", "1", "--no-ai", "--lang", "en", "--telemetry"])
+        .arg(&path)
+        .env("PCHECKER_TEST_MODE", "1")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    // Interrupt while waiting for the next run
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    for line in stdout.lines() {
+        if line.unwrap().contains("⏸️") {
+            break;
+        }
+    }
+    unsafe {
+        libc::kill(child.id() as libc::pid_t, libc::SIGINT);
+    }
+    let status = child.wait().unwrap();
+    assert_eq!(status.code(), Some(130));
+
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let mut lines = String::new();
+    flate2::read::MultiGzDecoder::new(&bytes[..]).read_to_string(&mut lines).unwrap();
+    assert!(lines.lines().count() > 0);
+    assert!(lines.lines().all(|line| serde_json::from_str::<serde_json::Value>(line).is_ok()));
+}